@@ -8,6 +8,7 @@ use std::{env, fs::File, io::Write, path::Path};
  * and "serde" to allow not to describe the launch
  * day or month for example
  */
+#[cfg(feature = "std")]
 #[derive(Deserialize)]
 struct SBASDBEntry<'a> {
     /// Constellation name (must be valid)
@@ -19,8 +20,32 @@ struct SBASDBEntry<'a> {
     /// Readable name
     pub name: &'a str,
 
+    /// COSPAR (international launch) identification code
+    pub cospar: Option<&'a str>,
+
+    /// NORAD catalog number
+    pub norad: Option<u32>,
+
+    /// Operating entity
+    pub operator: &'a str,
+
     /// Launch Datetime
     pub launch: &'a str,
+
+    /// Service start Datetime, when it differs from launch
+    pub operational: Option<&'a str>,
+
+    /// Decommission Datetime
+    pub decommission: Option<&'a str>,
+
+    /// Orbital longitude (decimal degrees east)
+    pub longitude: f64,
+
+    /// Broadcasts the legacy L1 service
+    pub l1: bool,
+
+    /// Broadcasts the dual frequency multi constellation (DFMC) L5 service
+    pub dfmc: bool,
 }
 
 #[cfg(feature = "std")]
@@ -44,7 +69,15 @@ pub struct SBASHelper<'a> {
     constellation: &'a str,
     prn: u16,
     name: &'a str,
+    cospar: Option<&'a str>,
+    norad: Option<u32>,
+    operator: &'a str,
     launch: &'a str,
+    operational: Option<&'a str>,
+    decommission: Option<&'a str>,
+    longitude: f64,
+    l1: bool,
+    dfmc: bool,
 }
 
 lazy_static! {
@@ -53,16 +86,35 @@ lazy_static! {
 
     fd.write_all(content.as_bytes()).unwrap();
 
-    for e in sbas_db {
+    for e in sbas_db.iter() {
         fd.write_all(
             format!(
                 "SBASHelper {{
                 constellation: \"{}\",
                 prn: {},
                 name: \"{}\",
+                cospar: {:?},
+                norad: {:?},
+                operator: \"{}\",
                 launch: \"{}\",
+                operational: {:?},
+                decommission: {:?},
+                longitude: {:?},
+                l1: {},
+                dfmc: {},
             }},",
-                e.constellation, e.prn, e.name, e.launch,
+                e.constellation,
+                e.prn,
+                e.name,
+                e.cospar,
+                e.norad,
+                e.operator,
+                e.launch,
+                e.operational,
+                e.decommission,
+                e.longitude,
+                e.l1,
+                e.dfmc,
             )
             .as_bytes(),
         )
@@ -71,79 +123,334 @@ lazy_static! {
 
     fd.write_all("    ];".as_bytes()).unwrap();
     fd.write_all("}\n".as_bytes()).unwrap();
+
+    // compile time identification
+    fd.write_all(
+        "
+/// Identifies the SBAS [Constellation] from PRN number (+100), in const context.
+const fn sbas_constellation(prn: u16) -> Option<Constellation> {
+    match prn {\n"
+            .as_bytes(),
+    )
+    .unwrap();
+
+    let mut identified = Vec::new();
+
+    for e in sbas_db.iter() {
+        // first entry prevails, like runtime identification
+        if identified.contains(&e.prn) {
+            continue;
+        }
+
+        identified.push(e.prn);
+
+        fd.write_all(
+            format!(
+                "        {} => Constellation::from_str_const(\"{}\"),\n",
+                e.prn, e.constellation
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+    }
+
+    fd.write_all("        _ => None,\n    }\n}\n".as_bytes())
+        .unwrap();
 }
 
-#[cfg(feature = "sbas")]
-use geojson::{Feature, GeoJson, Value};
+/// Per constellation catalogs, described in data/catalog
+const CATALOGS: [(&str, &str); 6] = [
+    ("gps.json", "GPS"),
+    ("galileo.json", "Galileo"),
+    ("glonass.json", "Glonass"),
+    ("beidou.json", "BeiDou"),
+    ("qzss.json", "QZSS"),
+    ("irnss.json", "IRNSS"),
+];
+
+/// PRN number assignment, over a period of time
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+struct CatalogPrnEntry<'a> {
+    /// PRN number
+    pub prn: u8,
+
+    /// FDMA frequency channel (Glonass)
+    #[serde(default)]
+    pub channel: Option<i8>,
+
+    /// Assignment start datetime
+    pub start: &'a str,
+
+    /// Assignment end datetime, None if still assigned
+    pub end: Option<&'a str>,
+}
+
+/// Satellite catalog entry
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+struct CatalogEntry<'a> {
+    /// Space Vehicle Number
+    pub svn: u16,
+
+    /// Readable name
+    #[serde(default)]
+    pub name: Option<&'a str>,
+
+    /// Block (generation) of this satellite
+    pub block: &'a str,
+
+    /// ANTEX antenna designator, when not deduced from the block
+    #[serde(default)]
+    pub antenna: Option<&'a str>,
+
+    /// Flight model number (Galileo)
+    #[serde(default)]
+    pub fm: Option<u8>,
+
+    /// Eccentric orbit (Galileo)
+    #[serde(default)]
+    pub eccentric: bool,
+
+    /// COSPAR (launch) identification code
+    pub cospar: &'a str,
 
-#[cfg(feature = "sbas")]
-use bincode::{config, Encode};
+    /// NORAD catalog number
+    pub norad: u32,
+
+    /// Active onboard clock
+    pub clock: &'a str,
+
+    /// Launch datetime
+    pub launch: &'a str,
+
+    /// Orbital longitude (GEO), in decimal degrees east
+    #[serde(default)]
+    pub longitude: Option<f64>,
+
+    /// Datetime from which this satellite was declared operational
+    #[serde(default)]
+    pub operational: Option<&'a str>,
+
+    /// Decommission datetime
+    #[serde(default)]
+    pub decommission: Option<&'a str>,
+
+    /// Orbit class, MEO when omitted
+    #[serde(default)]
+    pub orbit: Option<&'a str>,
+
+    /// PRN assignments
+    #[serde(borrow)]
+    pub prn: Vec<CatalogPrnEntry<'a>>,
+}
+
+/// Formats optional datetime description
+#[cfg(feature = "std")]
+fn optional_epoch(epoch: Option<&str>) -> String {
+    match epoch {
+        Some(epoch) => format!("Some(Epoch::from_str(\"{}\").unwrap())", epoch),
+        None => "None".to_string(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn build_catalog() {
+    let outdir = env::var("OUT_DIR").unwrap();
+    let path = Path::new(&outdir).join("catalog.rs");
+
+    let mut fd = File::create(path).unwrap_or_else(|e| {
+        panic!("Failed to initiate satellite catalog: {}", e);
+    });
+
+    let content = "use lazy_static::lazy_static;
+
+#[derive(Debug)]
+struct PrnAssignment {
+    prn: u8,
+    channel: Option<i8>,
+    start: Epoch,
+    end: Option<Epoch>,
+}
+
+#[derive(Debug)]
+struct CatalogEntry {
+    constellation: Constellation,
+    svn: u16,
+    name: Option<&'static str>,
+    block: &'static str,
+    antenna: Option<&'static str>,
+    fm: Option<u8>,
+    eccentric: bool,
+    orbit: Option<OrbitClass>,
+    #[cfg(feature = \"cospar\")]
+    cospar: &'static str,
+    norad: u32,
+    clock: &'static str,
+    launch: Epoch,
+    longitude: Option<f64>,
+    operational: Option<Epoch>,
+    decommission: Option<Epoch>,
+    prn: Vec<PrnAssignment>,
+}
+
+lazy_static! {
+    static ref CATALOG: Vec<CatalogEntry> = vec![
+\n";
+
+    fd.write_all(content.as_bytes()).unwrap();
+
+    for (file, constellation) in CATALOGS {
+        let db_path = format!("data/catalog/{}", file);
+        println!("cargo:rerun-if-changed={}", db_path);
+
+        let db_content = std::fs::read_to_string(&db_path).unwrap();
+
+        let catalog: Vec<CatalogEntry> = serde_json::from_str(&db_content)
+            .unwrap_or_else(|e| panic!("invalid catalog {}: {}", db_path, e));
+
+        for e in catalog {
+            let mut assignments = String::new();
+
+            for a in e.prn {
+                assignments.push_str(&format!(
+                    "PrnAssignment {{
+                        prn: {},
+                        channel: {:?},
+                        start: Epoch::from_str(\"{}\").unwrap(),
+                        end: {},
+                    }},",
+                    a.prn,
+                    a.channel,
+                    a.start,
+                    optional_epoch(a.end),
+                ));
+            }
+
+            let orbit = match e.orbit {
+                Some(orbit) => format!("Some(OrbitClass::{})", orbit),
+                None => "None".to_string(),
+            };
+
+            fd.write_all(
+                format!(
+                    "CatalogEntry {{
+                constellation: Constellation::{},
+                svn: {},
+                name: {:?},
+                block: \"{}\",
+                antenna: {:?},
+                fm: {:?},
+                eccentric: {},
+                orbit: {},
+                #[cfg(feature = \"cospar\")]
+                cospar: \"{}\",
+                norad: {},
+                clock: \"{}\",
+                launch: Epoch::from_str(\"{}\").unwrap(),
+                longitude: {:?},
+                operational: {},
+                decommission: {},
+                prn: vec![{}],
+            }},",
+                    constellation,
+                    e.svn,
+                    e.name,
+                    e.block,
+                    e.antenna,
+                    e.fm,
+                    e.eccentric,
+                    orbit,
+                    e.cospar,
+                    e.norad,
+                    e.clock,
+                    e.launch,
+                    e.longitude,
+                    optional_epoch(e.operational),
+                    optional_epoch(e.decommission),
+                    assignments,
+                )
+                .as_bytes(),
+            )
+            .unwrap()
+        }
+    }
+
+    fd.write_all("    ];".as_bytes()).unwrap();
+    fd.write_all("}\n".as_bytes()).unwrap();
+}
+
+use geojson::{Feature, GeoJson, Value};
 
-#[cfg(feature = "sbas")]
-#[derive(Encode)]
 struct SbasPolygon {
     name: String,
+    metadata: Vec<(String, String)>,
     coordinates: Vec<(f64, f64)>,
 }
 
-#[cfg(feature = "sbas")]
 impl SbasPolygon {
-    pub fn from_feature(feature: &Feature) -> Self {
-        Self {
-            name: {
-                feature
-                    .properties
-                    .as_ref()
-                    .unwrap_or_else(|| {
-                        panic!("invalid geojson: properties not defined in {:?}", feature);
-                    })
-                    .get("name")
-                    .unwrap_or_else(|| {
-                        panic!(
-                            "invalid geojson: name property not defined in {:?}",
-                            feature
-                        );
-                    })
-                    .to_string()
-            },
-            coordinates: {
-                let mut coordinates = Vec::new();
-
-                let geometry = feature.geometry.as_ref().unwrap_or_else(|| {
-                    panic!("invalid geojson: geometry not defined in {:?}", feature);
-                });
-
-                match &geometry.value {
-                    Value::Polygon(polygon) => {
-                        for coords in polygon[0].iter() {
-                            coordinates.push((coords[0], coords[1]));
-                        }
-                        coordinates
-                    },
-                    _ => {
-                        panic!("invalid geometry definition: expecting polygons");
-                    },
-                }
+    /// Builds one [SbasPolygon] per polygon of this feature
+    /// (Polygon or MultiPolygon), sharing its name and metadata.
+    pub fn from_feature(feature: &Feature) -> Vec<Self> {
+        let properties = feature.properties.as_ref().unwrap_or_else(|| {
+            panic!("invalid geojson: properties not defined in {:?}", feature);
+        });
+
+        let name = properties
+            .get("name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_else(|| {
+                panic!(
+                    "invalid geojson: name property not defined in {:?}",
+                    feature
+                );
+            })
+            .to_string();
+
+        let metadata = properties
+            .iter()
+            .filter(|(key, _)| key.as_str() != "name")
+            .filter_map(|(key, value)| Some((key.to_string(), value.as_str()?.to_string())))
+            .collect::<Vec<_>>();
+
+        let geometry = feature.geometry.as_ref().unwrap_or_else(|| {
+            panic!("invalid geojson: geometry not defined in {:?}", feature);
+        });
+
+        let polygons = match &geometry.value {
+            Value::Polygon(polygon) => vec![polygon],
+            Value::MultiPolygon(polygons) => polygons.iter().collect(),
+            _ => {
+                panic!("invalid geometry definition: expecting polygons");
             },
-        }
+        };
+
+        polygons
+            .into_iter()
+            .map(|polygon| Self {
+                name: name.clone(),
+                metadata: metadata.clone(),
+                coordinates: polygon[0]
+                    .iter()
+                    .map(|coords| (coords[0], coords[1]))
+                    .collect(),
+            })
+            .collect()
     }
 }
 
-#[cfg(feature = "sbas")]
-#[derive(Default, Encode)]
+#[derive(Default)]
 struct SbasMap {
     polygons: Vec<SbasPolygon>,
 }
 
-#[cfg(feature = "sbas")]
 impl SbasMap {
     fn from_geojson(geo: &GeoJson) -> Self {
         match geo {
             GeoJson::FeatureCollection(collection) => {
                 let mut map = Self::default();
                 for feature in collection.features.iter() {
-                    let polygon = SbasPolygon::from_feature(&feature);
-                    map.polygons.push(polygon);
+                    map.polygons.extend(SbasPolygon::from_feature(feature));
                 }
                 map
             },
@@ -155,13 +462,11 @@ impl SbasMap {
 }
 
 /// Retrieves the SBAS geoservices maps (.geojson)
-/// and stores them as a small static array of geo::Polygons
-#[cfg(all(feature = "sbas", feature = "std"))]
-fn build_sbas_service_polygons() {
-    let encoding_config = config::standard();
-
-    let outdir = env::var("OUT_DIR").unwrap();
-    let out_path = Path::new(&outdir).join("sbas_polygons.bin");
+/// and stores them as a small static array of polygons,
+/// which does not require std-lib nor geo at runtime.
+fn build_sbas_coverage() {
+    let outdir = std::env::var("OUT_DIR").unwrap();
+    let out_path = std::path::Path::new(&outdir).join("sbas_coverage.rs");
 
     // read database
     let geojson_database = format!(
@@ -179,12 +484,270 @@ fn build_sbas_service_polygons() {
 
     let sbas_map = SbasMap::from_geojson(&geojson);
 
-    let bytes = bincode::encode_to_vec(&sbas_map, encoding_config).unwrap_or_else(|e| {
-        panic!("failed to serialize polygon map: {}", e);
+    let mut content = String::from(
+        "/// SBAS service area: provider name, metadata (key, value) and polygon,
+/// as (longitude°, latitude°) vertices.
+pub(crate) type SbasArea = (
+    &'static str,
+    &'static [(&'static str, &'static str)],
+    &'static [(f64, f64)],
+);
+
+/// SBAS service areas, see [SbasArea].
+pub(crate) const SBAS_COVERAGE: &[SbasArea] = &[
+",
+    );
+
+    for polygon in sbas_map.polygons.iter() {
+        content.push_str(&format!("    ({:?}, &[", polygon.name));
+        for (key, value) in polygon.metadata.iter() {
+            content.push_str(&format!("({:?}, {:?}), ", key, value));
+        }
+        content.push_str("], &[");
+        for (long, lat) in polygon.coordinates.iter() {
+            content.push_str(&format!("({:?}, {:?}), ", long, lat));
+        }
+        content.push_str("]),\n");
+    }
+
+    content.push_str("];\n");
+
+    std::fs::write(out_path, content).unwrap_or_else(|e| {
+        panic!("failed to write SBAS coverage database: {}", e);
     });
+}
+
+/// Parses a "YYYY-MM-DD[THH:MM:SS] UTC" datetime into its (year, month, day,
+/// hours, minutes, seconds) fields. This is all our databases use, which
+/// avoids depending on hifitime (and its features) at build time.
+#[cfg(feature = "cospar")]
+fn parse_utc_datetime(datetime: &str) -> Option<(i32, u8, u8, u8, u8, u8)> {
+    let datetime = datetime.trim().trim_end_matches("UTC").trim_end();
+
+    let (date, time) = datetime.split_once('T').unwrap_or((datetime, "00:00:00"));
+
+    let mut date = date.split('-');
+    let y = date.next()?.parse::<i32>().ok()?;
+    let m = date
+        .next()?
+        .parse::<u8>()
+        .ok()
+        .filter(|m| (1..=12).contains(m))?;
+    let d = date
+        .next()?
+        .parse::<u8>()
+        .ok()
+        .filter(|d| (1..=31).contains(d))?;
+
+    let mut time = time.split(':');
+    let hh = time.next()?.parse::<u8>().ok().filter(|hh| *hh < 24)?;
+    let mm = time.next()?.parse::<u8>().ok().filter(|mm| *mm < 60)?;
+    let ss = time.next()?.parse::<u8>().ok().filter(|ss| *ss < 60)?;
+
+    if date.next().is_some() || time.next().is_some() {
+        return None;
+    }
+
+    Some((y, m, d, hh, mm, ss))
+}
+
+/// Launch identification of a satellite, from either
+/// the SBAS database or the satellite catalog.
+#[cfg(feature = "cospar")]
+#[derive(Deserialize)]
+struct LaunchEntry<'a> {
+    /// COSPAR (launch) identification code
+    #[serde(default)]
+    pub cospar: Option<&'a str>,
+
+    /// NORAD catalog number
+    #[serde(default)]
+    pub norad: Option<u32>,
+
+    /// Launch datetime
+    pub launch: &'a str,
+}
+
+/// Gathers the COSPAR designators, NORAD catalog numbers and launch datetimes
+/// of all satellites (catalog and SBAS database), and stores them
+/// as a static array sorted by COSPAR designator, which does not require std-lib.
+#[cfg(feature = "cospar")]
+fn build_cospar_database() {
+    let outdir = std::env::var("OUT_DIR").unwrap();
+    let out_path = std::path::Path::new(&outdir).join("cospar.rs");
+
+    // The SBAS database describes the service entry of the GEOs,
+    // not their physical launch: only the catalog launch dates are used.
+    let mut databases = CATALOGS
+        .iter()
+        .map(|(file, _)| (format!("data/catalog/{}", file), true))
+        .collect::<Vec<_>>();
+
+    databases.push(("data/sbas.json".to_string(), false));
+
+    // (year, launch, piece, norad, launch datetime)
+    let mut table = Vec::<(u16, u16, String, u32, Option<String>)>::new();
+
+    for (db_path, launch_dates) in databases.iter() {
+        let db_content = std::fs::read_to_string(db_path).unwrap();
+
+        let entries: Vec<LaunchEntry> = serde_json::from_str(&db_content)
+            .unwrap_or_else(|e| panic!("invalid database {}: {}", db_path, e));
+
+        for entry in entries {
+            let (Some(cospar), Some(norad)) = (entry.cospar, entry.norad) else {
+                continue;
+            };
+
+            let (year, rem) = cospar
+                .split_once('-')
+                .unwrap_or_else(|| panic!("{}: invalid COSPAR \"{}\"", db_path, cospar));
+
+            let year = year
+                .parse::<u16>()
+                .unwrap_or_else(|_| panic!("{}: invalid COSPAR \"{}\"", db_path, cospar));
+
+            let launch = rem
+                .get(..3)
+                .and_then(|launch| launch.parse::<u16>().ok())
+                .unwrap_or_else(|| panic!("{}: invalid COSPAR \"{}\"", db_path, cospar));
 
-    std::fs::write(out_path, bytes).unwrap_or_else(|e| {
-        panic!("failed to serialize polygon map: {}", e);
+            let piece = rem[3..].to_string();
+
+            let datetime = if *launch_dates {
+                let (y, m, d, hh, mm, ss) = parse_utc_datetime(entry.launch).unwrap_or_else(|| {
+                    panic!("{}: invalid launch datetime \"{}\"", db_path, entry.launch)
+                });
+
+                Some(format!("({}, {}, {}, {}, {}, {})", y, m, d, hh, mm, ss))
+            } else {
+                None
+            };
+
+            // a satellite may be described in several databases (BeiDou GEOs..)
+            match table
+                .iter()
+                .find(|(y, l, p, _, _)| *y == year && *l == launch && *p == piece)
+            {
+                Some((_, _, _, known, _)) => {
+                    assert_eq!(
+                        *known, norad,
+                        "{}: inconsistent NORAD number for {}",
+                        db_path, cospar
+                    );
+                },
+                None => {
+                    table.push((year, launch, piece, norad, datetime));
+                },
+            }
+        }
+    }
+
+    // chronological order: year, launch, then piece (A..Z, AA..)
+    table.sort_by(|a, b| (a.0, a.1, a.2.len(), &a.2).cmp(&(b.0, b.1, b.2.len(), &b.2)));
+
+    let mut content = String::from(
+        "/// COSPAR designators, as (launch year, launch number, piece code),
+/// NORAD catalog numbers and UTC launch datetimes (year, month, day, hours, minutes, seconds)
+/// of the satellites we know about.
+type CosparEntry = (u16, u16, &'static str, u32, Option<(i32, u8, u8, u8, u8, u8)>);
+
+/// Database sorted by COSPAR designator
+const COSPAR_DATABASE: &[CosparEntry] = &[
+",
+    );
+
+    for (year, launch, piece, norad, datetime) in table.iter() {
+        let datetime = match datetime {
+            Some(datetime) => format!("Some({})", datetime),
+            None => "None".to_string(),
+        };
+
+        content.push_str(&format!(
+            "    ({}, {}, {:?}, {}, {}),\n",
+            year, launch, piece, norad, datetime
+        ));
+    }
+
+    content.push_str("];\n");
+
+    std::fs::write(out_path, content).unwrap_or_else(|e| {
+        panic!("failed to write COSPAR database: {}", e);
+    });
+}
+
+/// PRN assignment of a catalog entry, regarding its orbit
+#[derive(Deserialize)]
+struct OrbitPrnEntry<'a> {
+    /// PRN number
+    pub prn: u8,
+
+    /// Assignment end datetime, None if still assigned
+    pub end: Option<&'a str>,
+}
+
+/// Orbit of a catalog entry
+#[derive(Deserialize)]
+struct OrbitEntry<'a> {
+    /// Orbit class, MEO when omitted
+    #[serde(default)]
+    pub orbit: Option<&'a str>,
+
+    /// Decommission datetime
+    #[serde(default)]
+    pub decommission: Option<&'a str>,
+
+    /// PRN assignments
+    #[serde(borrow)]
+    pub prn: Vec<OrbitPrnEntry<'a>>,
+}
+
+/// Gathers the orbit class of the satellites currently broadcasting each PRN
+/// (other than MEO) from the satellite catalog, as a static array
+/// which does not require std-lib.
+fn build_catalog_orbits() {
+    let outdir = std::env::var("OUT_DIR").unwrap();
+    let out_path = std::path::Path::new(&outdir).join("orbits.rs");
+
+    let mut content = String::from(
+        "/// Orbit class of the satellites currently broadcasting each PRN,
+/// when they are not on MEO, from our builtin catalog.
+const CATALOG_ORBITS: &[(Constellation, u8, OrbitClass)] = &[
+",
+    );
+
+    for (file, constellation) in CATALOGS {
+        let db_path = format!("data/catalog/{}", file);
+        println!("cargo:rerun-if-changed={}", db_path);
+
+        let db_content = std::fs::read_to_string(&db_path).unwrap();
+
+        let catalog: Vec<OrbitEntry> = serde_json::from_str(&db_content)
+            .unwrap_or_else(|e| panic!("invalid catalog {}: {}", db_path, e));
+
+        for entry in catalog {
+            let orbit = match entry.orbit {
+                Some("MEO") | None => continue,
+                Some(orbit) => orbit,
+            };
+
+            if entry.decommission.is_some() {
+                continue;
+            }
+
+            for assignment in entry.prn.iter().filter(|a| a.end.is_none()) {
+                content.push_str(&format!(
+                    "    (Constellation::{}, {}, OrbitClass::{}),\n",
+                    constellation, assignment.prn, orbit
+                ));
+            }
+        }
+    }
+
+    content.push_str("];\n");
+
+    std::fs::write(out_path, content).unwrap_or_else(|e| {
+        panic!("failed to write catalog orbits: {}", e);
     });
 }
 
@@ -192,12 +755,17 @@ fn main() {
     #[cfg(feature = "std")]
     build_sbas_helper();
 
-    #[cfg(all(feature = "sbas", feature = "std"))]
-    build_sbas_service_polygons();
+    #[cfg(feature = "std")]
+    build_catalog();
 
-    #[cfg(all(feature = "sbas", feature = "std"))]
+    build_sbas_coverage();
     println!("cargo:rerun-if-changed=data/coarse_sbas_coverage.geojson");
 
-    #[cfg(feature = "std")]
-    println!("cargo:rerun-if-changed=data/sbas.geojson");
+    build_catalog_orbits();
+
+    #[cfg(feature = "cospar")]
+    build_cospar_database();
+
+    #[cfg(any(feature = "std", feature = "cospar"))]
+    println!("cargo:rerun-if-changed=data/sbas.json");
 }
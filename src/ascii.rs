@@ -0,0 +1,142 @@
+//! Const (compile time) ASCII string helpers
+
+/// Returns true if `haystack` starts with `needle` at this offset, ignoring ASCII case.
+const fn matches_at(haystack: &[u8], offset: usize, needle: &[u8]) -> bool {
+    if offset + needle.len() > haystack.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < needle.len() {
+        if haystack[offset + i].to_ascii_lowercase() != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns true if `haystack` contains the (lowercase) `needle`,
+/// ignoring ASCII case, but not as part of the (lowercase) `excluded` pattern.
+pub(crate) const fn contains_except(haystack: &[u8], needle: &[u8], excluded: &[u8]) -> bool {
+    let mut offset = 0;
+    while offset < haystack.len() {
+        if matches_at(haystack, offset, needle)
+            && (excluded.is_empty() || !matches_at(haystack, offset, excluded))
+        {
+            return true;
+        }
+        offset += 1;
+    }
+    false
+}
+
+/// Returns true if `haystack` contains the (lowercase) `needle`, ignoring ASCII case.
+pub(crate) const fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    contains_except(haystack, needle, &[])
+}
+
+/// Parses a decimal [u8], possibly prefixed by '+', like [u8::from_str].
+pub(crate) const fn parse_u8(bytes: &[u8]) -> Option<u8> {
+    let bytes = match bytes.split_first() {
+        Some((b'+', rem)) => rem,
+        _ => bytes,
+    };
+
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mut value = 0u8;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add(bytes[i] - b'0') {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        i += 1;
+    }
+
+    Some(value)
+}
+
+/// Parses a fixed width decimal [u16], made of digits only (no sign nor blank).
+#[cfg(feature = "cospar")]
+pub(crate) const fn parse_digits_u16(bytes: &[u8]) -> Option<u16> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mut value = 0u16;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as u16) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        i += 1;
+    }
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{contains, contains_except, parse_u8};
+
+    #[test]
+    fn const_helpers() {
+        assert!(contains(b"GPS (US)", b"gps"));
+        assert!(!contains(b"GP", b"gps"));
+        assert!(contains_except(b"BDS", b"bds", b"bdsbas"));
+        assert!(!contains_except(b"BDSBAS", b"bds", b"bdsbas"));
+
+        for value in 0..=255u8 {
+            assert_eq!(parse_u8(value.to_string().as_bytes()), Some(value));
+        }
+
+        for content in ["", "+", "256", "1a", "-1", " 1"] {
+            assert_eq!(
+                parse_u8(content.as_bytes()),
+                None,
+                "failed for \"{}\"",
+                content
+            );
+        }
+
+        assert_eq!(parse_u8(b"+12"), Some(12));
+        assert_eq!(parse_u8(b"012"), Some(12));
+    }
+
+    #[test]
+    #[cfg(feature = "cospar")]
+    fn const_digits() {
+        use super::parse_digits_u16;
+
+        assert_eq!(parse_digits_u16(b"0080"), Some(80));
+        assert_eq!(parse_digits_u16(b"65535"), Some(65535));
+
+        for content in ["", "+1", "65536", " 1", "1-"] {
+            assert_eq!(parse_digits_u16(content.as_bytes()), None);
+        }
+    }
+}
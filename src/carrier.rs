@@ -0,0 +1,758 @@
+//! GNSS carrier signals (frequency bands)
+use crate::prelude::Constellation;
+use alloc::vec::Vec;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Speed of light in vacuum, in m/s
+pub(crate) const SPEED_OF_LIGHT_M_S: f64 = 299_792_458.0;
+
+/// Glonass G1 FDMA channel spacing, in Hz
+const G1_FDMA_SPACING_HZ: f64 = 562.5E3;
+
+/// Glonass G2 FDMA channel spacing, in Hz
+const G2_FDMA_SPACING_HZ: f64 = 437.5E3;
+
+/// Glonass FDMA frequency channels
+const GLONASS_FDMA_CHANNELS: core::ops::RangeInclusive<i8> = -7..=6;
+
+/// Carrier parsing issue
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParsingError {
+    #[error("unknown carrier")]
+    Unknown,
+}
+
+/// [Carrier] describes a GNSS carrier signal (frequency band).
+/// ```
+/// use gnss_rs::prelude::*;
+///
+/// assert_eq!(Carrier::L1.frequency_hz(), 1575.42E6);
+/// assert!((Carrier::L1.wavelength_m() - 0.1903).abs() < 1.0E-4);
+/// assert_eq!(Carrier::E5a.constellations(), &[Constellation::Galileo]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Carrier {
+    /// GPS, QZSS and SBAS L1
+    L1,
+
+    /// GPS and QZSS L2
+    L2,
+
+    /// GPS, QZSS, SBAS and IRNSS (NavIC) L5
+    L5,
+
+    /// Galileo E1
+    E1,
+
+    /// Galileo E5a
+    E5a,
+
+    /// Galileo E5b
+    E5b,
+
+    /// Galileo E5 (AltBOC E5a+E5b)
+    E5,
+
+    /// Galileo E6
+    E6,
+
+    /// BeiDou B1I
+    B1I,
+
+    /// BeiDou B1C
+    B1C,
+
+    /// BeiDou B2a
+    B2a,
+
+    /// BeiDou B2b
+    B2b,
+
+    /// BeiDou B3
+    B3,
+
+    /// Glonass G1 (nominal FDMA frequency)
+    G1,
+
+    /// Glonass G2 (nominal FDMA frequency)
+    G2,
+
+    /// Glonass G3 (CDMA)
+    G3,
+
+    /// QZSS L6 (formerly LEX)
+    L6,
+
+    /// IRNSS (NavIC) S band
+    S,
+}
+
+impl Carrier {
+    /// All [Carrier]s, in order of declaration.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::L1,
+        Self::L2,
+        Self::L5,
+        Self::E1,
+        Self::E5a,
+        Self::E5b,
+        Self::E5,
+        Self::E6,
+        Self::B1I,
+        Self::B1C,
+        Self::B2a,
+        Self::B2b,
+        Self::B3,
+        Self::G1,
+        Self::G2,
+        Self::G3,
+        Self::L6,
+        Self::S,
+    ];
+
+    /// Returns the carrier frequency, in Hz.
+    /// For Glonass FDMA signals (G1 and G2), this is the nominal frequency (channel 0),
+    /// see [Carrier::glonass_fdma_frequency_hz].
+    pub const fn frequency_hz(&self) -> f64 {
+        match self {
+            Self::L1 | Self::E1 | Self::B1C => 1575.42E6,
+            Self::L2 => 1227.60E6,
+            Self::L5 | Self::E5a | Self::B2a => 1176.45E6,
+            Self::E5b | Self::B2b => 1207.14E6,
+            Self::E5 => 1191.795E6,
+            Self::E6 | Self::L6 => 1278.75E6,
+            Self::B1I => 1561.098E6,
+            Self::B3 => 1268.52E6,
+            Self::G1 => 1602.0E6,
+            Self::G2 => 1246.0E6,
+            Self::G3 => 1202.025E6,
+            Self::S => 2492.028E6,
+        }
+    }
+
+    /// Returns the carrier wavelength, in meters.
+    pub fn wavelength_m(&self) -> f64 {
+        SPEED_OF_LIGHT_M_S / self.frequency_hz()
+    }
+
+    /// Converts a carrier phase (in cycles) measured on this [Carrier] to meters.
+    /// For Glonass FDMA carriers (G1 and G2), this uses the nominal frequency:
+    /// prefer [Carrier::glonass_fdma_cycles_to_meters].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let meters = Carrier::L1.cycles_to_meters(100.0);
+    /// assert!((meters - 19.029).abs() < 1.0E-3);
+    ///
+    /// let cycles = Carrier::L1.meters_to_cycles(meters);
+    /// assert!((cycles - 100.0).abs() < 1.0E-9);
+    /// ```
+    pub fn cycles_to_meters(&self, cycles: f64) -> f64 {
+        cycles * self.wavelength_m()
+    }
+
+    /// Converts a carrier phase (in meters) to cycles of this [Carrier].
+    /// This is the reciprocal of [Carrier::cycles_to_meters].
+    pub fn meters_to_cycles(&self, meters: f64) -> f64 {
+        meters / self.wavelength_m()
+    }
+
+    /// Converts a carrier phase (in cycles) measured on this Glonass FDMA [Carrier]
+    /// (G1 or G2) to meters, for this frequency channel.
+    /// Returns [None] for other carriers, see [Carrier::cycles_to_meters].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let meters = Carrier::G1.glonass_fdma_cycles_to_meters(100.0, 6).unwrap();
+    /// let cycles = Carrier::G1.glonass_fdma_meters_to_cycles(meters, 6).unwrap();
+    /// assert!((cycles - 100.0).abs() < 1.0E-9);
+    ///
+    /// assert!(Carrier::L1.glonass_fdma_cycles_to_meters(100.0, 6).is_none());
+    /// ```
+    pub fn glonass_fdma_cycles_to_meters(&self, cycles: f64, channel: i8) -> Option<f64> {
+        self.glonass_fdma_wavelength_m(channel)
+            .map(|wavelength| cycles * wavelength)
+    }
+
+    /// Converts a carrier phase (in meters) to cycles of this Glonass FDMA [Carrier]
+    /// (G1 or G2), for this frequency channel.
+    /// This is the reciprocal of [Carrier::glonass_fdma_cycles_to_meters].
+    pub fn glonass_fdma_meters_to_cycles(&self, meters: f64, channel: i8) -> Option<f64> {
+        self.glonass_fdma_wavelength_m(channel)
+            .map(|wavelength| meters / wavelength)
+    }
+
+    /// Converts a Doppler shift (in Hz) measured on this [Carrier] to a range rate (in m/s).
+    /// Following the RINEX convention, Doppler shifts are positive for approaching satellites,
+    /// which have a negative range rate. For Glonass FDMA carriers (G1 and G2), this uses
+    /// the nominal frequency: prefer [Carrier::glonass_fdma_doppler_to_range_rate].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let range_rate = Carrier::L1.doppler_to_range_rate(1000.0);
+    /// assert!((range_rate + 190.29).abs() < 1.0E-2);
+    ///
+    /// let doppler = Carrier::L1.range_rate_to_doppler(range_rate);
+    /// assert!((doppler - 1000.0).abs() < 1.0E-9);
+    /// ```
+    pub fn doppler_to_range_rate(&self, doppler_hz: f64) -> f64 {
+        -doppler_hz * self.wavelength_m()
+    }
+
+    /// Converts a range rate (in m/s) to the Doppler shift (in Hz) on this [Carrier].
+    /// This is the reciprocal of [Carrier::doppler_to_range_rate].
+    pub fn range_rate_to_doppler(&self, range_rate_m_s: f64) -> f64 {
+        -range_rate_m_s / self.wavelength_m()
+    }
+
+    /// Converts a Doppler shift (in Hz) measured on this Glonass FDMA [Carrier] (G1 or G2)
+    /// to a range rate (in m/s), for this frequency channel.
+    /// Returns [None] for other carriers, see [Carrier::doppler_to_range_rate].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let range_rate = Carrier::G1.glonass_fdma_doppler_to_range_rate(1000.0, -7).unwrap();
+    /// let doppler = Carrier::G1.glonass_fdma_range_rate_to_doppler(range_rate, -7).unwrap();
+    /// assert!((doppler - 1000.0).abs() < 1.0E-9);
+    ///
+    /// assert!(Carrier::G3.glonass_fdma_doppler_to_range_rate(1000.0, -7).is_none());
+    /// ```
+    pub fn glonass_fdma_doppler_to_range_rate(&self, doppler_hz: f64, channel: i8) -> Option<f64> {
+        self.glonass_fdma_wavelength_m(channel)
+            .map(|wavelength| -doppler_hz * wavelength)
+    }
+
+    /// Converts a range rate (in m/s) to the Doppler shift (in Hz) on this Glonass FDMA
+    /// [Carrier] (G1 or G2), for this frequency channel.
+    /// This is the reciprocal of [Carrier::glonass_fdma_doppler_to_range_rate].
+    pub fn glonass_fdma_range_rate_to_doppler(
+        &self,
+        range_rate_m_s: f64,
+        channel: i8,
+    ) -> Option<f64> {
+        self.glonass_fdma_wavelength_m(channel)
+            .map(|wavelength| -range_rate_m_s / wavelength)
+    }
+
+    /// Returns the carrier frequency (in Hz) of Glonass FDMA signals (G1 and G2)
+    /// for this frequency channel (-7..=+6), see
+    /// [SV::glonass_fdma_channel](crate::prelude::SV::glonass_fdma_channel).
+    /// Returns [None] for other carriers.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Carrier::G1.glonass_fdma_frequency_hz(1), Some(1602.5625E6));
+    /// assert_eq!(Carrier::G2.glonass_fdma_frequency_hz(-7), Some(1242.9375E6));
+    /// assert_eq!(Carrier::L1.glonass_fdma_frequency_hz(1), None);
+    /// ```
+    pub fn glonass_fdma_frequency_hz(&self, channel: i8) -> Option<f64> {
+        let spacing = match self {
+            Self::G1 => G1_FDMA_SPACING_HZ,
+            Self::G2 => G2_FDMA_SPACING_HZ,
+            _ => return None,
+        };
+        Some(self.frequency_hz() + channel as f64 * spacing)
+    }
+
+    /// Identifies the Glonass FDMA [Carrier] (G1 or G2) and frequency channel
+    /// closest to this frequency (in Hz), within this tolerance (in Hz).
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Carrier::from_glonass_fdma_frequency_hz(1602.5625E6, 1.0E3),
+    ///     Some((Carrier::G1, 1))
+    /// );
+    /// assert_eq!(
+    ///     Carrier::from_glonass_fdma_frequency_hz(1242.9375E6, 1.0E3),
+    ///     Some((Carrier::G2, -7))
+    /// );
+    /// assert_eq!(Carrier::from_glonass_fdma_frequency_hz(1575.42E6, 1.0E3), None);
+    /// ```
+    pub fn from_glonass_fdma_frequency_hz(
+        frequency_hz: f64,
+        tolerance_hz: f64,
+    ) -> Option<(Self, i8)> {
+        [Self::G1, Self::G2]
+            .into_iter()
+            .flat_map(|carrier| {
+                GLONASS_FDMA_CHANNELS.map(move |channel| {
+                    let error = carrier
+                        .glonass_fdma_frequency_hz(channel)
+                        .map(|frequency| (frequency - frequency_hz).abs())
+                        .unwrap_or(f64::INFINITY);
+                    (carrier, channel, error)
+                })
+            })
+            .filter(|(_, _, error)| *error <= tolerance_hz)
+            .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+            .map(|(carrier, channel, _)| (carrier, channel))
+    }
+
+    /// Identifies the [Carrier] closest to this frequency (in Hz), within this tolerance (in Hz).
+    /// Glonass G1 and G2 are identified on any of their FDMA channels.
+    /// Several carriers share the same frequency (like L1, E1 and B1C): the first one
+    /// in order of declaration prevails. Prefer [Carrier::from_constellation_frequency_hz]
+    /// when the constellation is known.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Carrier::from_frequency_hz(1575.42E6, 1.0), Some(Carrier::L1));
+    /// assert_eq!(Carrier::from_frequency_hz(1227.5E6, 1.0E6), Some(Carrier::L2));
+    /// assert_eq!(Carrier::from_frequency_hz(1604.8125E6, 1.0E3), Some(Carrier::G1));
+    /// assert_eq!(Carrier::from_frequency_hz(1500.0E6, 1.0E6), None);
+    /// ```
+    pub fn from_frequency_hz(frequency_hz: f64, tolerance_hz: f64) -> Option<Self> {
+        Self::closest(Self::VARIANTS.iter().copied(), frequency_hz, tolerance_hz)
+    }
+
+    /// Identifies the [Carrier] broadcasted by this [Constellation], closest to this
+    /// frequency (in Hz), within this tolerance (in Hz). See [Carrier::from_frequency_hz].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Carrier::from_constellation_frequency_hz(Constellation::Galileo, 1575.42E6, 1.0),
+    ///     Some(Carrier::E1)
+    /// );
+    /// assert_eq!(
+    ///     Carrier::from_constellation_frequency_hz(Constellation::BeiDou, 1575.42E6, 1.0),
+    ///     Some(Carrier::B1C)
+    /// );
+    /// assert_eq!(
+    ///     Carrier::from_constellation_frequency_hz(Constellation::GPS, 1278.75E6, 1.0),
+    ///     None
+    /// );
+    /// ```
+    pub fn from_constellation_frequency_hz(
+        constellation: Constellation,
+        frequency_hz: f64,
+        tolerance_hz: f64,
+    ) -> Option<Self> {
+        Self::closest(
+            Self::VARIANTS
+                .iter()
+                .copied()
+                .filter(|carrier| carrier.is_broadcasted_by(constellation)),
+            frequency_hz,
+            tolerance_hz,
+        )
+    }
+
+    /// Returns the [Carrier] closest to this frequency, within tolerance.
+    /// The first candidate prevails in case of equality.
+    fn closest<I: Iterator<Item = Self>>(
+        candidates: I,
+        frequency_hz: f64,
+        tolerance_hz: f64,
+    ) -> Option<Self> {
+        candidates
+            .map(|carrier| {
+                let error = match carrier {
+                    Self::G1 | Self::G2 => GLONASS_FDMA_CHANNELS
+                        .filter_map(|channel| carrier.glonass_fdma_frequency_hz(channel))
+                        .map(|frequency| (frequency - frequency_hz).abs())
+                        .fold(f64::INFINITY, f64::min),
+                    _ => (carrier.frequency_hz() - frequency_hz).abs(),
+                };
+                (carrier, error)
+            })
+            .filter(|(_, error)| *error <= tolerance_hz)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(carrier, _)| carrier)
+    }
+
+    /// Returns the carrier wavelength (in meters) of Glonass FDMA signals (G1 and G2)
+    /// for this frequency channel, see [Carrier::glonass_fdma_frequency_hz].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let lambda = Carrier::G2.glonass_fdma_wavelength_m(-2).unwrap();
+    /// assert!((lambda - 0.24077).abs() < 1.0E-5);
+    /// assert_eq!(Carrier::L2.glonass_fdma_wavelength_m(-2), None);
+    /// ```
+    pub fn glonass_fdma_wavelength_m(&self, channel: i8) -> Option<f64> {
+        self.glonass_fdma_frequency_hz(channel)
+            .map(|frequency| SPEED_OF_LIGHT_M_S / frequency)
+    }
+
+    /// Returns the Glonass FDMA frequency channel of this [Carrier] (G1 or G2)
+    /// closest to this frequency (in Hz), within this tolerance (in Hz).
+    /// This is the reciprocal of [Carrier::glonass_fdma_frequency_hz].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Carrier::G1.glonass_fdma_channel(1601.4375E6, 1.0E3), Some(-1));
+    /// assert_eq!(Carrier::G2.glonass_fdma_channel(1601.4375E6, 1.0E3), None);
+    /// ```
+    pub fn glonass_fdma_channel(&self, frequency_hz: f64, tolerance_hz: f64) -> Option<i8> {
+        Self::from_glonass_fdma_frequency_hz(frequency_hz, tolerance_hz)
+            .filter(|(carrier, _)| carrier == self)
+            .map(|(_, channel)| channel)
+    }
+
+    /// Returns the [Constellation]s broadcasting this [Carrier].
+    /// Augmentation systems are described as [Constellation::SBAS].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Carrier::L5.constellations(),
+    ///     &[Constellation::GPS, Constellation::QZSS, Constellation::IRNSS, Constellation::SBAS]
+    /// );
+    /// ```
+    pub const fn constellations(&self) -> &'static [Constellation] {
+        match self {
+            Self::L1 => &[Constellation::GPS, Constellation::QZSS, Constellation::SBAS],
+            Self::L2 => &[Constellation::GPS, Constellation::QZSS],
+            Self::L5 => &[
+                Constellation::GPS,
+                Constellation::QZSS,
+                Constellation::IRNSS,
+                Constellation::SBAS,
+            ],
+            Self::E1 | Self::E5a | Self::E5b | Self::E5 | Self::E6 => &[Constellation::Galileo],
+            Self::B1I | Self::B1C | Self::B2a | Self::B2b | Self::B3 => &[Constellation::BeiDou],
+            Self::G1 | Self::G2 | Self::G3 => &[Constellation::Glonass],
+            Self::L6 => &[Constellation::QZSS],
+            Self::S => &[Constellation::IRNSS],
+        }
+    }
+
+    /// Returns true if this [Carrier] is broadcasted by this [Constellation].
+    /// Any augmentation system matches [Constellation::SBAS] carriers.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert!(Carrier::L1.is_broadcasted_by(Constellation::EGNOS));
+    /// assert!(!Carrier::E6.is_broadcasted_by(Constellation::GPS));
+    /// ```
+    pub fn is_broadcasted_by(&self, constellation: Constellation) -> bool {
+        let constellation = if constellation.is_sbas() {
+            Constellation::SBAS
+        } else {
+            constellation
+        };
+        self.constellations().contains(&constellation)
+    }
+
+    /// Returns the lower and upper sidebands of this AltBOC composite [Carrier]:
+    /// Galileo E5 is the combination of E5a (lower) and E5b (upper), centered in between.
+    /// Returns [None] for other carriers.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let (lower, upper) = Carrier::E5.sidebands().unwrap();
+    /// assert_eq!((lower, upper), (Carrier::E5a, Carrier::E5b));
+    /// assert_eq!(
+    ///     Carrier::E5.frequency_hz(),
+    ///     (lower.frequency_hz() + upper.frequency_hz()) / 2.0
+    /// );
+    ///
+    /// assert!(Carrier::E5a.sidebands().is_none());
+    ///
+    /// // E5 observables use RINEX band 8
+    /// let observable = Observable::from_rinex(Constellation::Galileo, "C8Q").unwrap();
+    /// assert_eq!(observable.carrier, Carrier::E5);
+    /// ```
+    pub const fn sidebands(&self) -> Option<(Self, Self)> {
+        match self {
+            Self::E5 => Some((Self::E5a, Self::E5b)),
+            _ => None,
+        }
+    }
+
+    /// Returns the AltBOC composite [Carrier] this [Carrier] is a sideband of,
+    /// which is the reciprocal of [Carrier::sidebands].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Carrier::E5b.composite(), Some(Carrier::E5));
+    /// assert_eq!(Carrier::L5.composite(), None);
+    /// ```
+    pub const fn composite(&self) -> Option<Self> {
+        match self {
+            Self::E5a | Self::E5b => Some(Self::E5),
+            _ => None,
+        }
+    }
+
+    /// Returns true if both [Carrier]s share the same center frequency, which makes
+    /// them interoperable: they can be processed by the same receiver channel
+    /// (like L1, E1 and B1C at 1575.42 MHz). Glonass FDMA carriers (G1 and G2)
+    /// are only interoperable with themselves.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert!(Carrier::L5.is_interoperable(&Carrier::E5a));
+    /// assert!(Carrier::E6.is_interoperable(&Carrier::L6));
+    /// assert!(!Carrier::L1.is_interoperable(&Carrier::B1I));
+    /// ```
+    pub fn is_interoperable(&self, other: &Self) -> bool {
+        self == other || self.frequency_hz() == other.frequency_hz()
+    }
+
+    /// Returns all [Carrier]s interoperable with this one (itself included),
+    /// in order of declaration, see [Carrier::is_interoperable].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Carrier::E1.interoperable_carriers(),
+    ///     vec![Carrier::L1, Carrier::E1, Carrier::B1C]
+    /// );
+    /// assert_eq!(Carrier::G1.interoperable_carriers(), vec![Carrier::G1]);
+    /// ```
+    pub fn interoperable_carriers(&self) -> Vec<Self> {
+        Self::VARIANTS
+            .iter()
+            .filter(|carrier| self.is_interoperable(carrier))
+            .copied()
+            .collect()
+    }
+
+    /// Groups all [Carrier]s into interoperable bands (see [Carrier::is_interoperable]),
+    /// indexed by center frequency (in Hz), by decreasing frequency.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let bands = Carrier::interoperable_bands();
+    ///
+    /// let (frequency, carriers) = &bands[1];
+    /// assert_eq!(*frequency, 1602.0E6);
+    /// assert_eq!(carriers, &vec![Carrier::G1]);
+    ///
+    /// let l5 = bands.iter().find(|(frequency, _)| *frequency == 1176.45E6).unwrap();
+    /// assert_eq!(l5.1, vec![Carrier::L5, Carrier::E5a, Carrier::B2a]);
+    /// ```
+    pub fn interoperable_bands() -> Vec<(f64, Vec<Self>)> {
+        let mut bands = Vec::<(f64, Vec<Self>)>::new();
+
+        for carrier in Self::VARIANTS {
+            let frequency = carrier.frequency_hz();
+            match bands.iter_mut().find(|(band, _)| *band == frequency) {
+                Some((_, carriers)) => carriers.push(*carrier),
+                None => bands.push((frequency, alloc::vec![*carrier])),
+            }
+        }
+
+        bands.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        bands
+    }
+
+    /// Returns the usual name of this [Carrier], like "E5a".
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::L1 => "L1",
+            Self::L2 => "L2",
+            Self::L5 => "L5",
+            Self::E1 => "E1",
+            Self::E5a => "E5a",
+            Self::E5b => "E5b",
+            Self::E5 => "E5",
+            Self::E6 => "E6",
+            Self::B1I => "B1I",
+            Self::B1C => "B1C",
+            Self::B2a => "B2a",
+            Self::B2b => "B2b",
+            Self::B3 => "B3",
+            Self::G1 => "G1",
+            Self::G2 => "G2",
+            Self::G3 => "G3",
+            Self::L6 => "L6",
+            Self::S => "S",
+        }
+    }
+}
+
+impl core::fmt::Display for Carrier {
+    /// Formats this [Carrier] by its usual name, like "E5a".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::str::FromStr for Carrier {
+    type Err = ParsingError;
+
+    /// Parses [Carrier] from its usual name (case insensitive), like "E5a".
+    /// "LEX" is accepted for [Carrier::L6].
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+
+        if string.eq_ignore_ascii_case("LEX") {
+            return Ok(Self::L6);
+        }
+
+        Self::VARIANTS
+            .iter()
+            .find(|carrier| string.eq_ignore_ascii_case(carrier.name()))
+            .copied()
+            .ok_or(ParsingError::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Carrier, GLONASS_FDMA_CHANNELS};
+    use crate::prelude::Constellation;
+    use std::str::FromStr;
+
+    #[test]
+    fn carrier_parsing() {
+        for carrier in Carrier::VARIANTS {
+            let name = carrier.to_string();
+            assert_eq!(Carrier::from_str(&name), Ok(*carrier));
+            assert_eq!(Carrier::from_str(&name.to_lowercase()), Ok(*carrier));
+        }
+
+        assert_eq!(Carrier::from_str("LEX"), Ok(Carrier::L6));
+        assert!(Carrier::from_str("L3").is_err());
+    }
+
+    #[test]
+    fn carrier_frequencies() {
+        for carrier in Carrier::VARIANTS {
+            for constellation in carrier.constellations() {
+                // consistent with the constellation frequency plan
+                assert!(
+                    constellation
+                        .carrier_frequencies()
+                        .contains(&carrier.frequency_hz()),
+                    "{} is not a {} frequency",
+                    carrier,
+                    constellation
+                );
+            }
+
+            let wavelength = carrier.wavelength_m();
+            assert!(wavelength > 0.1 && wavelength < 0.3);
+        }
+
+        assert!(Carrier::L1.is_broadcasted_by(Constellation::WAAS));
+        assert!(Carrier::S.is_broadcasted_by(Constellation::IRNSS));
+        assert!(!Carrier::S.is_broadcasted_by(Constellation::GPS));
+    }
+
+    #[test]
+    fn frequency_identification() {
+        for carrier in Carrier::VARIANTS {
+            let frequency = carrier.frequency_hz();
+
+            for constellation in carrier.constellations() {
+                assert_eq!(
+                    Carrier::from_constellation_frequency_hz(*constellation, frequency, 1.0),
+                    Some(*carrier),
+                    "failed to identify {} {}",
+                    constellation,
+                    carrier
+                );
+            }
+
+            let identified = Carrier::from_frequency_hz(frequency + 100.0, 1.0E3).unwrap();
+            assert_eq!(identified.frequency_hz(), frequency);
+            assert_eq!(Carrier::from_frequency_hz(frequency + 100.0, 10.0), None);
+        }
+
+        for carrier in [Carrier::G1, Carrier::G2] {
+            for channel in -7..=6 {
+                let frequency = carrier.glonass_fdma_frequency_hz(channel).unwrap();
+                assert_eq!(
+                    Carrier::from_glonass_fdma_frequency_hz(frequency + 10.0E3, 100.0E3),
+                    Some((carrier, channel))
+                );
+                assert_eq!(Carrier::from_frequency_hz(frequency, 1.0), Some(carrier));
+            }
+        }
+
+        assert_eq!(
+            Carrier::from_constellation_frequency_hz(Constellation::EGNOS, 1176.45E6, 1.0),
+            Some(Carrier::L5)
+        );
+    }
+
+    #[test]
+    fn interoperability() {
+        let bands = Carrier::interoperable_bands();
+
+        assert_eq!(bands[0], (2492.028E6, vec![Carrier::S]));
+
+        let mut count = 0;
+        for (frequency, carriers) in bands.iter() {
+            for carrier in carriers.iter() {
+                assert_eq!(carrier.frequency_hz(), *frequency);
+                assert_eq!(carrier.interoperable_carriers(), *carriers);
+            }
+            count += carriers.len();
+        }
+
+        assert_eq!(count, Carrier::VARIANTS.len());
+
+        for pair in bands.windows(2) {
+            assert!(pair[0].0 > pair[1].0);
+        }
+    }
+
+    #[test]
+    fn doppler_conversions() {
+        for carrier in Carrier::VARIANTS {
+            for doppler in [-5000.0, -1.0, 0.0, 1.0, 5000.0] {
+                let range_rate = carrier.doppler_to_range_rate(doppler);
+                assert!((carrier.range_rate_to_doppler(range_rate) - doppler).abs() < 1.0E-9);
+
+                // approaching satellites
+                if doppler > 0.0 {
+                    assert!(range_rate < 0.0);
+                }
+            }
+        }
+
+        let nominal = Carrier::G1.doppler_to_range_rate(1000.0);
+        let channel0 = Carrier::G1
+            .glonass_fdma_doppler_to_range_rate(1000.0, 0)
+            .unwrap();
+        let channel6 = Carrier::G1
+            .glonass_fdma_doppler_to_range_rate(1000.0, 6)
+            .unwrap();
+
+        assert_eq!(nominal, channel0);
+        assert!(channel6 > nominal);
+    }
+
+    #[test]
+    fn cycles_conversions() {
+        for carrier in Carrier::VARIANTS {
+            let meters = carrier.cycles_to_meters(1.0);
+            assert_eq!(meters, carrier.wavelength_m());
+            assert!((carrier.meters_to_cycles(meters) - 1.0).abs() < 1.0E-12);
+        }
+
+        for channel in GLONASS_FDMA_CHANNELS {
+            for carrier in [Carrier::G1, Carrier::G2] {
+                let meters = carrier.glonass_fdma_cycles_to_meters(1.0, channel).unwrap();
+                assert_eq!(Some(meters), carrier.glonass_fdma_wavelength_m(channel));
+            }
+        }
+    }
+
+    #[test]
+    fn altboc_sidebands() {
+        for carrier in Carrier::VARIANTS {
+            if let Some((lower, upper)) = carrier.sidebands() {
+                assert!(lower.frequency_hz() < carrier.frequency_hz());
+                assert!(upper.frequency_hz() > carrier.frequency_hz());
+                assert_eq!(lower.composite(), Some(*carrier));
+                assert_eq!(upper.composite(), Some(*carrier));
+                assert_eq!(lower.constellations(), carrier.constellations());
+            }
+        }
+    }
+}
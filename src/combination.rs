@@ -0,0 +1,393 @@
+//! Dual frequency linear combinations
+use crate::{
+    carrier::SPEED_OF_LIGHT_M_S,
+    prelude::{Carrier, Constellation},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Dual frequency [Combination] kinds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum CombinationKind {
+    /// Ionosphere free combination, cancelling the first order ionospheric delay
+    IonosphereFree,
+
+    /// Wide lane combination, with a long wavelength (ambiguity resolution)
+    WideLane,
+
+    /// Narrow lane combination, with reduced noise
+    NarrowLane,
+
+    /// Melbourne-Wübbena combination: wide lane phase minus narrow lane code,
+    /// free of geometry, clocks and ionosphere
+    MelbourneWubbena,
+}
+
+/// [Combination] describes the dual frequency linear combination
+/// of two observations expressed in meters (phase or code).
+/// ```
+/// use gnss_rs::prelude::*;
+///
+/// let iono_free = Combination::from_carriers(
+///     CombinationKind::IonosphereFree,
+///     Constellation::GPS,
+///     Carrier::L1,
+///     Carrier::L2,
+/// )
+/// .unwrap();
+///
+/// let (a, b) = iono_free.coefficients();
+/// assert!((a - 2.5457).abs() < 1.0E-4);
+/// assert!((b + 1.5457).abs() < 1.0E-4);
+///
+/// let wide_lane = Combination::from_carriers(
+///     CombinationKind::WideLane,
+///     Constellation::GPS,
+///     Carrier::L1,
+///     Carrier::L2,
+/// )
+/// .unwrap();
+///
+/// let lambda = wide_lane.wavelength_m().unwrap();
+/// assert!((lambda - 0.8619).abs() < 1.0E-4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Combination {
+    /// [CombinationKind]
+    pub kind: CombinationKind,
+
+    /// Frequency of the first signal, in Hz
+    pub lhs_frequency_hz: f64,
+
+    /// Frequency of the second signal, in Hz
+    pub rhs_frequency_hz: f64,
+}
+
+impl Combination {
+    /// Builds a new [Combination] from two signal frequencies (in Hz).
+    pub const fn new(kind: CombinationKind, lhs_frequency_hz: f64, rhs_frequency_hz: f64) -> Self {
+        Self {
+            kind,
+            lhs_frequency_hz,
+            rhs_frequency_hz,
+        }
+    }
+
+    /// Builds a new [Combination] of two [Carrier]s broadcasted by this [Constellation].
+    /// Returns [None] if one [Carrier] is not broadcasted by this [Constellation],
+    /// both share the same frequency, or for Glonass FDMA carriers (G1, G2)
+    /// which require [Combination::from_glonass_fdma_carriers].
+    pub fn from_carriers(
+        kind: CombinationKind,
+        constellation: Constellation,
+        lhs: Carrier,
+        rhs: Carrier,
+    ) -> Option<Self> {
+        if !lhs.is_broadcasted_by(constellation) || !rhs.is_broadcasted_by(constellation) {
+            return None;
+        }
+
+        let frequency = |carrier: Carrier| match carrier {
+            Carrier::G1 | Carrier::G2 => None,
+            carrier => Some(carrier.frequency_hz()),
+        };
+
+        Self::from_frequencies(kind, frequency(lhs)?, frequency(rhs)?)
+    }
+
+    /// Builds a new [Combination] of two Glonass [Carrier]s, for this FDMA frequency channel
+    /// (see [Carrier::glonass_fdma_frequency_hz]). Returns [None] if one [Carrier]
+    /// is not broadcasted by Glonass or both share the same frequency.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let iono_free = Combination::from_glonass_fdma_carriers(
+    ///     CombinationKind::IonosphereFree,
+    ///     Carrier::G1,
+    ///     Carrier::G2,
+    ///     1,
+    /// )
+    /// .unwrap();
+    ///
+    /// // G1/G2 frequency ratio is always 9/7
+    /// assert_eq!(iono_free.coefficients(), (81.0 / 32.0, -49.0 / 32.0));
+    /// ```
+    pub fn from_glonass_fdma_carriers(
+        kind: CombinationKind,
+        lhs: Carrier,
+        rhs: Carrier,
+        channel: i8,
+    ) -> Option<Self> {
+        if !lhs.is_broadcasted_by(Constellation::Glonass)
+            || !rhs.is_broadcasted_by(Constellation::Glonass)
+        {
+            return None;
+        }
+
+        let frequency = |carrier: Carrier| {
+            carrier
+                .glonass_fdma_frequency_hz(channel)
+                .unwrap_or(carrier.frequency_hz())
+        };
+
+        Self::from_frequencies(kind, frequency(lhs), frequency(rhs))
+    }
+
+    /// Builds a new [Combination], verifying both frequencies differ.
+    fn from_frequencies(
+        kind: CombinationKind,
+        lhs_frequency_hz: f64,
+        rhs_frequency_hz: f64,
+    ) -> Option<Self> {
+        if lhs_frequency_hz == rhs_frequency_hz {
+            None
+        } else {
+            Some(Self::new(kind, lhs_frequency_hz, rhs_frequency_hz))
+        }
+    }
+
+    /// Returns the coefficients applied to both observations (in meters).
+    /// For [CombinationKind::MelbourneWubbena], these are the (wide lane)
+    /// phase coefficients, see [Combination::code_coefficients].
+    pub fn coefficients(&self) -> (f64, f64) {
+        let (f1, f2) = (self.lhs_frequency_hz, self.rhs_frequency_hz);
+        match self.kind {
+            CombinationKind::IonosphereFree => {
+                let denom = f1 * f1 - f2 * f2;
+                (f1 * f1 / denom, -f2 * f2 / denom)
+            },
+            CombinationKind::WideLane | CombinationKind::MelbourneWubbena => {
+                (f1 / (f1 - f2), -f2 / (f1 - f2))
+            },
+            CombinationKind::NarrowLane => (f1 / (f1 + f2), f2 / (f1 + f2)),
+        }
+    }
+
+    /// Returns the coefficients applied to both code observations (in meters)
+    /// of the [CombinationKind::MelbourneWubbena] combination, which
+    /// are added to the phase observations, see [Combination::coefficients].
+    /// Returns [None] for other combinations, which apply [Combination::coefficients]
+    /// to either phase or code observations.
+    pub fn code_coefficients(&self) -> Option<(f64, f64)> {
+        let (f1, f2) = (self.lhs_frequency_hz, self.rhs_frequency_hz);
+        match self.kind {
+            CombinationKind::MelbourneWubbena => Some((-f1 / (f1 + f2), -f2 / (f1 + f2))),
+            _ => None,
+        }
+    }
+
+    /// Returns the frequency (in Hz) of this [Combination].
+    /// The ionosphere free combination has no meaningful frequency.
+    pub fn frequency_hz(&self) -> Option<f64> {
+        let (f1, f2) = (self.lhs_frequency_hz, self.rhs_frequency_hz);
+        match self.kind {
+            CombinationKind::IonosphereFree => None,
+            CombinationKind::WideLane | CombinationKind::MelbourneWubbena => Some((f1 - f2).abs()),
+            CombinationKind::NarrowLane => Some(f1 + f2),
+        }
+    }
+
+    /// Returns the wavelength (in meters) of this [Combination].
+    /// The ionosphere free combination has no meaningful wavelength.
+    pub fn wavelength_m(&self) -> Option<f64> {
+        self.frequency_hz()
+            .map(|frequency| SPEED_OF_LIGHT_M_S / frequency)
+    }
+
+    /// Converts a combined carrier phase (in cycles of this [Combination]) to meters.
+    /// Returns [None] for the ionosphere free combination, which has no meaningful wavelength.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let wide_lane = Combination::from_carriers(
+    ///     CombinationKind::WideLane,
+    ///     Constellation::GPS,
+    ///     Carrier::L1,
+    ///     Carrier::L2,
+    /// )
+    /// .unwrap();
+    ///
+    /// let meters = wide_lane.cycles_to_meters(10.0).unwrap();
+    /// assert!((meters - 8.619).abs() < 1.0E-3);
+    ///
+    /// let cycles = wide_lane.meters_to_cycles(meters).unwrap();
+    /// assert!((cycles - 10.0).abs() < 1.0E-9);
+    /// ```
+    pub fn cycles_to_meters(&self, cycles: f64) -> Option<f64> {
+        self.wavelength_m().map(|wavelength| cycles * wavelength)
+    }
+
+    /// Converts a combined carrier phase (in meters) to cycles of this [Combination].
+    /// This is the reciprocal of [Combination::cycles_to_meters].
+    pub fn meters_to_cycles(&self, meters: f64) -> Option<f64> {
+        self.wavelength_m().map(|wavelength| meters / wavelength)
+    }
+
+    /// Returns the noise amplification factor of this [Combination], for observations
+    /// of identical noise (in meters). For [CombinationKind::MelbourneWubbena], phase noise
+    /// is neglected: this is the amplification of the code noise.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let iono_free = Combination::from_carriers(
+    ///     CombinationKind::IonosphereFree,
+    ///     Constellation::GPS,
+    ///     Carrier::L1,
+    ///     Carrier::L2,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!((iono_free.noise_amplification() - 2.978).abs() < 1.0E-3);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn noise_amplification(&self) -> f64 {
+        let (a, b) = self.code_coefficients().unwrap_or(self.coefficients());
+        a.hypot(b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Combination, CombinationKind};
+    use crate::prelude::{Carrier, Constellation};
+
+    fn combination(
+        kind: CombinationKind,
+        constellation: Constellation,
+        lhs: Carrier,
+        rhs: Carrier,
+    ) -> Combination {
+        Combination::from_carriers(kind, constellation, lhs, rhs).unwrap()
+    }
+
+    #[test]
+    fn published_values() {
+        for (constellation, lhs, rhs, iono_free, wide_lane, narrow_lane) in [
+            (
+                Constellation::GPS,
+                Carrier::L1,
+                Carrier::L2,
+                (2.5457, -1.5457),
+                0.8619,
+                0.1070,
+            ),
+            (
+                Constellation::GPS,
+                Carrier::L1,
+                Carrier::L5,
+                (2.2606, -1.2606),
+                0.7514,
+                0.1089,
+            ),
+            (
+                Constellation::Galileo,
+                Carrier::E1,
+                Carrier::E5a,
+                (2.2606, -1.2606),
+                0.7514,
+                0.1089,
+            ),
+            (
+                Constellation::Galileo,
+                Carrier::E1,
+                Carrier::E5b,
+                (2.4220, -1.4220),
+                0.8140,
+                0.1077,
+            ),
+        ] {
+            let (a, b) = combination(CombinationKind::IonosphereFree, constellation, lhs, rhs)
+                .coefficients();
+            assert!((a - iono_free.0).abs() < 1.0E-4, "{}/{}: {}", lhs, rhs, a);
+            assert!((b - iono_free.1).abs() < 1.0E-4, "{}/{}: {}", lhs, rhs, b);
+            assert!((a + b - 1.0).abs() < 1.0E-9);
+
+            let lambda = combination(CombinationKind::WideLane, constellation, lhs, rhs)
+                .wavelength_m()
+                .unwrap();
+            assert!(
+                (lambda - wide_lane).abs() < 1.0E-4,
+                "{}/{}: {}",
+                lhs,
+                rhs,
+                lambda
+            );
+
+            let lambda = combination(CombinationKind::NarrowLane, constellation, lhs, rhs)
+                .wavelength_m()
+                .unwrap();
+            assert!(
+                (lambda - narrow_lane).abs() < 1.0E-4,
+                "{}/{}: {}",
+                lhs,
+                rhs,
+                lambda
+            );
+
+            let mw = combination(CombinationKind::MelbourneWubbena, constellation, lhs, rhs);
+            let (phase1, phase2) = mw.coefficients();
+            let (code1, code2) = mw.code_coefficients().unwrap();
+
+            // geometry free
+            assert!((phase1 + phase2 + code1 + code2).abs() < 1.0E-9);
+
+            #[cfg(feature = "std")]
+            assert!((mw.noise_amplification() - 0.71).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn invalid_combinations() {
+        assert!(Combination::from_carriers(
+            CombinationKind::WideLane,
+            Constellation::GPS,
+            Carrier::L1,
+            Carrier::E5b
+        )
+        .is_none());
+
+        assert!(Combination::from_carriers(
+            CombinationKind::WideLane,
+            Constellation::GPS,
+            Carrier::L1,
+            Carrier::L1
+        )
+        .is_none());
+
+        assert!(Combination::from_carriers(
+            CombinationKind::WideLane,
+            Constellation::Glonass,
+            Carrier::G1,
+            Carrier::G2
+        )
+        .is_none());
+
+        for channel in -7..=6 {
+            let wide_lane = Combination::from_glonass_fdma_carriers(
+                CombinationKind::WideLane,
+                Carrier::G1,
+                Carrier::G2,
+                channel,
+            )
+            .unwrap();
+            assert!((wide_lane.wavelength_m().unwrap() - 0.84).abs() < 0.01);
+
+            assert!(Combination::from_glonass_fdma_carriers(
+                CombinationKind::NarrowLane,
+                Carrier::G1,
+                Carrier::G3,
+                channel,
+            )
+            .is_some());
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::Constellation;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for Constellation {
+    /// Picks any [Constellation], including [Constellation::Mixed].
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(Self::VARIANTS)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::Constellation;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_constellation() {
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+
+        while let Ok(constellation) = Constellation::arbitrary(&mut u) {
+            assert!(Constellation::VARIANTS.contains(&constellation));
+            if u.is_empty() {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::prelude::Constellation;
+use clap::{builder::PossibleValue, ValueEnum};
+
+impl ValueEnum for Constellation {
+    /// All [Constellation]s may be selected from the command line.
+    fn value_variants<'a>() -> &'a [Self] {
+        Self::VARIANTS
+    }
+
+    /// Possible values are lowercase names, while the single letter RINEX code
+    /// and the standard accronym are accepted as aliases.
+    /// All of them are compatible with [Constellation::from_str] reciprocal parsing.
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let value = match self {
+            Self::GPS => PossibleValue::new("gps").alias("g"),
+            Self::Glonass => PossibleValue::new("glonass").aliases(["glo", "r"]),
+            Self::BeiDou => PossibleValue::new("beidou").aliases(["bds", "c"]),
+            Self::QZSS => PossibleValue::new("qzss").alias("j"),
+            Self::Galileo => PossibleValue::new("galileo").aliases(["gal", "e"]),
+            Self::IRNSS => PossibleValue::new("irnss").aliases(["nav/ic", "i"]),
+            Self::WAAS => PossibleValue::new("waas"),
+            Self::EGNOS => PossibleValue::new("egnos"),
+            Self::MSAS => PossibleValue::new("msas"),
+            Self::GAGAN => PossibleValue::new("gagan"),
+            Self::BDSBAS => PossibleValue::new("bdsbas"),
+            Self::KASS => PossibleValue::new("kass"),
+            Self::SDCM => PossibleValue::new("sdcm"),
+            Self::ASBAS => PossibleValue::new("asbas"),
+            Self::SPAN => PossibleValue::new("span").alias("south-pan"),
+            Self::SBAS => PossibleValue::new("sbas").alias("s"),
+            Self::AusNZ => PossibleValue::new("aus/nz"),
+            Self::GBAS => PossibleValue::new("gbas"),
+            Self::NSAS => PossibleValue::new("nsas"),
+            Self::ASAL => PossibleValue::new("asal"),
+            Self::Mixed => PossibleValue::new("mixed").aliases(["mix", "m"]),
+        };
+
+        Some(value.help(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::Constellation;
+    use clap::ValueEnum;
+    use std::str::FromStr;
+
+    #[test]
+    fn value_enum() {
+        for constellation in Constellation::value_variants() {
+            let value = constellation.to_possible_value().unwrap();
+
+            for name in value.get_name_and_aliases() {
+                assert_eq!(
+                    <Constellation as FromStr>::from_str(name),
+                    Ok(*constellation),
+                    "\"{}\" is not FromStr compatible",
+                    name
+                );
+
+                assert_eq!(
+                    <Constellation as ValueEnum>::from_str(name, true),
+                    Ok(*constellation),
+                    "\"{}\" is not ValueEnum compatible",
+                    name
+                );
+            }
+        }
+    }
+}
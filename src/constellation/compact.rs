@@ -0,0 +1,131 @@
+//! Compact [Constellation] serialization, for large datasets.
+//!
+//! [Constellation]s are serialized as a small integer, which is their index
+//! in [Constellation::VARIANTS] (guaranteed not to change within a major version).
+//! Deserialization expects this integer. Human readable formats (like JSON)
+//! also accept any description compatible with [Constellation::from_str]
+//! (like the single RINEX letter).
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use gnss_rs::prelude::*;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "gnss_rs::constellation::compact")]
+//!     constellation: Constellation,
+//! }
+//! ```
+use crate::prelude::Constellation;
+use core::str::FromStr;
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes this [Constellation] as its index in [Constellation::VARIANTS].
+pub fn serialize<S: Serializer>(
+    constellation: &Constellation,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(constellation.index() as u8)
+}
+
+/// Deserializes a [Constellation] from its index in [Constellation::VARIANTS],
+/// or any readable description for human readable formats.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Constellation, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(CompactVisitor)
+    } else {
+        deserializer.deserialize_u8(CompactVisitor)
+    }
+}
+
+struct CompactVisitor;
+
+impl de::Visitor<'_> for CompactVisitor {
+    type Value = Constellation;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a constellation index or description")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Constellation::VARIANTS
+            .get(value as usize)
+            .copied()
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        if value < 0 {
+            Err(E::invalid_value(de::Unexpected::Signed(value), &self))
+        } else {
+            self.visit_u64(value as u64)
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Constellation::from_str(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::Constellation;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::constellation::compact")]
+        constellation: Constellation,
+    }
+
+    #[test]
+    fn compact_serdes() {
+        for constellation in Constellation::VARIANTS {
+            let record = Record {
+                constellation: *constellation,
+            };
+
+            let content = serde_json::to_string(&record).unwrap();
+
+            assert_eq!(
+                content,
+                format!("{{\"constellation\":{}}}", constellation.index())
+            );
+
+            let parsed: Record = serde_json::from_str(&content).unwrap();
+            assert_eq!(parsed, record);
+        }
+
+        for (content, expected) in [
+            ("{\"constellation\":\"G\"}", Constellation::GPS),
+            ("{\"constellation\":\"E\"}", Constellation::Galileo),
+            ("{\"constellation\":\"EGNOS\"}", Constellation::EGNOS),
+        ] {
+            let parsed: Record = serde_json::from_str(content).unwrap();
+            assert_eq!(parsed.constellation, expected);
+        }
+
+        assert!(serde_json::from_str::<Record>("{\"constellation\":100}").is_err());
+        assert!(serde_json::from_str::<Record>("{\"constellation\":\"?\"}").is_err());
+    }
+
+    #[test]
+    fn compact_binary_serdes() {
+        // not self describing
+        for constellation in Constellation::VARIANTS {
+            let record = Record {
+                constellation: *constellation,
+            };
+
+            let content = bincode::serialize(&record).unwrap();
+            assert_eq!(content, [constellation.index() as u8]);
+
+            let parsed: Record = bincode::deserialize(&content).unwrap();
+            assert_eq!(parsed, record);
+        }
+
+        assert!(bincode::deserialize::<Record>(&[100]).is_err());
+    }
+}
@@ -1,21 +1,104 @@
 //! GNSS constellations
-use hifitime::TimeScale;
+use alloc::string::{String, ToString};
+use core::ops::RangeInclusive;
+use hifitime::{Epoch, TimeScale};
 use thiserror::Error;
 
+use crate::{ascii, sv::SV};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::pyclass;
 
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "clap")]
+mod cli;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod compact;
+
 /// Constellation parsing & identification related errors
 #[derive(Error, Clone, Debug, PartialEq)]
 pub enum ParsingError {
-    #[error("Unknown constellation")]
-    Unknown,
+    #[error("empty constellation description")]
+    Empty,
+
+    #[error("unknown constellation \"{0}\"")]
+    Unknown(String),
+
+    #[error("ambiguous constellation description \"{0}\"")]
+    Ambiguous(String),
+
+    #[error("\"{0}\" is not an augmentation system")]
+    NotSbas(String),
+}
+
+/// Describes the service area (orbit architecture) of a [Constellation].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Coverage {
+    /// Worldwide navigation system, relying on MEO satellites
+    /// (possibly completed with GEO and IGSO satellites).
+    Global,
+
+    /// Regional navigation system, only serving a specific area
+    /// of the globe (for example [Constellation::QZSS] or [Constellation::IRNSS]).
+    Regional,
+
+    /// Geostationary augmentation service ([Constellation::is_sbas]).
+    Augmentation,
+}
+
+/// Service levels offered by an augmentation system ([Constellation::is_sbas]),
+/// see [Constellation::sbas_services]. Each service is described by the date
+/// it was declared available (UTC), [None] meaning it is not offered (yet).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SbasServices {
+    /// Open service (non safety critical use) declaration
+    pub open_service: Option<Epoch>,
+
+    /// Safety-of-life service certification (usable for aviation)
+    pub safety_of_life: Option<Epoch>,
+
+    /// GEO satellites usable as additional ranging sources
+    pub ranging: Option<Epoch>,
+
+    /// Ionospheric grid corrections broadcast (L1 service)
+    pub ionospheric_corrections: Option<Epoch>,
+}
+
+impl SbasServices {
+    /// Returns true if the open service was available at this [Epoch].
+    pub fn has_open_service(&self, epoch: Epoch) -> bool {
+        self.open_service.is_some_and(|t| t <= epoch)
+    }
+
+    /// Returns true if the safety-of-life service was certified at this [Epoch].
+    pub fn is_safety_of_life(&self, epoch: Epoch) -> bool {
+        self.safety_of_life.is_some_and(|t| t <= epoch)
+    }
+
+    /// Returns true if GEO ranging was available at this [Epoch].
+    pub fn has_ranging(&self, epoch: Epoch) -> bool {
+        self.ranging.is_some_and(|t| t <= epoch)
+    }
+
+    /// Returns true if ionospheric corrections were broadcast at this [Epoch].
+    pub fn has_ionospheric_corrections(&self, epoch: Epoch) -> bool {
+        self.ionospheric_corrections.is_some_and(|t| t <= epoch)
+    }
 }
 
 /// Describes all known `GNSS` constellations
@@ -23,6 +106,7 @@ pub enum ParsingError {
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "python", pyo3(module = "gnss"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum Constellation {
     /// American constellation
     #[default]
@@ -99,29 +183,37 @@ impl core::fmt::Display for Constellation {
     /// - "Glonass (RU)" for russian constellation
     ///
     /// If you don't want the country code (example: "GPS" for american constellation), use [core::fmt::UpperExp] formatting.
+    /// The alternate flag (`{:#}`) formats the full name without country code (example: "Glonass").
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        match self {
-            Self::GPS => write!(f, "GPS (US)"),
-            Self::Glonass => write!(f, "Glonass (RU)"),
-            Self::BeiDou => write!(f, "BeiDou (CH)"),
-            Self::QZSS => write!(f, "QZSS (JP)"),
-            Self::Galileo => write!(f, "Galileo (EU)"),
-            Self::IRNSS => write!(f, "IRNSS (IN)"),
-            Self::WAAS => write!(f, "WAAS (US)"),
-            Self::EGNOS => write!(f, "EGNOS (EU)"),
-            Self::MSAS => write!(f, "MSAS (JP)"),
-            Self::GAGAN => write!(f, "GAGAN (IN)"),
-            Self::BDSBAS => write!(f, "BDSBAS (CH)"),
-            Self::KASS => write!(f, "KASS (KR)"),
-            Self::SDCM => write!(f, "SDCM (RU)"),
-            Self::ASBAS => write!(f, "ASBAS (SA)"),
-            Self::SPAN => write!(f, "SPAN (AUS)"),
-            Self::SBAS => write!(f, "SBAS"),
-            Self::AusNZ => write!(f, "AUS/NZ (AUS)"),
-            Self::GBAS => write!(f, "GBAS (UK)"),
-            Self::NSAS => write!(f, "NSAS (NI)"),
-            Self::ASAL => write!(f, "ASAL (AL)"),
-            Self::Mixed => write!(f, "MIXED"),
+        let description = match self {
+            Self::GPS => "GPS (US)",
+            Self::Glonass => "Glonass (RU)",
+            Self::BeiDou => "BeiDou (CH)",
+            Self::QZSS => "QZSS (JP)",
+            Self::Galileo => "Galileo (EU)",
+            Self::IRNSS => "IRNSS (IN)",
+            Self::WAAS => "WAAS (US)",
+            Self::EGNOS => "EGNOS (EU)",
+            Self::MSAS => "MSAS (JP)",
+            Self::GAGAN => "GAGAN (IN)",
+            Self::BDSBAS => "BDSBAS (CH)",
+            Self::KASS => "KASS (KR)",
+            Self::SDCM => "SDCM (RU)",
+            Self::ASBAS => "ASBAS (SA)",
+            Self::SPAN => "SPAN (AUS)",
+            Self::SBAS => "SBAS",
+            Self::AusNZ => "AUS/NZ (AUS)",
+            Self::GBAS => "GBAS (UK)",
+            Self::NSAS => "NSAS (NI)",
+            Self::ASAL => "ASAL (AL)",
+            Self::Mixed => "MIXED",
+        };
+
+        if f.alternate() {
+            let name = description.split(" (").next().unwrap_or(description);
+            write!(f, "{}", name)
+        } else {
+            write!(f, "{}", description)
         }
     }
 }
@@ -146,29 +238,7 @@ impl core::fmt::UpperExp for Constellation {
     /// - "GBAS" for UK geo service
     /// - "MIX" for [Constellation::MIXED] setup
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        match self {
-            Self::GPS => write!(f, "GPS"),
-            Self::Glonass => write!(f, "GLO"),
-            Self::BeiDou => write!(f, "BDS"),
-            Self::QZSS => write!(f, "QZSS"),
-            Self::Galileo => write!(f, "GAL"),
-            Self::IRNSS => write!(f, "IRNSS"),
-            Self::WAAS => write!(f, "WAAS"),
-            Self::EGNOS => write!(f, "EGNOS"),
-            Self::MSAS => write!(f, "MSAS"),
-            Self::GAGAN => write!(f, "GAGAN"),
-            Self::BDSBAS => write!(f, "BDSBAS"),
-            Self::KASS => write!(f, "KASS"),
-            Self::SDCM => write!(f, "SDCM"),
-            Self::ASBAS => write!(f, "ASBAS"),
-            Self::SPAN => write!(f, "SPAN"),
-            Self::SBAS => write!(f, "SBAS"),
-            Self::AusNZ => write!(f, "AUS/NZ"),
-            Self::GBAS => write!(f, "GBAS"),
-            Self::NSAS => write!(f, "NSAS"),
-            Self::ASAL => write!(f, "ASAL"),
-            Self::Mixed => write!(f, "MIX"),
-        }
+        f.write_str(self.as_str())
     }
 }
 
@@ -208,8 +278,101 @@ impl core::fmt::LowerHex for Constellation {
 }
 
 impl Constellation {
+    /// All [Constellation]s, in order of declaration.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::GPS,
+        Self::Glonass,
+        Self::BeiDou,
+        Self::QZSS,
+        Self::Galileo,
+        Self::IRNSS,
+        Self::WAAS,
+        Self::EGNOS,
+        Self::MSAS,
+        Self::GAGAN,
+        Self::BDSBAS,
+        Self::KASS,
+        Self::SDCM,
+        Self::ASBAS,
+        Self::SPAN,
+        Self::SBAS,
+        Self::AusNZ,
+        Self::GBAS,
+        Self::NSAS,
+        Self::ASAL,
+        Self::Mixed,
+    ];
+
+    /// Total number of [Constellation]s.
+    pub const COUNT: usize = Self::VARIANTS.len();
+
+    /// Returns the index of this [Constellation] in [Constellation::VARIANTS].
+    pub const fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the stable short name (accronym) of this [Constellation],
+    /// which is also its [core::fmt::UpperExp] formatting.
+    /// Any output here is compatible with [Constellation::from_str] reciprocal parsing.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Constellation::GPS.as_str(), "GPS");
+    /// assert_eq!(Constellation::Glonass.as_str(), "GLO");
+    /// assert_eq!(Constellation::Mixed.as_str(), "MIX");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::GPS => "GPS",
+            Self::Glonass => "GLO",
+            Self::BeiDou => "BDS",
+            Self::QZSS => "QZSS",
+            Self::Galileo => "GAL",
+            Self::IRNSS => "IRNSS",
+            Self::WAAS => "WAAS",
+            Self::EGNOS => "EGNOS",
+            Self::MSAS => "MSAS",
+            Self::GAGAN => "GAGAN",
+            Self::BDSBAS => "BDSBAS",
+            Self::KASS => "KASS",
+            Self::SDCM => "SDCM",
+            Self::ASBAS => "ASBAS",
+            Self::SPAN => "SPAN",
+            Self::SBAS => "SBAS",
+            Self::AusNZ => "AUS/NZ",
+            Self::GBAS => "GBAS",
+            Self::NSAS => "NSAS",
+            Self::ASAL => "ASAL",
+            Self::Mixed => "MIX",
+        }
+    }
+
+    /// Parses an augmentation system ([Constellation::is_sbas]) from given description,
+    /// with the same rules as [Constellation::from_str]. This is to be used
+    /// in contexts where only an augmentation system is expected.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::constellation::ParsingError;
+    ///
+    /// assert_eq!(Constellation::from_sbas_str("EGNOS"), Ok(Constellation::EGNOS));
+    ///
+    /// assert_eq!(
+    ///     Constellation::from_sbas_str("GPS"),
+    ///     Err(ParsingError::NotSbas("GPS".to_string())),
+    /// );
+    /// ```
+    pub fn from_sbas_str(string: &str) -> Result<Self, ParsingError> {
+        let constellation = string.parse::<Self>()?;
+
+        if constellation.is_sbas() {
+            Ok(constellation)
+        } else {
+            Err(ParsingError::NotSbas(string.trim().to_string()))
+        }
+    }
+
     /// Returns true if Self is an augmentation system
-    pub fn is_sbas(&self) -> bool {
+    pub const fn is_sbas(&self) -> bool {
         matches!(
             *self,
             Self::WAAS
@@ -229,6 +392,245 @@ impl Constellation {
         )
     }
 
+    /// Returns the [Coverage] (service area) of this [Constellation].
+    /// Returns [None] for [Constellation::Mixed], which is not a navigation system.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::constellation::Coverage;
+    ///
+    /// assert_eq!(Constellation::GPS.coverage(), Some(Coverage::Global));
+    /// assert_eq!(Constellation::QZSS.coverage(), Some(Coverage::Regional));
+    /// assert_eq!(Constellation::EGNOS.coverage(), Some(Coverage::Augmentation));
+    /// assert_eq!(Constellation::Mixed.coverage(), None);
+    /// ```
+    pub fn coverage(&self) -> Option<Coverage> {
+        match self {
+            Self::GPS | Self::Glonass | Self::Galileo | Self::BeiDou => Some(Coverage::Global),
+            Self::QZSS | Self::IRNSS => Some(Coverage::Regional),
+            c => {
+                if c.is_sbas() {
+                    Some(Coverage::Augmentation)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+
+    /// Returns true if this [Constellation] is a global navigation system.
+    pub fn is_global(&self) -> bool {
+        self.coverage() == Some(Coverage::Global)
+    }
+
+    /// Returns true if this [Constellation] is a regional navigation system,
+    /// only serving a limited area of the globe.
+    pub fn is_regional(&self) -> bool {
+        self.coverage() == Some(Coverage::Regional)
+    }
+
+    /// Returns the carrier frequencies (in Hz) broadcasted by this [Constellation],
+    /// in the order listed below. Returns an empty list for [Constellation::Mixed].
+    ///
+    /// For example:
+    /// - L1, L2, L5 for [Constellation::GPS]
+    /// - E1, E5a, E5b, E5 and E6 for [Constellation::Galileo]
+    /// - B1I, B1C, B2a, B2b and B3 for [Constellation::BeiDou]
+    /// - G1, G2 (nominal FDMA frequencies) and G3 for [Constellation::Glonass]
+    /// - L1, L2, L5 and L6 for [Constellation::QZSS]
+    /// - L5 and S for [Constellation::IRNSS]
+    /// - L1 and L5 for any SBAS
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Constellation::GPS.carrier_frequencies(),
+    ///     &[1575.42E6, 1227.60E6, 1176.45E6]
+    /// );
+    ///
+    /// assert!(Constellation::Galileo.carrier_frequencies().contains(&1278.75E6));
+    /// ```
+    pub fn carrier_frequencies(&self) -> &'static [f64] {
+        match self {
+            Self::GPS => &[1575.42E6, 1227.60E6, 1176.45E6],
+            Self::Glonass => &[1602.0E6, 1246.0E6, 1202.025E6],
+            Self::Galileo => &[1575.42E6, 1176.45E6, 1207.14E6, 1191.795E6, 1278.75E6],
+            Self::BeiDou => &[1561.098E6, 1575.42E6, 1176.45E6, 1207.14E6, 1268.52E6],
+            Self::QZSS => &[1575.42E6, 1227.60E6, 1176.45E6, 1278.75E6],
+            Self::IRNSS => &[1176.45E6, 2492.028E6],
+            c => {
+                if c.is_sbas() {
+                    &[1575.42E6, 1176.45E6]
+                } else {
+                    &[]
+                }
+            },
+        }
+    }
+
+    /// Returns the agency operating this [Constellation], when known.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Constellation::GPS.operator(), Some("U.S. Space Force"));
+    /// assert_eq!(Constellation::Galileo.operator(), Some("EUSPA/ESA"));
+    /// assert_eq!(Constellation::EGNOS.operator(), Some("EUSPA/ESSP"));
+    /// assert_eq!(Constellation::SBAS.operator(), None);
+    /// ```
+    pub fn operator(&self) -> Option<&'static str> {
+        match self {
+            Self::GPS => Some("U.S. Space Force"),
+            Self::Glonass => Some("Roscosmos"),
+            Self::Galileo => Some("EUSPA/ESA"),
+            Self::BeiDou => Some("CNSA"),
+            Self::QZSS => Some("QZS System Services"),
+            Self::IRNSS => Some("ISRO"),
+            Self::WAAS => Some("FAA"),
+            Self::EGNOS => Some("EUSPA/ESSP"),
+            Self::MSAS => Some("JCAB"),
+            Self::GAGAN => Some("AAI/ISRO"),
+            Self::BDSBAS => Some("CNSA"),
+            Self::KASS => Some("KARI"),
+            Self::SDCM => Some("Roscosmos"),
+            Self::ASBAS => Some("ASECNA"),
+            Self::SPAN | Self::AusNZ => Some("Geoscience Australia/LINZ"),
+            Self::NSAS => Some("NIGCOMSAT"),
+            Self::ASAL => Some("ASAL"),
+            _ => None,
+        }
+    }
+
+    /// Returns the date of the first satellite launch of this [Constellation]
+    /// (experimental satellites included), expressed as UTC [Epoch].
+    /// No data may exist prior this date.
+    /// Returns [None] when unknown, or not applicable (hosted payloads).
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let first_launch = Constellation::Galileo.first_launch()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(first_launch.year(), 2005);
+    /// ```
+    pub fn first_launch(&self) -> Option<Epoch> {
+        let (year, month, day) = match self {
+            Self::GPS => (1978, 2, 22),
+            Self::Glonass => (1982, 10, 12),
+            Self::Galileo => (2005, 12, 28),
+            Self::BeiDou => (2000, 10, 31),
+            Self::QZSS => (2010, 9, 11),
+            Self::IRNSS => (2013, 7, 1),
+            _ => return None,
+        };
+
+        Some(Epoch::from_gregorian_utc_at_midnight(year, month, day))
+    }
+
+    /// Returns the date this [Constellation] was declared fully operational,
+    /// expressed as UTC [Epoch]. For augmentation services, this is the date
+    /// of their safety-of-life service declaration.
+    /// Returns [None] when this has not happened (yet) or is unknown.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let foc = Constellation::BeiDou.full_operational_capability()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(foc.year(), 2020);
+    /// assert_eq!(Constellation::Galileo.full_operational_capability(), None);
+    /// ```
+    pub fn full_operational_capability(&self) -> Option<Epoch> {
+        let (year, month, day) = match self {
+            Self::GPS => (1995, 7, 17),
+            Self::Glonass => (1995, 12, 14),
+            Self::BeiDou => (2020, 7, 31),
+            Self::QZSS => (2018, 11, 1),
+            Self::WAAS => (2003, 7, 10),
+            Self::EGNOS => (2011, 3, 2),
+            Self::MSAS => (2007, 9, 27),
+            Self::GAGAN => (2015, 4, 21),
+            Self::KASS => (2023, 12, 28),
+            _ => return None,
+        };
+
+        Some(Epoch::from_gregorian_utc_at_midnight(year, month, day))
+    }
+
+    /// Returns the service levels offered by this augmentation system ([Constellation::is_sbas]).
+    /// Returns [None] for other constellations, for generic [Constellation::SBAS]
+    /// and for [Constellation::GBAS], which is not broadcast by satellites.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let egnos = Constellation::EGNOS.sbas_services().unwrap();
+    ///
+    /// let t = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+    /// assert!(egnos.has_open_service(t));
+    /// assert!(egnos.has_ionospheric_corrections(t));
+    /// assert!(!egnos.is_safety_of_life(t)); // certified in 2011
+    /// assert!(egnos.has_ranging(t));
+    ///
+    /// // SouthPAN is not certified for aviation (yet)
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// let span = Constellation::SPAN.sbas_services().unwrap();
+    /// assert!(span.has_open_service(t));
+    /// assert!(!span.is_safety_of_life(t));
+    /// assert!(!span.has_ranging(t));
+    ///
+    /// assert!(Constellation::GPS.sbas_services().is_none());
+    /// assert!(Constellation::GBAS.sbas_services().is_none());
+    /// ```
+    pub fn sbas_services(&self) -> Option<SbasServices> {
+        let date =
+            |(year, month, day)| Some(Epoch::from_gregorian_utc_at_midnight(year, month, day));
+
+        // (open service, safety of life, GEO ranging, ionospheric corrections)
+        let (open_service, safety_of_life, ranging, ionospheric_corrections) = match self {
+            Self::WAAS => (
+                date((2000, 8, 24)),
+                date((2003, 7, 10)),
+                date((2003, 7, 10)),
+                date((2000, 8, 24)),
+            ),
+            Self::EGNOS => (
+                date((2009, 10, 1)),
+                date((2011, 3, 2)),
+                date((2009, 10, 1)),
+                date((2009, 10, 1)),
+            ),
+            Self::MSAS => (
+                date((2007, 9, 27)),
+                date((2007, 9, 27)),
+                date((2007, 9, 27)),
+                date((2007, 9, 27)),
+            ),
+            Self::GAGAN => (
+                date((2013, 12, 30)),
+                date((2015, 4, 21)),
+                None,
+                date((2013, 12, 30)),
+            ),
+            Self::KASS => (
+                date((2023, 12, 28)),
+                date((2023, 12, 28)),
+                None,
+                date((2023, 12, 28)),
+            ),
+            Self::BDSBAS => (date((2023, 1, 1)), None, None, date((2023, 1, 1))),
+            Self::SDCM => (date((2016, 1, 1)), None, None, date((2016, 1, 1))),
+            Self::SPAN | Self::AusNZ => (date((2022, 9, 26)), None, None, date((2022, 9, 26))),
+            Self::ASBAS | Self::NSAS | Self::ASAL => (None, None, None, None),
+            _ => return None,
+        };
+
+        Some(SbasServices {
+            open_service,
+            safety_of_life,
+            ranging,
+            ionospheric_corrections,
+        })
+    }
+
     /// Returns the country code two or three letter code,
     /// for this [Constellation], when that applies.
     /// For example:
@@ -279,9 +681,7 @@ impl Constellation {
             Some(Self::BeiDou)
         } else if lower.starts_with("ru") {
             Some(Self::Glonass)
-        } else if lower.starts_with("jp") {
-            Some(Self::QZSS)
-        } else if lower.starts_with("jap") {
+        } else if lower.starts_with("jp") || lower.starts_with("jap") {
             Some(Self::QZSS)
         } else if lower.starts_with("in") {
             Some(Self::IRNSS)
@@ -315,37 +715,184 @@ impl Constellation {
             Some(Self::BDSBAS)
         } else if lower.starts_with("ru") {
             Some(Self::SDCM)
-        } else if lower.starts_with("jp") {
-            Some(Self::MSAS)
-        } else if lower.starts_with("jap") {
+        } else if lower.starts_with("jp") || lower.starts_with("jap") {
             Some(Self::MSAS)
         } else if lower.starts_with("in") {
             Some(Self::GAGAN)
         } else if lower.starts_with("uk") {
             Some(Self::GBAS)
-        } else if lower.starts_with("sa") {
-            Some(Self::ASBAS)
-        } else if lower.starts_with("south-af") {
+        } else if lower.starts_with("sa") || lower.starts_with("south-af") {
             Some(Self::ASBAS)
-        } else if lower.starts_with("kr") {
+        } else if lower.starts_with("kr") || lower.starts_with("kor") {
             Some(Self::KASS)
-        } else if lower.starts_with("kor") {
-            Some(Self::KASS)
-        } else if lower.starts_with("australia") {
-            Some(Self::SPAN)
-        } else if lower.starts_with("new-zea") {
-            Some(Self::SPAN)
-        } else if lower.starts_with("nz") {
+        } else if lower.starts_with("australia")
+            || lower.starts_with("new-zea")
+            || lower.starts_with("nz")
+        {
             Some(Self::SPAN)
         } else {
             None
         }
     }
 
-    fn is_mixed(&self) -> bool {
+    /// Returns the augmentation systems ([Constellation::is_sbas]) providing
+    /// corrections for this core [Constellation].
+    /// Returns an empty list for augmentation systems themselves.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert!(Constellation::GPS.augmentations().contains(&Constellation::EGNOS));
+    /// assert_eq!(Constellation::Glonass.augmentations(), &[Constellation::SDCM]);
+    /// assert!(Constellation::EGNOS.augmentations().is_empty());
+    /// ```
+    pub fn augmentations(&self) -> &'static [Self] {
+        match self {
+            Self::GPS => &[
+                Self::WAAS,
+                Self::EGNOS,
+                Self::MSAS,
+                Self::GAGAN,
+                Self::BDSBAS,
+                Self::KASS,
+                Self::SDCM,
+                Self::SPAN,
+                Self::ASBAS,
+                Self::NSAS,
+                Self::ASAL,
+            ],
+            Self::Galileo => &[Self::EGNOS, Self::SPAN, Self::BDSBAS],
+            Self::BeiDou => &[Self::BDSBAS],
+            Self::Glonass => &[Self::SDCM],
+            _ => &[],
+        }
+    }
+
+    /// Returns the augmentation systems ([Constellation::is_sbas]) serving
+    /// the given country code or region (not case sensitive).
+    /// The main service comes first, followed by the candidate overlapping services.
+    /// Returns an empty list for unknown regions.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Constellation::augmentations_for_region("Europe")[0], Constellation::EGNOS);
+    /// assert_eq!(Constellation::augmentations_for_region("RU"), &[Constellation::SDCM, Constellation::EGNOS]);
+    /// assert!(Constellation::augmentations_for_region("Africa").contains(&Constellation::ASAL));
+    /// assert!(Constellation::augmentations_for_region("Antarctica").is_empty());
+    /// ```
+    pub fn augmentations_for_region(region: &str) -> &'static [Self] {
+        let lower = region.trim().to_lowercase();
+
+        if lower.starts_with("us") || lower.starts_with("north america") {
+            &[Self::WAAS]
+        } else if lower.starts_with("eu") {
+            &[Self::EGNOS]
+        } else if lower.starts_with("uk") {
+            &[Self::GBAS, Self::EGNOS]
+        } else if lower.starts_with("ru") {
+            &[Self::SDCM, Self::EGNOS]
+        } else if lower.starts_with("ch") {
+            &[Self::BDSBAS, Self::MSAS, Self::KASS]
+        } else if lower.starts_with("jp") || lower.starts_with("jap") {
+            &[Self::MSAS, Self::KASS, Self::BDSBAS]
+        } else if lower.starts_with("kr") || lower.starts_with("kor") {
+            &[Self::KASS, Self::MSAS, Self::BDSBAS]
+        } else if lower.starts_with("in") {
+            &[Self::GAGAN, Self::BDSBAS]
+        } else if lower.starts_with("sa") || lower.starts_with("south-af") {
+            &[Self::ASBAS]
+        } else if lower.starts_with("ng") || lower.starts_with("nigeria") {
+            &[Self::NSAS, Self::ASBAS]
+        } else if lower.starts_with("al") {
+            &[Self::ASAL, Self::EGNOS]
+        } else if lower.starts_with("africa") {
+            &[Self::ASBAS, Self::NSAS, Self::ASAL, Self::EGNOS]
+        } else if lower.starts_with("australia")
+            || lower.starts_with("new-zea")
+            || lower.starts_with("new zea")
+            || lower.starts_with("nz")
+        {
+            &[Self::SPAN]
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns true if Self is [Constellation::Mixed], describing
+    /// a combination of [Constellation]s.
+    pub fn is_mixed(&self) -> bool {
         *self == Self::Mixed
     }
 
+    /// Returns true if Self is a core navigation system
+    /// (GPS, Glonass, Galileo, BeiDou, QZSS or IRNSS),
+    /// as opposed to augmentation systems and [Constellation::Mixed].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert!(Constellation::GPS.is_core_gnss());
+    /// assert!(Constellation::QZSS.is_core_gnss());
+    /// assert!(!Constellation::EGNOS.is_core_gnss());
+    /// assert!(!Constellation::Mixed.is_core_gnss());
+    /// ```
+    pub fn is_core_gnss(&self) -> bool {
+        matches!(
+            *self,
+            Self::GPS | Self::Glonass | Self::Galileo | Self::BeiDou | Self::QZSS | Self::IRNSS
+        )
+    }
+
+    /// Returns the range of valid PRN numbers for this [Constellation].
+    /// Augmentation systems use the RINEX convention (PRN - 100), ranging from 20 to 58.
+    /// Returns [None] for [Constellation::Mixed].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// assert_eq!(Constellation::GPS.prn_range(), Some(1..=32));
+    /// assert_eq!(Constellation::EGNOS.prn_range(), Some(20..=58));
+    /// assert_eq!(Constellation::Mixed.prn_range(), None);
+    /// ```
+    pub fn prn_range(&self) -> Option<RangeInclusive<u8>> {
+        match self {
+            Self::GPS => Some(1..=32),
+            Self::Glonass => Some(1..=27),
+            Self::Galileo => Some(1..=36),
+            Self::BeiDou => Some(1..=63),
+            Self::QZSS => Some(1..=10),
+            Self::IRNSS => Some(1..=14),
+            c => {
+                if c.is_sbas() {
+                    Some(20..=58)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+
+    /// Iterates over all satellites of this [Constellation], over its valid
+    /// PRN range (see [Constellation::prn_range]), by increasing PRN number.
+    /// This does not tell whether these satellites actually exist.
+    /// Yields nothing for [Constellation::Mixed].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let gps = Constellation::GPS.sv_iter().collect::<Vec<_>>();
+    /// assert_eq!(gps.len(), 32);
+    /// assert_eq!(gps[0], sv!("G01"));
+    /// assert_eq!(gps[31], sv!("G32"));
+    ///
+    /// assert_eq!(Constellation::Mixed.sv_iter().count(), 0);
+    /// ```
+    pub fn sv_iter(&self) -> impl Iterator<Item = SV> {
+        let constellation = *self;
+        self.prn_range()
+            .into_iter()
+            .flatten()
+            .map(move |prn| SV::new(constellation, prn))
+    }
+
     /// Returns the [TimeScale] this [Constellation] represents.
     /// Returns [None] when this operation does not apply to given [Constellation].
     /// [Constellation::SBAS] are said to be refered to [TimeScale::GPST]
@@ -367,11 +914,120 @@ impl Constellation {
     }
 }
 
+impl Constellation {
+    /// Const (compile time) equivalent of [Constellation::from_str](core::str::FromStr),
+    /// which returns [None] on parsing failure. This is what the [gnss!](crate::gnss)
+    /// and [constellation!](crate::constellation) macros rely on.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// const GAL: Option<Constellation> = Constellation::from_str_const("Galileo");
+    /// assert_eq!(GAL, Some(Constellation::Galileo));
+    ///
+    /// assert_eq!(Constellation::from_str_const("GPS (US)"), Some(Constellation::GPS));
+    /// assert_eq!(Constellation::from_str_const("GPS+GAL"), None);
+    /// ```
+    pub const fn from_str_const(string: &str) -> Option<Self> {
+        let s = string.trim_ascii().as_bytes();
+
+        if s.is_empty() {
+            return None;
+        }
+
+        // single letter reciprocal
+        if s.len() == 1 {
+            match s[0].to_ascii_lowercase() {
+                b'g' => return Some(Self::GPS),
+                b'c' => return Some(Self::BeiDou),
+                b'e' => return Some(Self::Galileo),
+                b'r' => return Some(Self::Glonass),
+                b'j' => return Some(Self::QZSS),
+                b'i' => return Some(Self::IRNSS),
+                b's' => return Some(Self::SBAS),
+                b'm' => return Some(Self::Mixed),
+                _ => {},
+            }
+        }
+
+        // multi constellation descriptions (like "GPS+GAL")
+        // cannot be reduced to a single constellation
+        let core_matches = [
+            ascii::contains(s, b"gps"),
+            ascii::contains(s, b"glo"),
+            ascii::contains(s, b"gal"),
+            ascii::contains_except(s, b"bds", b"bdsbas") || ascii::contains(s, b"beidou"),
+            ascii::contains(s, b"qzss"),
+            ascii::contains(s, b"irnss") || ascii::contains(s, b"nav/ic"),
+        ];
+
+        let mut found = 0;
+        let mut i = 0;
+        while i < core_matches.len() {
+            if core_matches[i] {
+                found += 1;
+            }
+            i += 1;
+        }
+
+        if found > 1 {
+            return None;
+        }
+
+        // smart guess, in the same order as [Constellation::from_str]
+        const GUESSES: [(&[u8], Constellation); 29] = [
+            (b"gps", Constellation::GPS),
+            (b"glo", Constellation::Glonass),
+            (b"beidou", Constellation::BeiDou),
+            (b"bdsbas", Constellation::BDSBAS),
+            (b"bds", Constellation::BeiDou),
+            (b"galileo", Constellation::Galileo),
+            (b"qzss", Constellation::QZSS),
+            (b"irnss", Constellation::IRNSS),
+            (b"nav/ic", Constellation::IRNSS),
+            (b"span", Constellation::SPAN),
+            (b"south-pan", Constellation::SPAN),
+            (b"south pan", Constellation::SPAN),
+            (b"aus/nz", Constellation::AusNZ),
+            (b"australia", Constellation::SPAN),
+            (b"new-zealand", Constellation::SPAN),
+            (b"new zealand", Constellation::SPAN),
+            (b"waas", Constellation::WAAS),
+            (b"kass", Constellation::KASS),
+            (b"egnos", Constellation::EGNOS),
+            (b"gagan", Constellation::GAGAN),
+            (b"gbas", Constellation::GBAS),
+            (b"sdcm", Constellation::SDCM),
+            (b"msas", Constellation::MSAS),
+            (b"nsas", Constellation::NSAS),
+            (b"asbas", Constellation::ASBAS),
+            (b"asal", Constellation::ASAL),
+            (b"gal", Constellation::Galileo),
+            (b"mix", Constellation::Mixed),
+            (b"sbas", Constellation::SBAS),
+        ];
+
+        let mut i = 0;
+        while i < GUESSES.len() {
+            let (pattern, constellation) = GUESSES[i];
+            if ascii::contains(s, pattern) {
+                return Some(constellation);
+            }
+            i += 1;
+        }
+
+        None
+    }
+}
+
 impl core::str::FromStr for Constellation {
     type Err = ParsingError;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let s = string.trim().to_lowercase();
 
+        if s.is_empty() {
+            return Err(ParsingError::Empty);
+        }
+
         // single letter reciprocal
         match s.as_str() {
             "g" => return Ok(Self::GPS),
@@ -385,12 +1041,26 @@ impl core::str::FromStr for Constellation {
             _ => {},
         }
 
+        // multi constellation descriptions (like "GPS+GAL")
+        // cannot be reduced to a single constellation
+        let core = s.replace("bdsbas", "");
+        let core_matches = [
+            core.contains("gps"),
+            core.contains("glo"),
+            core.contains("gal"),
+            core.contains("bds") || core.contains("beidou"),
+            core.contains("qzss"),
+            core.contains("irnss") || core.contains("nav/ic"),
+        ];
+
+        if core_matches.iter().filter(|found| **found).count() > 1 {
+            return Err(ParsingError::Ambiguous(string.trim().to_string()));
+        }
+
         // smart guess
         if s.contains("gps") {
             Ok(Self::GPS)
-        } else if s.contains("glo") {
-            Ok(Self::Glonass)
-        } else if s.contains("glonass") {
+        } else if s.contains("glo") || s.contains("glonass") {
             Ok(Self::Glonass)
         } else if s.contains("beidou") {
             Ok(Self::BeiDou)
@@ -402,23 +1072,14 @@ impl core::str::FromStr for Constellation {
             Ok(Self::Galileo)
         } else if s.contains("qzss") {
             Ok(Self::QZSS)
-        } else if s.contains("irnss") {
+        } else if s.contains("irnss") || s.contains("nav/ic") {
             Ok(Self::IRNSS)
-        } else if s.contains("nav/ic") {
-            Ok(Self::IRNSS)
-        } else if s.contains("span") {
-            Ok(Self::SPAN)
-        } else if s.contains("south-pan") {
-            Ok(Self::SPAN)
-        } else if s.contains("south pan") {
+        } else if s.contains("span") || s.contains("south-pan") || s.contains("south pan") {
             Ok(Self::SPAN)
         } else if s.contains("aus/nz") {
             Ok(Self::AusNZ)
-        } else if s.contains("australia") {
-            Ok(Self::SPAN)
-        } else if s.contains("new-zealand") {
-            Ok(Self::SPAN)
-        } else if s.contains("new zealand") {
+        } else if s.contains("australia") || s.contains("new-zealand") || s.contains("new zealand")
+        {
             Ok(Self::SPAN)
         } else if s.contains("waas") {
             Ok(Self::WAAS)
@@ -436,8 +1097,6 @@ impl core::str::FromStr for Constellation {
             Ok(Self::MSAS)
         } else if s.contains("nsas") {
             Ok(Self::NSAS)
-        } else if s.contains("span") {
-            Ok(Self::SPAN)
         } else if s.contains("asbas") {
             Ok(Self::ASBAS)
         } else if s.contains("asal") {
@@ -449,7 +1108,7 @@ impl core::str::FromStr for Constellation {
         } else if s.contains("sbas") {
             Ok(Self::SBAS)
         } else {
-            Err(ParsingError::Unknown)
+            Err(ParsingError::Unknown(string.trim().to_string()))
         }
     }
 }
@@ -481,7 +1140,30 @@ mod tests {
             ("BDSBAS", Ok(Constellation::BDSBAS)),
             ("ASAL", Ok(Constellation::ASAL)),
             ("SDCM", Ok(Constellation::SDCM)),
+            ("", Err(ParsingError::Empty)),
+            ("  ", Err(ParsingError::Empty)),
+            ("X", Err(ParsingError::Unknown("X".to_string()))),
+            (
+                " Unknown ",
+                Err(ParsingError::Unknown("Unknown".to_string())),
+            ),
+            (
+                "GPS+GAL",
+                Err(ParsingError::Ambiguous("GPS+GAL".to_string())),
+            ),
+            (
+                "BeiDou/Glonass",
+                Err(ParsingError::Ambiguous("BeiDou/Glonass".to_string())),
+            ),
+            ("BDSBAS (CH)", Ok(Constellation::BDSBAS)),
         ] {
+            assert_eq!(
+                Constellation::from_str_const(desc),
+                expected.clone().ok(),
+                "const parsing failed for \"{}\"",
+                desc
+            );
+
             assert_eq!(
                 Constellation::from_str(desc),
                 expected,
@@ -491,6 +1173,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn accronym_reciprocal() {
+        for constellation in Constellation::VARIANTS {
+            let accronym = format!("{:E}", constellation);
+            assert_eq!(
+                Constellation::from_str(&accronym),
+                Ok(*constellation),
+                "\"{}\" is not reciprocal",
+                accronym
+            );
+        }
+    }
+
+    #[test]
+    fn const_parsing() {
+        for constellation in Constellation::VARIANTS {
+            for desc in [
+                constellation.to_string(),
+                format!("{:#}", constellation),
+                format!("{:E}", constellation),
+                format!("{:x}", constellation),
+                format!("{:?}", constellation),
+                format!("{:?}", constellation).to_lowercase(),
+            ] {
+                assert_eq!(
+                    Constellation::from_str_const(&desc),
+                    Constellation::from_str(&desc).ok(),
+                    "const parsing differs for \"{}\"",
+                    desc
+                );
+            }
+        }
+    }
+
     #[test]
     fn formating() {
         for (constellation, displayed, upper_exp, upper_hex) in [
@@ -516,6 +1232,79 @@ mod tests {
                 upper_exp
             );
         }
+
+        for constellation in Constellation::VARIANTS {
+            let alternate = format!("{:#}", constellation);
+            assert!(constellation.to_string().starts_with(&alternate));
+            assert_eq!(Constellation::from_str(&alternate), Ok(*constellation));
+        }
+
+        assert_eq!(format!("{:#}", Constellation::Glonass), "Glonass");
+        assert_eq!(format!("{:#}", Constellation::AusNZ), "AUS/NZ");
+    }
+
+    #[test]
+    fn sbas_services() {
+        for constellation in Constellation::VARIANTS {
+            let services = constellation.sbas_services();
+
+            if matches!(constellation, Constellation::SBAS | Constellation::GBAS)
+                || !constellation.is_sbas()
+            {
+                assert!(services.is_none());
+                continue;
+            }
+
+            let services = services.unwrap_or_else(|| panic!("{} is not described", constellation));
+
+            // safety of life is the full operational capability of augmentation services
+            assert_eq!(
+                services.safety_of_life,
+                constellation.full_operational_capability()
+            );
+
+            if let Some(safety_of_life) = services.safety_of_life {
+                assert!(services.open_service.is_some_and(|t| t <= safety_of_life));
+            }
+
+            // all L1 services broadcast an ionospheric grid
+            assert_eq!(
+                services.ionospheric_corrections.is_some(),
+                services.open_service.is_some(),
+                "{} ionospheric corrections",
+                constellation
+            );
+
+            assert_eq!(
+                services.ranging.is_some(),
+                matches!(
+                    constellation,
+                    Constellation::WAAS | Constellation::EGNOS | Constellation::MSAS
+                ),
+                "{} GEO ranging",
+                constellation
+            );
+        }
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(Constellation::COUNT, 21);
+
+        for (i, constellation) in Constellation::VARIANTS.iter().enumerate() {
+            assert_eq!(constellation.index(), i);
+
+            // no duplicates
+            assert!(!Constellation::VARIANTS[i + 1..].contains(constellation));
+
+            // reciprocal
+            assert_eq!(
+                Constellation::from_str(constellation.as_str()),
+                Ok(*constellation),
+                "reciprocal failed for {}",
+                constellation.as_str()
+            );
+        }
     }
 
     #[test]
@@ -549,6 +1338,162 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_coverage() {
+        for (constellation, coverage) in [
+            (Constellation::GPS, Some(Coverage::Global)),
+            (Constellation::Glonass, Some(Coverage::Global)),
+            (Constellation::Galileo, Some(Coverage::Global)),
+            (Constellation::BeiDou, Some(Coverage::Global)),
+            (Constellation::QZSS, Some(Coverage::Regional)),
+            (Constellation::IRNSS, Some(Coverage::Regional)),
+            (Constellation::WAAS, Some(Coverage::Augmentation)),
+            (Constellation::SBAS, Some(Coverage::Augmentation)),
+            (Constellation::Mixed, None),
+        ] {
+            assert_eq!(
+                constellation.coverage(),
+                coverage,
+                "invalid results for {}",
+                constellation
+            );
+
+            assert_eq!(
+                constellation.is_regional(),
+                coverage == Some(Coverage::Regional)
+            );
+
+            assert_eq!(
+                constellation.is_global(),
+                coverage == Some(Coverage::Global)
+            );
+        }
+    }
+
+    #[test]
+    fn test_carrier_frequencies() {
+        for constellation in Constellation::VARIANTS {
+            let frequencies = constellation.carrier_frequencies();
+
+            if constellation.is_mixed() {
+                assert!(frequencies.is_empty());
+            } else {
+                assert!(
+                    !frequencies.is_empty(),
+                    "no frequencies for {}",
+                    constellation
+                );
+            }
+
+            for frequency in frequencies {
+                assert!(
+                    *frequency > 1.0E9 && *frequency < 3.0E9,
+                    "invalid frequency for {}",
+                    constellation
+                );
+            }
+        }
+
+        assert_eq!(Constellation::IRNSS.carrier_frequencies().len(), 2);
+        assert_eq!(Constellation::EGNOS.carrier_frequencies().len(), 2);
+    }
+
+    #[test]
+    fn test_operator() {
+        for constellation in Constellation::VARIANTS {
+            if !constellation.is_sbas() && !constellation.is_mixed() {
+                assert!(
+                    constellation.operator().is_some(),
+                    "missing operator for {}",
+                    constellation
+                );
+            }
+        }
+
+        assert_eq!(Constellation::Mixed.operator(), None);
+    }
+
+    #[test]
+    fn test_milestones() {
+        for constellation in Constellation::VARIANTS {
+            if let Some(foc) = constellation.full_operational_capability() {
+                if let Some(first_launch) = constellation.first_launch() {
+                    assert!(
+                        first_launch < foc,
+                        "invalid milestones for {}",
+                        constellation
+                    );
+                }
+            }
+        }
+
+        assert!(Constellation::GPS.first_launch().is_some());
+        assert!(Constellation::Mixed.first_launch().is_none());
+        assert!(Constellation::Mixed.full_operational_capability().is_none());
+    }
+
+    #[test]
+    fn test_augmentations() {
+        for constellation in Constellation::VARIANTS {
+            for augmentation in constellation.augmentations() {
+                assert!(augmentation.is_sbas());
+            }
+        }
+
+        for (region, expected) in [
+            ("US", Constellation::WAAS),
+            ("USA", Constellation::WAAS),
+            ("Europe", Constellation::EGNOS),
+            ("India", Constellation::GAGAN),
+            ("Japan", Constellation::MSAS),
+            ("Korea", Constellation::KASS),
+            ("China", Constellation::BDSBAS),
+            ("Russia", Constellation::SDCM),
+            ("Nigeria", Constellation::NSAS),
+            ("Algeria", Constellation::ASAL),
+            ("New Zealand", Constellation::SPAN),
+        ] {
+            let augmentations = Constellation::augmentations_for_region(region);
+            assert_eq!(augmentations[0], expected, "invalid results for {}", region);
+
+            for augmentation in augmentations {
+                assert!(augmentation.is_sbas());
+            }
+        }
+    }
+
+    #[test]
+    fn test_classification() {
+        for constellation in Constellation::VARIANTS {
+            let classes = [
+                constellation.is_core_gnss(),
+                constellation.is_sbas(),
+                constellation.is_mixed(),
+            ];
+
+            assert_eq!(
+                classes.iter().filter(|class| **class).count(),
+                1,
+                "invalid classification for {}",
+                constellation
+            );
+        }
+    }
+
+    #[test]
+    fn test_sv_iter() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                assert_eq!(sv.constellation, *constellation);
+                assert!(sv.is_valid(), "invalid satellite {:?}", sv);
+            }
+        }
+
+        assert_eq!(Constellation::Glonass.sv_iter().count(), 27);
+        assert_eq!(Constellation::BeiDou.sv_iter().count(), 63);
+        assert_eq!(Constellation::SBAS.sv_iter().count(), 39);
+    }
+
     #[test]
     fn test_timescale() {
         for (constellation_str, timescale) in [
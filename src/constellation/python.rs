@@ -1,7 +1,12 @@
 use crate::prelude::{Constellation, TimeScale};
-use std::str::FromStr;
 
-use pyo3::{prelude::*, types::PyType};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use pyo3::{basic::CompareOp, prelude::*, types::PyType};
 
 #[pymethods]
 impl Constellation {
@@ -14,10 +19,35 @@ impl Constellation {
         Ok(constellation)
     }
 
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_cls: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
     fn __str__(&self) -> String {
         format!("{}", self)
     }
 
+    fn __repr__(&self) -> String {
+        format!("<Constellation {:E}>", self)
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(other))
+    }
+
+    /// Pickling support: the standard accronym is a reciprocal description.
+    fn __getnewargs__(&self) -> (String,) {
+        (format!("{:E}", self),)
+    }
+
     fn __format__(&self, spec: &str) -> PyResult<String> {
         match spec {
             "x" => Ok(format!("{:x}", self)),
@@ -0,0 +1,42 @@
+use crate::cospar::COSPAR;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Launch piece codes (I and O are not used)
+const LETTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+impl<'a> Arbitrary<'a> for COSPAR {
+    /// Generates valid [COSPAR] numbers only, as [COSPAR::new] enforces the format.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let year = u.int_in_range(1957..=2100)?;
+        let launch = u.int_in_range(1..=999)?;
+        let len = u.int_in_range(1..=3)?;
+
+        let mut code = [0; 3];
+        for byte in code.iter_mut().take(len) {
+            *byte = *u.choose(LETTERS)?;
+        }
+
+        let code =
+            core::str::from_utf8(&code[..len]).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        Self::new(year, launch, code).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cospar::COSPAR;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::str::FromStr;
+
+    #[test]
+    fn arbitrary_cospar() {
+        let bytes = (0..4096).map(|i| (i * 13 % 256) as u8).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let cospar = COSPAR::arbitrary(&mut u).unwrap();
+            assert_eq!(COSPAR::from_str(&cospar.to_string()).unwrap(), cospar);
+        }
+    }
+}
@@ -1,26 +1,89 @@
 //! COSPAR (Launch) ID number
+use crate::{ascii, prelude::Epoch};
+use core::cmp::Ordering;
 use thiserror::Error;
 
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::pyclass;
 
 #[cfg(feature = "python")]
 mod python;
 
-#[derive(Debug, Error)]
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+mod tle;
+
+pub use tle::{TleError, TleIdentity};
+
+// Includes the COSPAR database
+include!(concat!(env!("OUT_DIR"), "/cospar.rs"));
+
+/// COSPAR parsing error
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum Error {
     #[error("Invalid COSPAR number")]
     InvalidFormat,
+
+    #[error("invalid launch year (4 digits, since 1957)")]
+    InvalidYear,
+
+    #[error("invalid launch number (1 to 999)")]
+    InvalidLaunchNumber,
+
+    #[error("invalid piece code (1 to 3 letters, A-Z excluding I and O)")]
+    InvalidPieceCode,
 }
 
-/// COSPAR ID number
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+/// Maximal length of the piece code
+const MAX_CODE_LEN: usize = 3;
+
+/// Valid launch years: from the first artificial satellite (Sputnik 1)
+const YEARS: core::ops::RangeInclusive<u16> = 1957..=9999;
+
+/// Valid launch numbers (3 digits)
+const LAUNCHES: core::ops::RangeInclusive<u16> = 1..=999;
+
+/// COSPAR ID number.
+///
+/// [COSPAR] is `Copy` and does not allocate, so it is available without std-lib.
+/// It sorts chronologically: by launch year, launch number then piece code
+/// (A to Z, then AA, AB..).
+/// ```
+/// use gnss_rs::prelude::COSPAR;
+/// use std::collections::BTreeSet;
+/// use std::str::FromStr;
+///
+/// let cospar = COSPAR::new(2018, 80, "A").unwrap();
+/// assert_eq!(cospar.code(), "A");
+/// assert_eq!(cospar.to_string(), "2018-080A");
+///
+/// let set = ["2018-080AA", "2018-080B", "2018-080A", "1996-068A"]
+///     .iter()
+///     .map(|desc| COSPAR::from_str(desc).unwrap())
+///     .collect::<BTreeSet<_>>();
+///
+/// let sorted = set.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+/// assert_eq!(sorted, ["1996-068A", "2018-080A", "2018-080B", "2018-080AA"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "python", pyo3(module = "gnss"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "CosparFields", into = "CosparFields")
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct COSPAR {
     /// Launch year
     pub year: u16,
@@ -29,63 +92,318 @@ pub struct COSPAR {
     pub launch: u16,
 
     /// Up to three letter code representing the sequential
-    /// identifier of a piece in a Launch.
-    pub code: String,
+    /// identifier of a piece in a Launch (ASCII, zero padded).
+    code: [u8; MAX_CODE_LEN],
+}
+
+/// Serialized [COSPAR] fields, with a readable piece code,
+/// validated by [COSPAR::new] on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename = "COSPAR")]
+struct CosparFields {
+    year: u16,
+    launch: u16,
+    code: String,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CosparFields> for COSPAR {
+    type Error = Error;
+
+    fn try_from(fields: CosparFields) -> Result<Self, Self::Error> {
+        Self::new(fields.year, fields.launch, &fields.code)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<COSPAR> for CosparFields {
+    fn from(cospar: COSPAR) -> Self {
+        Self {
+            year: cospar.year,
+            launch: cospar.launch,
+            code: cospar.code().to_string(),
+        }
+    }
 }
 
 impl COSPAR {
+    /// Builds a new [COSPAR] from launch year, launch number (in that year)
+    /// and up to three letter piece code.
+    /// ```
+    /// use gnss_rs::cospar::{COSPAR, Error};
+    ///
+    /// assert!(COSPAR::new(2018, 80, "A").is_ok());
+    ///
+    /// assert_eq!(COSPAR::new(1950, 80, "A"), Err(Error::InvalidYear));
+    /// assert_eq!(COSPAR::new(2018, 0, "A"), Err(Error::InvalidLaunchNumber));
+    ///
+    /// // I and O are not used, to avoid confusion with 1 and 0
+    /// assert_eq!(COSPAR::new(2018, 80, "AI"), Err(Error::InvalidPieceCode));
+    /// assert_eq!(COSPAR::new(2018, 80, "ABCD"), Err(Error::InvalidPieceCode));
+    /// ```
+    ///
+    /// This is a const function, static tables of [COSPAR] numbers may be declared
+    /// with [cospar!](crate::cospar!) or [COSPAR::from_str_const].
+    pub const fn new(year: u16, launch: u16, code: &str) -> Result<Self, Error> {
+        Self::from_parts(year, launch, code.as_bytes())
+    }
+
+    /// Verifies all fields and builds [COSPAR].
+    const fn from_parts(year: u16, launch: u16, code: &[u8]) -> Result<Self, Error> {
+        let year = match Self::launch_year(year) {
+            Ok(year) => year,
+            Err(e) => return Err(e),
+        };
+
+        let launch = match Self::launch_number(launch) {
+            Ok(launch) => launch,
+            Err(e) => return Err(e),
+        };
+
+        let code = match Self::piece_code(code) {
+            Ok(code) => code,
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { year, launch, code })
+    }
+
+    /// Verifies the launch year.
+    const fn launch_year(year: u16) -> Result<u16, Error> {
+        if year >= *YEARS.start() && year <= *YEARS.end() {
+            Ok(year)
+        } else {
+            Err(Error::InvalidYear)
+        }
+    }
+
+    /// Verifies the launch number.
+    const fn launch_number(launch: u16) -> Result<u16, Error> {
+        if launch >= *LAUNCHES.start() && launch <= *LAUNCHES.end() {
+            Ok(launch)
+        } else {
+            Err(Error::InvalidLaunchNumber)
+        }
+    }
+
+    /// Verifies and packs the piece code.
+    const fn piece_code(code: &[u8]) -> Result<[u8; MAX_CODE_LEN], Error> {
+        if code.is_empty() || code.len() > MAX_CODE_LEN {
+            return Err(Error::InvalidPieceCode);
+        }
+
+        let mut packed = [0; MAX_CODE_LEN];
+        let mut i = 0;
+
+        while i < code.len() {
+            if !code[i].is_ascii_uppercase() || code[i] == b'I' || code[i] == b'O' {
+                return Err(Error::InvalidPieceCode);
+            }
+            packed[i] = code[i];
+            i += 1;
+        }
+
+        Ok(packed)
+    }
+
+    /// Parses [COSPAR] from standard "YYYY-NNNP[PP]" description, in const context.
+    /// This is the strict (no blank) equivalent of [FromStr](core::str::FromStr).
+    const fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 9 {
+            return Err(Error::InvalidFormat);
+        }
+
+        let mut offset = 0;
+        while offset < bytes.len() && bytes[offset] != b'-' {
+            offset += 1;
+        }
+
+        if offset == bytes.len() {
+            return Err(Error::InvalidFormat);
+        }
+
+        if offset != 4 {
+            return Err(Error::InvalidYear);
+        }
+
+        let (year, rem) = bytes.split_at(4);
+
+        let year = match ascii::parse_digits_u16(year) {
+            Some(year) => year,
+            None => return Err(Error::InvalidYear),
+        };
+
+        let (launch, code) = rem.split_at(4);
+
+        let launch = match ascii::parse_digits_u16(launch.split_at(1).1) {
+            Some(launch) => launch,
+            None => return Err(Error::InvalidLaunchNumber),
+        };
+
+        Self::from_parts(year, launch, code)
+    }
+
+    /// Const (compile time) equivalent of [COSPAR::from_str](core::str::FromStr),
+    /// which returns [None] on parsing failure. This is what the [cospar!](crate::cospar!) macro relies on.
+    /// ```
+    /// use gnss_rs::prelude::COSPAR;
+    ///
+    /// const GPS_III_SV01: Option<COSPAR> = COSPAR::from_str_const("2018-109A");
+    /// assert_eq!(GPS_III_SV01, COSPAR::new(2018, 109, "A").ok());
+    ///
+    /// assert_eq!(COSPAR::from_str_const("2018-109I"), None);
+    /// ```
+    pub const fn from_str_const(string: &str) -> Option<Self> {
+        match Self::parse(string.as_bytes()) {
+            Ok(cospar) => Some(cospar),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the piece code, like "A".
+    pub fn code(&self) -> &str {
+        let len = self
+            .code
+            .iter()
+            .position(|b| *b == 0)
+            .unwrap_or(MAX_CODE_LEN);
+
+        core::str::from_utf8(&self.code[..len]).unwrap_or_default()
+    }
+
+    /// Returns the NORAD catalog number of this satellite,
+    /// if it is part of our database (GNSS and SBAS satellites).
+    /// ```
+    /// use gnss_rs::prelude::COSPAR;
+    /// use std::str::FromStr;
+    ///
+    /// let cospar = COSPAR::from_str("2018-109A").unwrap(); // GPS III SV01
+    /// assert_eq!(cospar.norad(), Some(43873));
+    ///
+    /// let cospar = COSPAR::from_str("1957-001A").unwrap(); // Sputnik 1
+    /// assert_eq!(cospar.norad(), None);
+    /// ```
+    pub fn norad(&self) -> Option<u32> {
+        Some(self.database_entry()?.3)
+    }
+
+    /// Returns the launch datetime of this satellite, expressed as UTC [Epoch],
+    /// if it is part of our satellite catalog. This is the actual launch of the spacecraft,
+    /// not only its year. The SBAS database only describes the service entry of the GEOs,
+    /// so satellites only described there return [None].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let cospar = COSPAR::from_str("2018-109A").unwrap(); // GPS III SV01
+    /// let launch = cospar.launch_epoch().unwrap();
+    /// assert_eq!(launch, Epoch::from_str("2018-12-23T00:00:00 UTC").unwrap());
+    ///
+    /// // BeiDou GEO, also part of BDSBAS
+    /// let cospar = COSPAR::from_str("2012-059A").unwrap();
+    /// let launch = cospar.launch_epoch().unwrap();
+    /// assert_eq!(launch, Epoch::from_str("2012-10-25T00:00:00 UTC").unwrap());
+    ///
+    /// // ASTRA-5B (EGNOS)
+    /// let cospar = COSPAR::from_str("2014-011B").unwrap();
+    /// assert!(cospar.launch_epoch().is_none());
+    /// ```
+    pub fn launch_epoch(&self) -> Option<Epoch> {
+        let (y, m, d, hh, mm, ss) = self.database_entry()?.4?;
+        Some(Epoch::from_gregorian_utc_hms(y, m, d, hh, mm, ss))
+    }
+
+    /// Returns the database entry describing this satellite.
+    fn database_entry(&self) -> Option<&'static CosparEntry> {
+        let index = COSPAR_DATABASE
+            .binary_search_by(|(year, launch, code, _, _)| {
+                (*year, *launch, code.len(), *code).cmp(&(
+                    self.year,
+                    self.launch,
+                    self.code().len(),
+                    self.code(),
+                ))
+            })
+            .ok()?;
+
+        COSPAR_DATABASE.get(index)
+    }
+
+    /// Identifies a satellite of our database (GNSS and SBAS satellites)
+    /// from its NORAD catalog number.
+    /// ```
+    /// use gnss_rs::prelude::COSPAR;
+    ///
+    /// let cospar = COSPAR::from_norad(43873).unwrap();
+    /// assert_eq!(cospar.to_string(), "2018-109A");
+    ///
+    /// assert!(COSPAR::from_norad(2).is_none());
+    /// ```
+    pub fn from_norad(norad: u32) -> Option<Self> {
+        let (year, launch, code, _, _) = COSPAR_DATABASE.iter().find(|entry| entry.3 == norad)?;
+        Self::new(*year, *launch, code).ok()
+    }
+
     /// Define a new [COSPAR] with updated year of launch.
-    pub fn with_launch_year(&self, year: u16) -> Self {
-        let mut s = self.clone();
-        s.year = year;
-        s
+    pub fn with_launch_year(&self, year: u16) -> Result<Self, Error> {
+        let mut s = *self;
+        s.year = Self::launch_year(year)?;
+        Ok(s)
     }
 
     /// Define a new [COSPAR] with updated launch number (in that year).
-    pub fn with_launch_number(&self, launch: u16) -> Self {
-        let mut s = self.clone();
-        s.launch = launch;
-        s
+    pub fn with_launch_number(&self, launch: u16) -> Result<Self, Error> {
+        let mut s = *self;
+        s.launch = Self::launch_number(launch)?;
+        Ok(s)
     }
 
     /// Define a new [COSPAR] with updated 3 letter launch sequential code.
-    pub fn with_launch_code(&self, code: &str) -> Self {
-        let mut s = self.clone();
-        s.code = code.to_string();
-        s
+    pub fn with_launch_code(&self, code: &str) -> Result<Self, Error> {
+        let mut s = *self;
+        s.code = Self::piece_code(code.as_bytes())?;
+        Ok(s)
+    }
+}
+
+impl PartialOrd for COSPAR {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for COSPAR {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        (self.year, self.launch, self.code().len(), self.code).cmp(&(
+            rhs.year,
+            rhs.launch,
+            rhs.code().len(),
+            rhs.code,
+        ))
     }
 }
 
 impl core::fmt::Display for COSPAR {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{:04}-{:03}{}", self.year, self.launch, self.code)
+        write!(f, "{:04}-{:03}{}", self.year, self.launch, self.code())
     }
 }
 
 impl core::str::FromStr for COSPAR {
     type Err = Error;
+
+    /// Parses [COSPAR] from standard "YYYY-NNNP[PP]" description,
+    /// with 4 digit launch year, 3 digit launch number and up to 3 letter piece code.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() < 9 {
-            return Err(Error::InvalidFormat);
-        }
-        let offset = s.find('-').ok_or(Error::InvalidFormat)?;
-        let (year, rem) = s.split_at(offset);
-        let year = year.parse::<u16>().map_err(|_| Error::InvalidFormat)?;
-        let launch = rem[1..4]
-            .trim()
-            .parse::<u16>()
-            .map_err(|_| Error::InvalidFormat)?;
-        Ok(Self {
-            year,
-            launch,
-            code: rem[4..].trim().to_string(),
-        })
+        Self::parse(s.trim().as_bytes())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::cospar::COSPAR;
+    use crate::cospar::{Error, COSPAR, COSPAR_DATABASE};
     use std::str::FromStr;
     #[test]
     fn cospar() {
@@ -95,7 +413,7 @@ mod test {
                 COSPAR {
                     year: 2018,
                     launch: 80,
-                    code: "A".to_string(),
+                    code: *b"A\0\0",
                 },
             ),
             (
@@ -103,7 +421,7 @@ mod test {
                 COSPAR {
                     year: 1996,
                     launch: 68,
-                    code: "A".to_string(),
+                    code: *b"A\0\0",
                 },
             ),
         ] {
@@ -112,5 +430,98 @@ mod test {
             let recip = cospar.to_string();
             assert_eq!(recip, desc, "cospar reciprocal");
         }
+
+        let cospar = COSPAR::from_str("2014-011AB").unwrap();
+        assert_eq!(cospar.code(), "AB");
+        assert_eq!(cospar.with_launch_code("C").unwrap().code(), "C");
+        assert!(cospar.with_launch_code("ABCD").is_err());
+        assert!(cospar.with_launch_code("").is_err());
+        assert!(COSPAR::from_str("2014-011ABCD").is_err());
+
+        assert!(cospar.with_launch_year(2015).is_ok());
+        assert!(cospar.with_launch_year(1900).is_err());
+        assert!(cospar.with_launch_number(999).is_ok());
+        assert!(cospar.with_launch_number(1000).is_err());
+
+        assert!(cospar < COSPAR::from_str("2014-011BA").unwrap());
+        assert!(cospar > COSPAR::from_str("2014-011Z").unwrap());
+        assert!(cospar < COSPAR::from_str("2014-012A").unwrap());
+    }
+
+    #[test]
+    fn cospar_norad() {
+        assert!(!COSPAR_DATABASE.is_empty());
+
+        for (index, (year, launch, code, norad, _)) in COSPAR_DATABASE.iter().enumerate() {
+            let cospar = COSPAR::new(*year, *launch, code)
+                .unwrap_or_else(|e| panic!("invalid COSPAR #{}: {}", index, e));
+
+            assert_eq!(cospar.norad(), Some(*norad), "{}", cospar);
+            assert_eq!(COSPAR::from_norad(*norad), Some(cospar), "{}", norad);
+
+            // designator and launch year must agree
+            if let Some(launch) = cospar.launch_epoch() {
+                assert_eq!(launch.year(), *year as i32, "{}", cospar);
+            }
+
+            // sorted and unique
+            if index > 0 {
+                let (year, launch, code, _, _) = COSPAR_DATABASE[index - 1];
+                assert!(COSPAR::new(year, launch, code).unwrap() < cospar);
+            }
+        }
+
+        // ASTRA-5B: EGNOS GEO (SBAS database)
+        let cospar = COSPAR::from_str("2014-011B").unwrap();
+        assert_eq!(cospar.norad(), Some(39617));
+    }
+
+    #[test]
+    fn cospar_errors() {
+        for (desc, error) in [
+            ("2018-80A", Error::InvalidFormat),
+            ("2018 080A", Error::InvalidFormat),
+            ("18-0800AB", Error::InvalidYear),
+            ("1950-080A", Error::InvalidYear),
+            ("2O18-080A", Error::InvalidYear),
+            ("2018-000A", Error::InvalidLaunchNumber),
+            ("2018-+80A", Error::InvalidLaunchNumber),
+            ("2018-08éA", Error::InvalidLaunchNumber),
+            ("2018-0800", Error::InvalidPieceCode),
+            ("2018-080a", Error::InvalidPieceCode),
+            ("2018-080O", Error::InvalidPieceCode),
+            ("2018-080AIA", Error::InvalidPieceCode),
+            ("2018-080ABCD", Error::InvalidPieceCode),
+            ("2018-080 A", Error::InvalidPieceCode),
+        ] {
+            assert_eq!(COSPAR::from_str(desc), Err(error), "{}", desc);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn cospar_serdes() {
+        let cospar = COSPAR::from_str("2014-011AB").unwrap();
+
+        let content = serde_json::to_string(&cospar).unwrap();
+        assert_eq!(content, "{\"year\":2014,\"launch\":11,\"code\":\"AB\"}");
+        assert_eq!(serde_json::from_str::<COSPAR>(&content).unwrap(), cospar);
+
+        let bytes = bincode::serialize(&cospar).unwrap();
+        assert_eq!(bincode::deserialize::<COSPAR>(&bytes).unwrap(), cospar);
+
+        // deserialization is validated like COSPAR::new
+        for content in [
+            "{\"year\":1950,\"launch\":11,\"code\":\"AB\"}",
+            "{\"year\":2014,\"launch\":0,\"code\":\"AB\"}",
+            "{\"year\":2014,\"launch\":11,\"code\":\"\"}",
+            "{\"year\":2014,\"launch\":11,\"code\":\"AI\"}",
+        ] {
+            assert!(
+                serde_json::from_str::<COSPAR>(content).is_err(),
+                "{}",
+                content
+            );
+        }
     }
 }
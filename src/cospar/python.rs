@@ -4,14 +4,11 @@ use pyo3::{pymethods, PyResult};
 #[pymethods]
 impl COSPAR {
     /// Builds a new [COSPAR] identification number.
-    /// The launch sequence number should be a 3 letter code (unchecked).
+    /// The launch sequence number should be an up to 3 letter code.
     #[new]
-    fn py_new(year: u16, launch: u16, code: &str) -> Self {
-        Self {
-            year,
-            launch,
-            code: code.to_string(),
-        }
+    fn py_new(year: u16, launch: u16, code: &str) -> PyResult<Self> {
+        Self::new(year, launch, code)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
     }
 
     fn __str__(&self) -> String {
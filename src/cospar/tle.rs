@@ -0,0 +1,385 @@
+//! TLE international designators and identification
+use crate::{
+    cospar::{Error, COSPAR},
+    prelude::Epoch,
+};
+
+#[cfg(feature = "std")]
+use crate::prelude::SV;
+
+use alloc::{format, string::String};
+use hifitime::Unit;
+
+/// Launch years that may be described by a two-digit TLE designator:
+/// 57 to 99 stand for 1957 to 1999, 00 to 56 stand for 2000 to 2056.
+const TLE_YEARS: core::ops::RangeInclusive<u16> = 1957..=2056;
+
+/// Minimal TLE line length, including the checksum.
+const TLE_LINE_LEN: usize = 69;
+
+/// Converts a two-digit TLE year.
+fn tle_year(year: u16) -> u16 {
+    if year < 57 {
+        2000 + year
+    } else {
+        1900 + year
+    }
+}
+
+/// TLE parsing error
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum TleError {
+    #[error("invalid TLE line #{0}")]
+    InvalidLine(u8),
+
+    #[error("TLE line #{0} checksum mismatch")]
+    ChecksumMismatch(u8),
+
+    #[error("invalid NORAD catalog number")]
+    InvalidNoradNumber,
+
+    #[error("NORAD catalog numbers differ between both lines")]
+    NoradNumberMismatch,
+
+    #[error("invalid TLE epoch")]
+    InvalidEpoch,
+
+    #[error("invalid international designator: {0}")]
+    InvalidDesignator(#[from] Error),
+}
+
+/// Identification of the satellite described by a two-line element set (TLE),
+/// like those published by Celestrak.
+/// ```
+/// use gnss_rs::cospar::TleIdentity;
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let tle = TleIdentity::from_lines(
+///     "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+///     "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(tle.norad, 25544);
+/// assert_eq!(tle.cospar, Some(COSPAR::from_str("1998-067A").unwrap()));
+///
+/// let epoch = Epoch::from_str("2008-09-20T12:25:40.104192 UTC").unwrap();
+/// assert!((tle.epoch - epoch).abs().to_seconds() < 1.0E-3);
+///
+/// // not a GNSS satellite
+/// assert_eq!(tle.sv(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TleIdentity {
+    /// NORAD catalog number
+    pub norad: u32,
+
+    /// [COSPAR] international designator, when published
+    pub cospar: Option<COSPAR>,
+
+    /// Epoch of this element set
+    pub epoch: Epoch,
+}
+
+impl TleIdentity {
+    /// Parses [TleIdentity] from both lines of a TLE (the optional title line excluded).
+    /// Both checksums are verified. Alpha-5 NORAD numbers (above 99999) are supported.
+    pub fn from_lines(line1: &str, line2: &str) -> Result<Self, TleError> {
+        let line1 = Self::verified_line(line1, 1)?;
+        let line2 = Self::verified_line(line2, 2)?;
+
+        let norad = Self::norad_number(&line1[2..7])?;
+
+        if Self::norad_number(&line2[2..7])? != norad {
+            return Err(TleError::NoradNumberMismatch);
+        }
+
+        let designator = line1[9..17].trim();
+
+        let cospar = if designator.is_empty() {
+            None
+        } else {
+            Some(COSPAR::from_tle_designator(designator)?)
+        };
+
+        let year = line1[18..20]
+            .parse::<u16>()
+            .map_err(|_| TleError::InvalidEpoch)?;
+
+        let day_of_year = line1[20..32]
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| TleError::InvalidEpoch)?;
+
+        if !(1.0..367.0).contains(&day_of_year) {
+            return Err(TleError::InvalidEpoch);
+        }
+
+        let epoch = Epoch::from_gregorian_utc_at_midnight(tle_year(year) as i32, 1, 1)
+            + (day_of_year - 1.0) * Unit::Day;
+
+        Ok(Self {
+            norad,
+            cospar,
+            epoch,
+        })
+    }
+
+    /// Verifies line number, length and checksum.
+    fn verified_line(line: &str, number: u8) -> Result<&str, TleError> {
+        let line = line.trim_end();
+
+        if line.len() < TLE_LINE_LEN
+            || !line.is_ascii()
+            || line.as_bytes()[0] != b'0' + number
+            || line.as_bytes()[1] != b' '
+        {
+            return Err(TleError::InvalidLine(number));
+        }
+
+        let checksum = line.as_bytes()[TLE_LINE_LEN - 1];
+
+        if !checksum.is_ascii_digit() {
+            return Err(TleError::InvalidLine(number));
+        }
+
+        let sum = line.as_bytes()[..TLE_LINE_LEN - 1]
+            .iter()
+            .map(|b| match b {
+                b'0'..=b'9' => (b - b'0') as u32,
+                b'-' => 1,
+                _ => 0,
+            })
+            .sum::<u32>();
+
+        if sum % 10 != (checksum - b'0') as u32 {
+            return Err(TleError::ChecksumMismatch(number));
+        }
+
+        Ok(line)
+    }
+
+    /// Parses a 5 character NORAD number, possibly in Alpha-5 format
+    /// (leading letter standing for 10 to 33, I and O excluded).
+    fn norad_number(content: &str) -> Result<u32, TleError> {
+        let content = content.trim_start();
+        let first = *content
+            .as_bytes()
+            .first()
+            .ok_or(TleError::InvalidNoradNumber)?;
+
+        let (prefix, digits) = match first {
+            b'0'..=b'9' => (0, content),
+            b'A'..=b'H' => ((first - b'A') as u32 + 10, &content[1..]),
+            b'J'..=b'N' => ((first - b'A') as u32 + 9, &content[1..]),
+            b'P'..=b'Z' => ((first - b'A') as u32 + 8, &content[1..]),
+            _ => return Err(TleError::InvalidNoradNumber),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(TleError::InvalidNoradNumber);
+        }
+
+        let digits = digits
+            .parse::<u32>()
+            .map_err(|_| TleError::InvalidNoradNumber)?;
+
+        Ok(prefix * 10_000 + digits)
+    }
+
+    /// Returns the [SV] broadcasted by this satellite at the epoch of this element set,
+    /// using our builtin catalog (or SBAS database).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::cospar::TleIdentity;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// // GPS III SV01
+    /// let tle = TleIdentity::from_lines(
+    ///     "1 43873U 18109A   24001.50000000 -.00000010  00000-0  00000-0 0  9998",
+    ///     "2 43873  55.0000 100.0000 0001000 200.0000 160.0000  2.00560000 38811",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(tle.sv(), Some(sv!("G04")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sv(&self) -> Option<SV> {
+        SV::from_norad_at(self.norad, self.epoch)
+    }
+}
+
+impl COSPAR {
+    /// Parses a [COSPAR] from a TLE international designator (line 1, columns 10 to 17),
+    /// like "18080A": two-digit launch year, three-digit launch number
+    /// and up to three letter piece code, possibly blank padded.
+    /// Two-digit years 57 to 99 stand for 1957 to 1999, 00 to 56 for 2000 to 2056.
+    /// ```
+    /// use gnss_rs::cospar::{COSPAR, Error};
+    /// use std::str::FromStr;
+    ///
+    /// let cospar = COSPAR::from_tle_designator("18080A  ").unwrap();
+    /// assert_eq!(cospar, COSPAR::from_str("2018-080A").unwrap());
+    ///
+    /// let cospar = COSPAR::from_tle_designator("96068A").unwrap();
+    /// assert_eq!(cospar.year, 1996);
+    ///
+    /// assert_eq!(COSPAR::from_tle_designator("18-080A"), Err(Error::InvalidLaunchNumber));
+    /// assert_eq!(COSPAR::from_tle_designator("18080"), Err(Error::InvalidFormat));
+    /// ```
+    pub fn from_tle_designator(designator: &str) -> Result<Self, Error> {
+        let designator = designator.trim();
+        if designator.len() < 6 || !designator.is_ascii() {
+            return Err(Error::InvalidFormat);
+        }
+
+        let (year, rem) = designator.split_at(2);
+
+        if !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidYear);
+        }
+
+        let year = tle_year(year.parse::<u16>().map_err(|_| Error::InvalidYear)?);
+
+        let (launch, code) = rem.split_at(3);
+
+        if !launch.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidLaunchNumber);
+        }
+
+        let launch = launch
+            .parse::<u16>()
+            .map_err(|_| Error::InvalidLaunchNumber)?;
+
+        Self::new(year, launch, code)
+    }
+
+    /// Formats this [COSPAR] as a TLE international designator, like "18080A",
+    /// without blank padding.
+    /// Returns [None] if the launch year cannot be described with two digits (1957 to 2056).
+    /// ```
+    /// use gnss_rs::prelude::COSPAR;
+    /// use std::str::FromStr;
+    ///
+    /// let cospar = COSPAR::from_str("2018-080A").unwrap();
+    /// assert_eq!(cospar.to_tle_designator(), Some("18080A".to_string()));
+    ///
+    /// let cospar = COSPAR::from_str("2057-001A").unwrap();
+    /// assert_eq!(cospar.to_tle_designator(), None);
+    /// ```
+    pub fn to_tle_designator(&self) -> Option<String> {
+        if !TLE_YEARS.contains(&self.year) {
+            return None;
+        }
+
+        Some(format!(
+            "{:02}{:03}{}",
+            self.year % 100,
+            self.launch,
+            self.code()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{TleError, TleIdentity};
+    use crate::cospar::{Error, COSPAR};
+    use crate::prelude::Epoch;
+    use std::str::FromStr;
+
+    #[test]
+    fn tle_designators() {
+        for (designator, desc) in [
+            ("57001A", "1957-001A"),
+            ("99025AB", "1999-025AB"),
+            ("00001A", "2000-001A"),
+            ("18109A", "2018-109A"),
+            ("56999ZZZ", "2056-999ZZZ"),
+        ] {
+            let cospar = COSPAR::from_tle_designator(designator).unwrap();
+            assert_eq!(cospar, COSPAR::from_str(desc).unwrap());
+            assert_eq!(cospar.to_tle_designator().unwrap(), designator);
+        }
+
+        for (designator, error) in [
+            ("", Error::InvalidFormat),
+            ("1808A", Error::InvalidFormat),
+            ("2018080A", Error::InvalidPieceCode),
+            ("1A080A", Error::InvalidYear),
+            ("18000A", Error::InvalidLaunchNumber),
+            ("18080I", Error::InvalidPieceCode),
+            ("18080 A", Error::InvalidPieceCode),
+        ] {
+            assert_eq!(
+                COSPAR::from_tle_designator(designator),
+                Err(error),
+                "{}",
+                designator
+            );
+        }
+
+        let cospar = COSPAR::from_str("2057-001A").unwrap();
+        assert_eq!(cospar.to_tle_designator(), None);
+    }
+
+    #[test]
+    fn tle_identity() {
+        let line1 = "1 43873U 18109A   24001.50000000 -.00000010  00000-0  00000-0 0  9998";
+        let line2 = "2 43873  55.0000 100.0000 0001000 200.0000 160.0000  2.00560000 38811";
+
+        let tle = TleIdentity::from_lines(line1, line2).unwrap();
+        assert_eq!(tle.norad, 43873);
+        assert_eq!(tle.cospar, Some(COSPAR::from_str("2018-109A").unwrap()));
+        assert_eq!(
+            tle.epoch,
+            Epoch::from_str("2024-01-01T12:00:00 UTC").unwrap()
+        );
+
+        assert_eq!(
+            TleIdentity::from_lines(line2, line1),
+            Err(TleError::InvalidLine(1))
+        );
+
+        assert_eq!(
+            TleIdentity::from_lines(&line1[..68], line2),
+            Err(TleError::InvalidLine(1))
+        );
+
+        let corrupted = line1.replace("24001.5", "24002.5");
+        assert_eq!(
+            TleIdentity::from_lines(&corrupted, line2),
+            Err(TleError::ChecksumMismatch(1))
+        );
+
+        // other satellite (checksum still valid)
+        let other = line2.replace("43873  55.0", "43874  54.0");
+        assert_eq!(
+            TleIdentity::from_lines(line1, &other),
+            Err(TleError::NoradNumberMismatch)
+        );
+
+        // blank designator
+        let blank = line1.replace("18109A  ", "        ");
+        let blank = format!("{}{}", &blank[..68], 9);
+        let tle = TleIdentity::from_lines(&blank, line2).unwrap();
+        assert_eq!(tle.cospar, None);
+
+        // Alpha-5
+        assert_eq!(TleIdentity::norad_number("A0000"), Ok(100_000));
+        assert_eq!(TleIdentity::norad_number("J1234"), Ok(181_234));
+        assert_eq!(TleIdentity::norad_number("Z9999"), Ok(339_999));
+        assert_eq!(TleIdentity::norad_number(" 1234"), Ok(1234));
+        assert_eq!(
+            TleIdentity::norad_number("I0000"),
+            Err(TleError::InvalidNoradNumber)
+        );
+        assert_eq!(
+            TleIdentity::norad_number("12 34"),
+            Err(TleError::InvalidNoradNumber)
+        );
+    }
+}
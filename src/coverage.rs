@@ -0,0 +1,224 @@
+//! SBAS service areas, usable without std-lib.
+use crate::prelude::Constellation;
+
+// Includes the SBAS coverage database
+include!(concat!(env!("OUT_DIR"), "/sbas_coverage.rs"));
+
+/// Returns true if this polygon, described by its (longitude°, latitude°) vertices,
+/// contains this location (even-odd rule).
+pub(crate) fn polygon_contains(polygon: &[(f64, f64)], long_ddeg: f64, lat_ddeg: f64) -> bool {
+    let mut inside = false;
+
+    for (i, (x_i, y_i)) in polygon.iter().enumerate() {
+        let (x_j, y_j) = polygon[(i + polygon.len() - 1) % polygon.len()];
+
+        if (*y_i > lat_ddeg) != (y_j > lat_ddeg)
+            && long_ddeg < (x_j - x_i) * (lat_ddeg - y_i) / (y_j - y_i) + x_i
+        {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Helps select a SBAS (geostationary augmentation service) from user coordinates,
+/// expressed as latitude and longitude in decimal degrees.
+/// Unlike [sbas_selector](crate::sbas_selector), this does not require the "sbas" option
+/// nor std-lib, which makes it suitable for embedded receivers.
+/// ```
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_selector_latlon,
+/// };
+///
+/// assert_eq!(sbas_selector_latlon(48.808378, 2.38262), Some(Constellation::EGNOS));
+/// assert_eq!(sbas_selector_latlon(-77.490631, 91.435181), None);
+///```
+pub fn sbas_selector_latlon(lat_ddeg: f64, long_ddeg: f64) -> Option<Constellation> {
+    SBAS_COVERAGE
+        .iter()
+        .filter(|(_, _, polygon)| polygon_contains(polygon, long_ddeg, lat_ddeg))
+        .find_map(|(name, _, _)| Constellation::from_str_const(name))
+}
+
+/// Helps select a SBAS (geostationary augmentation service) from user ECEF (WGS84)
+/// coordinates, in meters, like a receiver PVT solution.
+/// This converts to geodetic coordinates and uses [sbas_selector_latlon].
+/// ```
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_selector_ecef,
+/// };
+///
+/// // Paris
+/// let (x, y, z) = (4_201_000.0, 175_000.0, 4_780_000.0);
+/// assert_eq!(sbas_selector_ecef(x, y, z), Some(Constellation::EGNOS));
+///```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_selector_ecef(x_m: f64, y_m: f64, z_m: f64) -> Option<Constellation> {
+    let (lat_ddeg, long_ddeg) = crate::sv::geo::ecef_to_geodetic_deg(x_m, y_m, z_m);
+    sbas_selector_latlon(lat_ddeg, long_ddeg)
+}
+
+/// Mean earth radius, in meters
+#[cfg(feature = "std")]
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// Sampling step (in degrees) of polygon edges, when measuring distances
+#[cfg(feature = "std")]
+const EDGE_STEP_DEG: f64 = 0.05;
+
+/// Great circle distance between two locations (in decimal degrees), in meters.
+#[cfg(feature = "std")]
+fn haversine_m(lat1_ddeg: f64, long1_ddeg: f64, lat2_ddeg: f64, long2_ddeg: f64) -> f64 {
+    let (lat1, lat2) = (lat1_ddeg.to_radians(), lat2_ddeg.to_radians());
+    let dlat = lat2 - lat1;
+    let dlong = (long2_ddeg - long1_ddeg).to_radians();
+
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlong / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_M * h.sqrt().min(1.0).asin()
+}
+
+/// Distance (in meters) from this location to the edges of this polygon,
+/// described by its (longitude°, latitude°) vertices.
+#[cfg(feature = "std")]
+fn polygon_distance_m(polygon: &[(f64, f64)], long_ddeg: f64, lat_ddeg: f64) -> f64 {
+    polygon
+        .windows(2)
+        .flat_map(|edge| {
+            let ((x0, y0), (x1, y1)) = (edge[0], edge[1]);
+            let steps = ((x1 - x0).abs().max((y1 - y0).abs()) / EDGE_STEP_DEG)
+                .ceil()
+                .max(1.0) as usize;
+
+            (0..=steps).map(move |i| {
+                let t = i as f64 / steps as f64;
+                (x0 + t * (x1 - x0), y0 + t * (y1 - y0))
+            })
+        })
+        .map(|(x, y)| haversine_m(lat_ddeg, long_ddeg, y, x))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Selects the SBAS (geostationary augmentation service) closest to these user coordinates,
+/// expressed as latitude and longitude in decimal degrees, along with the distance (in meters)
+/// to its service area. When the location is covered, this is the [sbas_selector_latlon]
+/// service, at a null distance. Otherwise, this is the service whose area edge is the closest,
+/// to evaluate marginal reception (ocean crossings, high latitudes).
+/// The distance is accurate to a few kilometers.
+/// ```
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_selector_nearest,
+/// };
+///
+/// // Paris: covered
+/// assert_eq!(sbas_selector_nearest(48.808378, 2.38262), Some((Constellation::EGNOS, 0.0)));
+///
+/// // Argentina: closest to WAAS, a few thousands km away
+/// let (constellation, distance_m) = sbas_selector_nearest(-23.216639, -63.170983).unwrap();
+/// assert_eq!(constellation, Constellation::WAAS);
+/// assert!(distance_m > 3_000.0E3 && distance_m < 3_200.0E3);
+///```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_selector_nearest(lat_ddeg: f64, long_ddeg: f64) -> Option<(Constellation, f64)> {
+    if let Some(constellation) = sbas_selector_latlon(lat_ddeg, long_ddeg) {
+        return Some((constellation, 0.0));
+    }
+
+    SBAS_COVERAGE
+        .iter()
+        .filter_map(|(name, _, polygon)| {
+            let constellation = Constellation::from_str_const(name)?;
+            Some((
+                constellation,
+                polygon_distance_m(polygon, long_ddeg, lat_ddeg),
+            ))
+        })
+        .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{polygon_contains, sbas_selector_latlon, SBAS_COVERAGE};
+
+    #[cfg(feature = "std")]
+    use super::{haversine_m, sbas_selector_nearest};
+    use crate::prelude::Constellation;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_database() {
+        for (name, _, polygon) in SBAS_COVERAGE.iter() {
+            assert!(
+                Constellation::from_str(name).is_ok(),
+                "invalid constellation name found \"{}\"",
+                name
+            );
+            assert!(polygon.len() > 3, "{}: invalid polygon", name);
+        }
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        let square = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+            (0.0, 0.0),
+        ];
+        assert!(polygon_contains(&square, 5.0, 5.0));
+        assert!(!polygon_contains(&square, 15.0, 5.0));
+        assert!(!polygon_contains(&square, 5.0, -5.0));
+        assert!(!polygon_contains(&[], 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_sbas_selector_latlon() {
+        for (lat_ddeg, long_ddeg, expected) in [
+            (48.808378, 2.38268, Some(Constellation::EGNOS)),
+            (33.981431, -118.193601, Some(Constellation::WAAS)),
+            (19.314290, 76.798953, Some(Constellation::GAGAN)),
+            (-27.579847, 131.334992, Some(Constellation::SPAN)),
+            (37.067846, 128.34, Some(Constellation::KASS)),
+            (60.004390, 89.090326, Some(Constellation::SDCM)),
+            (-23.216639, -63.170983, None), // argentina
+            (-77.490631, 91.435181, None),  // antarctica
+        ] {
+            assert_eq!(
+                sbas_selector_latlon(lat_ddeg, long_ddeg),
+                expected,
+                "invalid results for coordinates lat={}° long={}°",
+                lat_ddeg,
+                long_ddeg
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sbas_selector_nearest() {
+        // one degree of latitude
+        let degree_m = haversine_m(0.0, 0.0, 1.0, 0.0);
+        assert!((degree_m - 111_195.0).abs() < 1.0);
+
+        // argentina: WAAS southern edge is at 5°N
+        let (constellation, distance_m) = sbas_selector_nearest(-23.216639, -63.170983).unwrap();
+        assert_eq!(constellation, Constellation::WAAS);
+        assert!((distance_m - 28.216639 * degree_m).abs() < 1.0E3);
+
+        // covered
+        for (lat_ddeg, long_ddeg) in [(48.808378, 2.38268), (33.981431, -118.193601)] {
+            let (constellation, distance_m) = sbas_selector_nearest(lat_ddeg, long_ddeg).unwrap();
+            assert_eq!(
+                Some(constellation),
+                sbas_selector_latlon(lat_ddeg, long_ddeg)
+            );
+            assert_eq!(distance_m, 0.0);
+        }
+    }
+}
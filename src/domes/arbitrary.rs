@@ -0,0 +1,52 @@
+use crate::domes::{TrackingPoint, DOMES};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for TrackingPoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[Self::Monument, Self::Instrument])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for DOMES {
+    /// Generates mostly valid [DOMES] numbers, and deliberately near-invalid ones,
+    /// where one of the fields does not fit its number of digits.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut domes = Self {
+            area: u.int_in_range(0..=999)?,
+            site: u.int_in_range(0..=99)?,
+            point: TrackingPoint::arbitrary(u)?,
+            sequential: u.int_in_range(0..=999)?,
+        };
+
+        if u.ratio(1, 8)? {
+            match u.int_in_range(0..=2)? {
+                0 => domes.area = 1000,
+                1 => domes.site = 100,
+                _ => domes.sequential = 1000,
+            }
+        }
+
+        Ok(domes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domes::DOMES;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::str::FromStr;
+
+    #[test]
+    fn arbitrary_domes() {
+        let bytes = (0..4096).map(|i| (i * 11 % 256) as u8).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let domes = DOMES::arbitrary(&mut u).unwrap();
+
+            if domes.area < 1000 && domes.site < 100 && domes.sequential < 1000 {
+                assert_eq!(DOMES::from_str(&domes.to_string()).unwrap(), domes);
+            }
+        }
+    }
+}
@@ -3,9 +3,15 @@ use thiserror::Error;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::pyclass;
 
@@ -24,6 +30,7 @@ pub enum Error {
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "python", pyo3(module = "gnss"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum TrackingPoint {
     /// Monument (pole, pillar, geodetic marker..)
     Monument,
@@ -41,6 +48,7 @@ pub enum TrackingPoint {
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "python", pyo3(module = "gnss"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DOMES {
     /// Area / Country code (3 digits)
     pub area: u16,
@@ -5,11 +5,20 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 
+mod ascii;
+mod coverage;
+
 // pub modules
+pub mod carrier;
+pub mod combination;
 pub mod constellation;
+pub mod observable;
+pub mod sbas_message;
 pub mod sv;
 
 // private modules
@@ -17,8 +26,8 @@ pub mod sv;
 #[cfg_attr(docsrs, doc(cfg(all(feature = "sbas", feature = "std"))))]
 mod sbas;
 
-#[cfg(all(feature = "cospar", feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "cospar", feature = "std"))))]
+#[cfg(feature = "cospar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cospar")))]
 pub mod cospar;
 
 #[cfg(feature = "domes")]
@@ -30,15 +39,31 @@ mod python;
 
 #[cfg(all(feature = "sbas", feature = "std"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "sbas", feature = "sbas"))))]
-pub use sbas::sbas_selector;
+pub use sbas::{
+    geo_footprint, sbas_coverage, sbas_coverage_fraction, sbas_coverage_geojson, sbas_coverage_kml,
+    sbas_coverages, sbas_selection, sbas_selector, sbas_selector_at, sbas_service_area,
+    SbasCoverage, SbasCoverageError, SbasGeo, SbasSelection, SbasSelector,
+};
+
+pub use coverage::sbas_selector_latlon;
+
+#[cfg(feature = "std")]
+pub use coverage::{sbas_selector_ecef, sbas_selector_nearest};
 
 #[cfg(feature = "sbas")]
-pub use geo::geometry::Point;
+pub use geo::geometry::{MultiPolygon, Point, Polygon};
 
 pub mod prelude {
-    pub use crate::{constellation::Constellation, sv::SV};
+    pub use crate::{
+        carrier::Carrier,
+        combination::{Combination, CombinationKind},
+        constellation::Constellation,
+        observable::{Observable, ObservableKind, ObservableUnit, Signal, TrackingCode},
+        sbas_message::{DfmcMessageType, L1MessageType},
+        sv::{SV, SVN},
+    };
 
-    #[cfg(all(feature = "cospar", feature = "std"))]
+    #[cfg(feature = "cospar")]
     pub use crate::cospar::COSPAR;
 
     #[cfg(feature = "domes")]
@@ -1,25 +1,67 @@
 /// Creates a [crate::prelude::SV] from given (case insensitive) string description.
+/// The description is parsed at compile time (see [SV::from_str_const](crate::prelude::SV::from_str_const)),
+/// so invalid descriptions do not compile.
 /// Example:
 /// ```
-/// use std::str::FromStr;
 /// use gnss_rs::prelude::*;
 /// use gnss_rs::sv; // macro
 /// assert_eq!(sv!("G08").constellation, Constellation::GPS);
 /// assert_eq!(sv!("G08").prn, 8);
 /// assert_eq!(sv!("e05").constellation, Constellation::Galileo);
 /// assert_eq!(sv!("e05").prn, 5);
+///
+/// const E05: SV = sv!("E05");
+/// ```
+/// ```compile_fail
+/// use gnss_rs::sv;
+/// let sv = sv!("X08");
 /// ```
 #[macro_export]
 macro_rules! sv {
-    ($desc: expr) => {
-        SV::from_str($desc).unwrap()
-    };
+    ($desc: expr) => {{
+        const PARSED: $crate::prelude::SV = match $crate::prelude::SV::from_str_const($desc) {
+            Some(sv) => sv,
+            None => panic!("invalid satellite description"),
+        };
+        PARSED
+    }};
+}
+
+/// Creates a [COSPAR](crate::cospar::COSPAR) from given "YYYY-NNNP[PP]" description.
+/// The description is parsed and verified at compile time
+/// (see [COSPAR::from_str_const](crate::cospar::COSPAR::from_str_const)),
+/// so invalid descriptions do not compile.
+/// Example:
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::cospar; // macro
+///
+/// // static table of launch identifiers
+/// const LAUNCHES: [COSPAR; 2] = [cospar!("2018-109A"), cospar!("2014-011B")];
+///
+/// assert_eq!(LAUNCHES[0].year, 2018);
+/// assert_eq!(LAUNCHES[1].code(), "B");
+/// ```
+/// ```compile_fail
+/// use gnss_rs::cospar;
+/// let cospar = cospar!("2018-109O");
+/// ```
+#[cfg(feature = "cospar")]
+#[macro_export]
+macro_rules! cospar {
+    ($desc: expr) => {{
+        const PARSED: $crate::cospar::COSPAR = match $crate::cospar::COSPAR::from_str_const($desc) {
+            Some(cospar) => cospar,
+            None => panic!("invalid COSPAR description"),
+        };
+        PARSED
+    }};
 }
 
 /// Creates a [crate::prelude::Constellation] from given (case insensitive) string description.
+/// The description is parsed at compile time, see [constellation!](crate::constellation!).
 /// Example:
 /// ```
-/// use std::str::FromStr;
 /// use gnss_rs::prelude::*;
 /// use gnss_rs::gnss; // macro
 /// assert_eq!(gnss!("gps"), Constellation::GPS);
@@ -29,6 +71,34 @@ macro_rules! sv {
 #[macro_export]
 macro_rules! gnss {
     ($desc: expr) => {
-        Constellation::from_str($desc).unwrap()
+        $crate::constellation!($desc)
     };
 }
+
+/// Creates a [crate::prelude::Constellation] from given (case insensitive) string description.
+/// The description is parsed at compile time (see [Constellation::from_str_const](crate::prelude::Constellation::from_str_const)),
+/// so invalid or ambiguous descriptions do not compile.
+/// Example:
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::constellation; // macro
+/// assert_eq!(constellation!("gps"), Constellation::GPS);
+/// assert_eq!(constellation!("EGNOS"), Constellation::EGNOS);
+///
+/// const GAL: Constellation = constellation!("Galileo");
+/// ```
+/// ```compile_fail
+/// use gnss_rs::constellation;
+/// let gnss = constellation!("GPS+GAL");
+/// ```
+#[macro_export]
+macro_rules! constellation {
+    ($desc: expr) => {{
+        const PARSED: $crate::prelude::Constellation =
+            match $crate::prelude::Constellation::from_str_const($desc) {
+                Some(constellation) => constellation,
+                None => panic!("invalid constellation description"),
+            };
+        PARSED
+    }};
+}
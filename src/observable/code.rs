@@ -0,0 +1,241 @@
+//! Tracking (ranging) codes
+use super::ParsingError;
+use crate::prelude::{Carrier, Constellation};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// [TrackingCode] describes the ranging code (signal component) that was tracked,
+/// which is the third character of RINEX (V3+) observation codes.
+/// The meaning of each code depends on the [Constellation] and [Carrier],
+/// use [TrackingCode::is_valid] to verify a combination.
+/// ```
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let code = TrackingCode::from_str("P").unwrap();
+/// assert_eq!(code, TrackingCode::P);
+/// assert_eq!(code.to_string(), "P");
+///
+/// assert!(code.is_valid(Constellation::GPS, Carrier::L1));
+/// assert!(!code.is_valid(Constellation::Galileo, Carrier::E1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum TrackingCode {
+    /// Galileo PRS (E1, E6), BeiDou B1A and B3A, IRNSS SPS
+    A,
+
+    /// Galileo OS/CS data (E1, E6), QZSS L1C/B, IRNSS RS data
+    B,
+
+    /// GPS & QZSS & SBAS C/A, Glonass C/A, Galileo OS/CS pilot (E1, E6), IRNSS RS pilot
+    C,
+
+    /// GPS L2 semi-codeless, BeiDou data (B1C, B2a, B2b), QZSS L5S data
+    D,
+
+    /// QZSS L1C/B and L6E
+    E,
+
+    /// In-phase (data) component, like GPS L5-I or Galileo E5a-I
+    I,
+
+    /// Long (pilot) component, like GPS L2C-L or L1C-P
+    L,
+
+    /// GPS M-code
+    M,
+
+    /// GPS codeless, BeiDou B1C codeless
+    N,
+
+    /// GPS P-code (unencrypted), Glonass P-code, BeiDou pilot (B1C, B2a, B2b), QZSS L5S pilot
+    P,
+
+    /// Quadrature (pilot) component, like GPS L5-Q or Galileo E5a-Q
+    Q,
+
+    /// Short (data) component, like GPS L2C-M or L1C-D
+    S,
+
+    /// Z-tracking (P(Y) semi-codeless)
+    W,
+
+    /// Combined data + pilot tracking, like GPS L5-I+Q or Galileo E1-B+C
+    X,
+
+    /// GPS encrypted P(Y) code
+    Y,
+
+    /// Galileo A+B+C (E1, E6), BeiDou B2b data + pilot, QZSS L1-SAIF, L5S data + pilot and L6D+E
+    Z,
+}
+
+impl TrackingCode {
+    /// All [TrackingCode]s, in order of declaration.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::A,
+        Self::B,
+        Self::C,
+        Self::D,
+        Self::E,
+        Self::I,
+        Self::L,
+        Self::M,
+        Self::N,
+        Self::P,
+        Self::Q,
+        Self::S,
+        Self::W,
+        Self::X,
+        Self::Y,
+        Self::Z,
+    ];
+
+    /// Returns the RINEX identifier of this [TrackingCode], like 'C'.
+    pub const fn letter(&self) -> char {
+        match self {
+            Self::A => 'A',
+            Self::B => 'B',
+            Self::C => 'C',
+            Self::D => 'D',
+            Self::E => 'E',
+            Self::I => 'I',
+            Self::L => 'L',
+            Self::M => 'M',
+            Self::N => 'N',
+            Self::P => 'P',
+            Self::Q => 'Q',
+            Self::S => 'S',
+            Self::W => 'W',
+            Self::X => 'X',
+            Self::Y => 'Y',
+            Self::Z => 'Z',
+        }
+    }
+
+    /// Identifies [TrackingCode] from its RINEX identifier, like 'C'.
+    pub const fn from_letter(letter: char) -> Result<Self, ParsingError> {
+        match letter {
+            'A' => Ok(Self::A),
+            'B' => Ok(Self::B),
+            'C' => Ok(Self::C),
+            'D' => Ok(Self::D),
+            'E' => Ok(Self::E),
+            'I' => Ok(Self::I),
+            'L' => Ok(Self::L),
+            'M' => Ok(Self::M),
+            'N' => Ok(Self::N),
+            'P' => Ok(Self::P),
+            'Q' => Ok(Self::Q),
+            'S' => Ok(Self::S),
+            'W' => Ok(Self::W),
+            'X' => Ok(Self::X),
+            'Y' => Ok(Self::Y),
+            'Z' => Ok(Self::Z),
+            _ => Err(ParsingError::UnknownTrackingCode(letter)),
+        }
+    }
+
+    /// Returns true if this [TrackingCode] is defined for this [Constellation] and [Carrier]
+    /// (RINEX V3 signal plan). Augmentation systems all follow [Constellation::SBAS] definitions.
+    pub fn is_valid(&self, constellation: Constellation, carrier: Carrier) -> bool {
+        let constellation = if constellation.is_sbas() {
+            Constellation::SBAS
+        } else {
+            constellation
+        };
+
+        tracking_codes(constellation, carrier).contains(self.letter())
+    }
+}
+
+/// Returns the RINEX tracking codes defined for this [Constellation] and [Carrier].
+pub(crate) fn tracking_codes(constellation: Constellation, carrier: Carrier) -> &'static str {
+    match (constellation, carrier) {
+        (Constellation::GPS, Carrier::L1) => "CSLXPWYMN",
+        (Constellation::GPS, Carrier::L2) => "CDSLXPWYMN",
+        (Constellation::GPS, Carrier::L5) => "IQX",
+        (Constellation::Glonass, Carrier::G1 | Carrier::G2) => "CP",
+        (Constellation::Glonass, Carrier::G3) => "IQX",
+        (Constellation::Galileo, Carrier::E1 | Carrier::E6) => "ABCXZ",
+        (Constellation::Galileo, Carrier::E5a | Carrier::E5b | Carrier::E5) => "IQX",
+        (Constellation::BeiDou, Carrier::B1C) => "DPXAN",
+        (Constellation::BeiDou, Carrier::B1I) => "IQX",
+        (Constellation::BeiDou, Carrier::B2a) => "DPX",
+        (Constellation::BeiDou, Carrier::B2b) => "IQXDPZ",
+        (Constellation::BeiDou, Carrier::B3) => "IQXA",
+        (Constellation::QZSS, Carrier::L1) => "CESLXZB",
+        (Constellation::QZSS, Carrier::L2) => "SLX",
+        (Constellation::QZSS, Carrier::L5) => "IQXDPZ",
+        (Constellation::QZSS, Carrier::L6) => "SLXEZ",
+        (Constellation::IRNSS, Carrier::L5 | Carrier::S) => "ABCX",
+        (Constellation::SBAS, Carrier::L1) => "C",
+        (Constellation::SBAS, Carrier::L5) => "IQX",
+        _ => "",
+    }
+}
+
+impl core::fmt::Display for TrackingCode {
+    /// Formats this [TrackingCode] as its RINEX identifier, like "C".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+impl core::str::FromStr for TrackingCode {
+    type Err = ParsingError;
+
+    /// Parses [TrackingCode] from its RINEX identifier, like "C".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.trim().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Self::from_letter(letter),
+            _ => Err(ParsingError::InvalidFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{tracking_codes, TrackingCode};
+    use crate::observable::ParsingError;
+    use crate::prelude::{Carrier, Constellation};
+    use std::str::FromStr;
+
+    #[test]
+    fn code_parsing() {
+        for code in TrackingCode::VARIANTS {
+            assert_eq!(TrackingCode::from_str(&code.to_string()), Ok(*code));
+        }
+
+        assert_eq!(
+            TrackingCode::from_str("F"),
+            Err(ParsingError::UnknownTrackingCode('F'))
+        );
+    }
+
+    #[test]
+    fn code_validity() {
+        for constellation in Constellation::VARIANTS {
+            for carrier in Carrier::VARIANTS {
+                for letter in tracking_codes(*constellation, *carrier).chars() {
+                    // table only contains known codes, on carriers actually broadcasted
+                    let code = TrackingCode::from_letter(letter).unwrap();
+                    assert!(code.is_valid(*constellation, *carrier));
+                    assert!(carrier.is_broadcasted_by(*constellation));
+                }
+            }
+        }
+
+        assert!(TrackingCode::C.is_valid(Constellation::WAAS, Carrier::L1));
+        assert!(!TrackingCode::P.is_valid(Constellation::Galileo, Carrier::E1));
+        assert!(!TrackingCode::W.is_valid(Constellation::GPS, Carrier::L5));
+    }
+}
@@ -0,0 +1,155 @@
+//! Observable kinds (physical measurements)
+use super::ParsingError;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Unit in which [ObservableKind] measurements are expressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum ObservableUnit {
+    /// Meters (m)
+    Meters,
+
+    /// Carrier cycles
+    Cycles,
+
+    /// Hertz (Hz)
+    Hertz,
+
+    /// Carrier to noise density ratio (dB-Hz)
+    DecibelHertz,
+}
+
+impl core::fmt::Display for ObservableUnit {
+    /// Formats this [ObservableUnit] by its usual symbol, like "dB-Hz".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Meters => write!(f, "m"),
+            Self::Cycles => write!(f, "cycles"),
+            Self::Hertz => write!(f, "Hz"),
+            Self::DecibelHertz => write!(f, "dB-Hz"),
+        }
+    }
+}
+
+/// [ObservableKind] describes the physical measurement of an [Observable](super::Observable).
+/// ```
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let kind = ObservableKind::from_str("L").unwrap();
+/// assert_eq!(kind, ObservableKind::Phase);
+/// assert_eq!(kind.unit(), ObservableUnit::Cycles);
+/// assert_eq!(kind.to_string(), "L");
+///
+/// assert_eq!(ObservableKind::SSI.unit().to_string(), "dB-Hz");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum ObservableKind {
+    /// Pseudo range ('C'), in meters
+    PseudoRange,
+
+    /// Carrier phase ('L'), in cycles
+    Phase,
+
+    /// Doppler shift ('D'), in Hz
+    Doppler,
+
+    /// Signal strength ('S'), in dB-Hz
+    SSI,
+}
+
+impl ObservableKind {
+    /// All [ObservableKind]s, in order of declaration.
+    pub const VARIANTS: &'static [Self] =
+        &[Self::PseudoRange, Self::Phase, Self::Doppler, Self::SSI];
+
+    /// Returns the [ObservableUnit] of this [ObservableKind] measurements.
+    pub const fn unit(&self) -> ObservableUnit {
+        match self {
+            Self::PseudoRange => ObservableUnit::Meters,
+            Self::Phase => ObservableUnit::Cycles,
+            Self::Doppler => ObservableUnit::Hertz,
+            Self::SSI => ObservableUnit::DecibelHertz,
+        }
+    }
+
+    /// Returns the RINEX identifier of this [ObservableKind], like 'C'.
+    pub const fn letter(&self) -> char {
+        match self {
+            Self::PseudoRange => 'C',
+            Self::Phase => 'L',
+            Self::Doppler => 'D',
+            Self::SSI => 'S',
+        }
+    }
+
+    /// Identifies [ObservableKind] from its RINEX identifier, like 'C'.
+    pub const fn from_letter(letter: char) -> Result<Self, ParsingError> {
+        match letter {
+            'C' => Ok(Self::PseudoRange),
+            'L' => Ok(Self::Phase),
+            'D' => Ok(Self::Doppler),
+            'S' => Ok(Self::SSI),
+            _ => Err(ParsingError::UnknownKind(letter)),
+        }
+    }
+}
+
+impl core::fmt::Display for ObservableKind {
+    /// Formats this [ObservableKind] as its RINEX identifier, like "C".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+impl core::str::FromStr for ObservableKind {
+    type Err = ParsingError;
+
+    /// Parses [ObservableKind] from its RINEX identifier, like "C".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.trim().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Self::from_letter(letter),
+            _ => Err(ParsingError::InvalidFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ObservableKind, ObservableUnit};
+    use crate::observable::ParsingError;
+    use std::str::FromStr;
+
+    #[test]
+    fn kind_parsing() {
+        for kind in ObservableKind::VARIANTS {
+            assert_eq!(ObservableKind::from_str(&kind.to_string()), Ok(*kind));
+        }
+
+        assert_eq!(
+            ObservableKind::from_str("X"),
+            Err(ParsingError::UnknownKind('X'))
+        );
+        assert_eq!(
+            ObservableKind::from_str("CL"),
+            Err(ParsingError::InvalidFormat)
+        );
+        assert_eq!(
+            ObservableKind::from_str(""),
+            Err(ParsingError::InvalidFormat)
+        );
+
+        assert_eq!(ObservableKind::PseudoRange.unit(), ObservableUnit::Meters);
+        assert_eq!(ObservableKind::Doppler.unit().to_string(), "Hz");
+    }
+}
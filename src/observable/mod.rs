@@ -0,0 +1,361 @@
+//! RINEX (V3+) observables
+use thiserror::Error;
+
+use crate::{
+    constellation::ParsingError as ConstellationParsingError,
+    prelude::{Carrier, Constellation},
+};
+
+mod code;
+mod kind;
+mod msm;
+mod ranging;
+mod signal;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod string;
+
+pub use code::TrackingCode;
+pub use kind::{ObservableKind, ObservableUnit};
+pub use ranging::{Modulation, RangingCode};
+pub use signal::Signal;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Observable parsing & validation related errors
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParsingError {
+    #[error("invalid observable format")]
+    InvalidFormat,
+
+    #[error("constellation parsing error: {0}")]
+    ConstellationParsing(#[from] ConstellationParsingError),
+
+    #[error("unknown observable kind '{0}'")]
+    UnknownKind(char),
+
+    #[error("band '{0}' is not defined for {1}")]
+    UnknownBand(char, Constellation),
+
+    #[error("{0} is not broadcasted by {1}")]
+    InvalidCarrier(Carrier, Constellation),
+
+    #[error("unknown tracking code '{0}'")]
+    UnknownTrackingCode(char),
+
+    #[error("tracking code '{0}' is not defined for {1} {2}")]
+    InvalidTrackingCode(TrackingCode, Constellation, Carrier),
+}
+
+/// [Observable] describes a RINEX (V3+) observation code, like "C1C" or "L5Q":
+/// the measurement [ObservableKind], the signal [Carrier] and the tracking code (attribute).
+/// Band numbers depend on the [Constellation], use [Observable::from_rinex] to parse
+/// (and validate) an observation code.
+/// ```
+/// use gnss_rs::prelude::*;
+///
+/// let observable = Observable::from_rinex(Constellation::Galileo, "L5Q").unwrap();
+/// assert_eq!(observable.kind, ObservableKind::Phase);
+/// assert_eq!(observable.carrier, Carrier::E5a);
+/// assert_eq!(observable.code, TrackingCode::Q);
+/// assert_eq!(observable.to_string(), "L5Q");
+///
+/// let observable = Observable::from_rinex(Constellation::BeiDou, "C2I").unwrap();
+/// assert_eq!(observable.carrier, Carrier::B1I);
+///
+/// // tracking code does not exist
+/// assert!(Observable::from_rinex(Constellation::GPS, "C1B").is_err());
+///
+/// // band does not exist
+/// assert!(Observable::from_rinex(Constellation::GPS, "C7X").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ObservableFields"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Observable {
+    /// [ObservableKind] (physical measurement)
+    pub kind: ObservableKind,
+
+    /// Signal [Carrier]
+    pub carrier: Carrier,
+
+    /// [TrackingCode] (RINEX attribute)
+    pub code: TrackingCode,
+}
+
+/// Deserialized [Observable] fields, validated by [Observable::new].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename = "Observable")]
+struct ObservableFields {
+    kind: ObservableKind,
+    carrier: Carrier,
+    code: TrackingCode,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ObservableFields> for Observable {
+    type Error = ParsingError;
+
+    fn try_from(fields: ObservableFields) -> Result<Self, Self::Error> {
+        Self::new(fields.kind, fields.carrier, fields.code)
+    }
+}
+
+impl Observable {
+    /// Builds a new [Observable], verifying that this [TrackingCode] is defined
+    /// for this [Carrier] by at least one of the [Constellation]s broadcasting it.
+    /// Use [Observable::signal] to validate against a specific [Constellation].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let observable = Observable::new(ObservableKind::Phase, Carrier::E6, TrackingCode::C).unwrap();
+    /// assert_eq!(observable.to_string(), "L6C");
+    ///
+    /// assert!(Observable::new(ObservableKind::Phase, Carrier::L1, TrackingCode::Q).is_err());
+    /// ```
+    pub fn new(
+        kind: ObservableKind,
+        carrier: Carrier,
+        code: TrackingCode,
+    ) -> Result<Self, ParsingError> {
+        let constellations = carrier.constellations();
+
+        if !constellations
+            .iter()
+            .any(|constellation| code.is_valid(*constellation, carrier))
+        {
+            return Err(ParsingError::InvalidTrackingCode(
+                code,
+                constellations[0],
+                carrier,
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            carrier,
+            code,
+        })
+    }
+
+    /// Parses and validates a RINEX (V3+) observation code (like "C1C"),
+    /// according to this [Constellation] band and tracking code definitions.
+    /// Augmentation systems all follow [Constellation::SBAS] definitions.
+    pub fn from_rinex(constellation: Constellation, code: &str) -> Result<Self, ParsingError> {
+        let mut chars = code.trim().chars();
+
+        let (kind, band, code) = match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(kind), Some(band), Some(code), None) => (kind, band, code),
+            _ => return Err(ParsingError::InvalidFormat),
+        };
+
+        let constellation = if constellation.is_sbas() {
+            Constellation::SBAS
+        } else {
+            constellation
+        };
+
+        let kind = ObservableKind::from_letter(kind)?;
+
+        let carrier = band_carrier(constellation, band)
+            .ok_or(ParsingError::UnknownBand(band, constellation))?;
+
+        let code = TrackingCode::from_letter(code)?;
+
+        if !code.is_valid(constellation, carrier) {
+            return Err(ParsingError::InvalidTrackingCode(
+                code,
+                constellation,
+                carrier,
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            carrier,
+            code,
+        })
+    }
+
+    /// Returns the [ObservableUnit] of this [Observable] measurements.
+    pub const fn unit(&self) -> ObservableUnit {
+        self.kind.unit()
+    }
+
+    /// Returns the [Signal] this [Observable] measures, when broadcasted by this [Constellation].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// let observable = Observable::from_rinex(Constellation::GPS, "L1C").unwrap();
+    /// let signal = observable.signal(Constellation::GPS).unwrap();
+    /// assert_eq!(signal.to_string(), "G1C");
+    ///
+    /// assert!(observable.signal(Constellation::Galileo).is_err());
+    /// ```
+    pub fn signal(&self, constellation: Constellation) -> Result<Signal, ParsingError> {
+        Signal::new(constellation, self.carrier, self.code)
+    }
+}
+
+impl core::fmt::Display for Observable {
+    /// Formats this [Observable] as a RINEX (V3+) observation code, like "C1C".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}{}{}", self.kind, rinex_band(self.carrier), self.code)
+    }
+}
+
+/// Returns the RINEX band number of this [Carrier].
+const fn rinex_band(carrier: Carrier) -> char {
+    match carrier {
+        Carrier::L1 | Carrier::E1 | Carrier::B1C | Carrier::G1 => '1',
+        Carrier::L2 | Carrier::G2 | Carrier::B1I => '2',
+        Carrier::G3 => '3',
+        Carrier::L5 | Carrier::E5a | Carrier::B2a => '5',
+        Carrier::E6 | Carrier::B3 | Carrier::L6 => '6',
+        Carrier::E5b | Carrier::B2b => '7',
+        Carrier::E5 => '8',
+        Carrier::S => '9',
+    }
+}
+
+/// Returns the [Carrier] designated by this RINEX band number, for this [Constellation].
+fn band_carrier(constellation: Constellation, band: char) -> Option<Carrier> {
+    match (constellation, band) {
+        (Constellation::GPS, '1') => Some(Carrier::L1),
+        (Constellation::GPS, '2') => Some(Carrier::L2),
+        (Constellation::GPS, '5') => Some(Carrier::L5),
+        (Constellation::Glonass, '1') => Some(Carrier::G1),
+        (Constellation::Glonass, '2') => Some(Carrier::G2),
+        (Constellation::Glonass, '3') => Some(Carrier::G3),
+        (Constellation::Galileo, '1') => Some(Carrier::E1),
+        (Constellation::Galileo, '5') => Some(Carrier::E5a),
+        (Constellation::Galileo, '6') => Some(Carrier::E6),
+        (Constellation::Galileo, '7') => Some(Carrier::E5b),
+        (Constellation::Galileo, '8') => Some(Carrier::E5),
+        (Constellation::BeiDou, '1') => Some(Carrier::B1C),
+        (Constellation::BeiDou, '2') => Some(Carrier::B1I),
+        (Constellation::BeiDou, '5') => Some(Carrier::B2a),
+        (Constellation::BeiDou, '6') => Some(Carrier::B3),
+        (Constellation::BeiDou, '7') => Some(Carrier::B2b),
+        (Constellation::QZSS, '1') => Some(Carrier::L1),
+        (Constellation::QZSS, '2') => Some(Carrier::L2),
+        (Constellation::QZSS, '5') => Some(Carrier::L5),
+        (Constellation::QZSS, '6') => Some(Carrier::L6),
+        (Constellation::IRNSS, '5') => Some(Carrier::L5),
+        (Constellation::IRNSS, '9') => Some(Carrier::S),
+        (Constellation::SBAS, '1') => Some(Carrier::L1),
+        (Constellation::SBAS, '5') => Some(Carrier::L5),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        band_carrier, code::tracking_codes, Observable, ObservableKind, ParsingError, TrackingCode,
+    };
+    use crate::prelude::{Carrier, Constellation};
+
+    #[test]
+    fn rinex_reciprocity() {
+        for constellation in [
+            Constellation::GPS,
+            Constellation::Glonass,
+            Constellation::Galileo,
+            Constellation::BeiDou,
+            Constellation::QZSS,
+            Constellation::IRNSS,
+            Constellation::SBAS,
+        ] {
+            for band in '0'..='9' {
+                let carrier = match band_carrier(constellation, band) {
+                    Some(carrier) => carrier,
+                    None => continue,
+                };
+
+                assert!(
+                    carrier.is_broadcasted_by(constellation),
+                    "{} does not broadcast {}",
+                    constellation,
+                    carrier
+                );
+
+                let codes = tracking_codes(constellation, carrier);
+                assert!(!codes.is_empty());
+
+                for kind in ['C', 'L', 'D', 'S'] {
+                    for code in codes.chars() {
+                        let rinex = format!("{}{}{}", kind, band, code);
+                        let observable = Observable::from_rinex(constellation, &rinex).unwrap();
+                        assert_eq!(observable.carrier, carrier);
+                        assert_eq!(observable.code.letter(), code);
+                        assert_eq!(observable.to_string(), rinex);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rinex_parsing() {
+        let observable = Observable::from_rinex(Constellation::EGNOS, "S1C").unwrap();
+        assert_eq!(observable.kind, ObservableKind::SSI);
+        assert_eq!(observable.carrier, Carrier::L1);
+
+        let observable = Observable::from_rinex(Constellation::Glonass, " D2P ").unwrap();
+        assert_eq!(observable.kind, ObservableKind::Doppler);
+        assert_eq!(observable.carrier, Carrier::G2);
+
+        for (code, error) in [
+            ("C1", ParsingError::InvalidFormat),
+            ("C1CX", ParsingError::InvalidFormat),
+            ("X1C", ParsingError::UnknownKind('X')),
+            ("C1F", ParsingError::UnknownTrackingCode('F')),
+            ("C4C", ParsingError::UnknownBand('4', Constellation::GPS)),
+            (
+                "L5C",
+                ParsingError::InvalidTrackingCode(TrackingCode::C, Constellation::GPS, Carrier::L5),
+            ),
+        ] {
+            assert_eq!(Observable::from_rinex(Constellation::GPS, code), Err(error));
+        }
+
+        assert_eq!(
+            Observable::new(ObservableKind::Phase, Carrier::L5, TrackingCode::C),
+            Observable::from_rinex(Constellation::IRNSS, "L5C"),
+        );
+
+        assert_eq!(
+            Observable::new(ObservableKind::Phase, Carrier::L1, TrackingCode::Q),
+            Err(ParsingError::InvalidTrackingCode(
+                TrackingCode::Q,
+                Constellation::GPS,
+                Carrier::L1
+            )),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn observable_serdes() {
+        let observable = Observable::from_rinex(Constellation::GPS, "C1C").unwrap();
+
+        let content = serde_json::to_string(&observable).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Observable>(&content).unwrap(),
+            observable
+        );
+
+        // deserialization is validated like Observable::new
+        let content = content.replace("\"code\":\"C\"", "\"code\":\"Q\"");
+        assert!(serde_json::from_str::<Observable>(&content).is_err());
+    }
+}
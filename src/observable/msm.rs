@@ -0,0 +1,231 @@
+//! RTCM (V3) MSM signal identifiers
+use super::{Signal, TrackingCode};
+use crate::prelude::{Carrier, Constellation};
+use alloc::vec::Vec;
+
+use Carrier as F;
+use TrackingCode as T;
+
+/// GPS MSM signal identifiers (RTCM 10403.3 table 3.5-91)
+const GPS: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::L1, T::C),
+    (3, F::L1, T::P),
+    (4, F::L1, T::W),
+    (8, F::L2, T::C),
+    (9, F::L2, T::P),
+    (10, F::L2, T::W),
+    (15, F::L2, T::S),
+    (16, F::L2, T::L),
+    (17, F::L2, T::X),
+    (22, F::L5, T::I),
+    (23, F::L5, T::Q),
+    (24, F::L5, T::X),
+    (30, F::L1, T::S),
+    (31, F::L1, T::L),
+    (32, F::L1, T::X),
+];
+
+/// Glonass MSM signal identifiers (RTCM 10403.3 table 3.5-96)
+const GLONASS: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::G1, T::C),
+    (3, F::G1, T::P),
+    (8, F::G2, T::C),
+    (9, F::G2, T::P),
+];
+
+/// Galileo MSM signal identifiers (RTCM 10403.3 table 3.5-99)
+const GALILEO: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::E1, T::C),
+    (3, F::E1, T::A),
+    (4, F::E1, T::B),
+    (5, F::E1, T::X),
+    (6, F::E1, T::Z),
+    (8, F::E6, T::C),
+    (9, F::E6, T::A),
+    (10, F::E6, T::B),
+    (11, F::E6, T::X),
+    (12, F::E6, T::Z),
+    (14, F::E5b, T::I),
+    (15, F::E5b, T::Q),
+    (16, F::E5b, T::X),
+    (18, F::E5, T::I),
+    (19, F::E5, T::Q),
+    (20, F::E5, T::X),
+    (22, F::E5a, T::I),
+    (23, F::E5a, T::Q),
+    (24, F::E5a, T::X),
+];
+
+/// SBAS MSM signal identifiers (RTCM 10403.3 table 3.5-102)
+const SBAS: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::L1, T::C),
+    (22, F::L5, T::I),
+    (23, F::L5, T::Q),
+    (24, F::L5, T::X),
+];
+
+/// QZSS MSM signal identifiers (RTCM 10403.3 table 3.5-105)
+const QZSS: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::L1, T::C),
+    (9, F::L6, T::S),
+    (10, F::L6, T::L),
+    (11, F::L6, T::X),
+    (15, F::L2, T::S),
+    (16, F::L2, T::L),
+    (17, F::L2, T::X),
+    (22, F::L5, T::I),
+    (23, F::L5, T::Q),
+    (24, F::L5, T::X),
+    (30, F::L1, T::S),
+    (31, F::L1, T::L),
+    (32, F::L1, T::X),
+];
+
+/// BeiDou MSM signal identifiers (RTCM 10403.3 table 3.5-108)
+const BEIDOU: &[(u8, Carrier, TrackingCode)] = &[
+    (2, F::B1I, T::I),
+    (3, F::B1I, T::Q),
+    (4, F::B1I, T::X),
+    (8, F::B3, T::I),
+    (9, F::B3, T::Q),
+    (10, F::B3, T::X),
+    (14, F::B2b, T::I),
+    (15, F::B2b, T::Q),
+    (16, F::B2b, T::X),
+    (22, F::B2a, T::D),
+    (23, F::B2a, T::P),
+    (24, F::B2a, T::X),
+    (25, F::B2b, T::D),
+    (30, F::B1C, T::D),
+    (31, F::B1C, T::P),
+    (32, F::B1C, T::X),
+];
+
+/// IRNSS MSM signal identifiers (RTCM 10403.3 amendment 1)
+const IRNSS: &[(u8, Carrier, TrackingCode)] = &[(8, F::S, T::A), (22, F::L5, T::A)];
+
+/// Returns the MSM signal identifiers table of this [Constellation].
+fn msm_table(constellation: Constellation) -> &'static [(u8, Carrier, TrackingCode)] {
+    match constellation {
+        Constellation::GPS => GPS,
+        Constellation::Glonass => GLONASS,
+        Constellation::Galileo => GALILEO,
+        Constellation::BeiDou => BEIDOU,
+        Constellation::QZSS => QZSS,
+        Constellation::IRNSS => IRNSS,
+        constellation if constellation.is_sbas() => SBAS,
+        _ => &[],
+    }
+}
+
+impl Signal {
+    /// Identifies a [Signal] from its RTCM MSM signal identifier (1 to 32),
+    /// for this [Constellation]. Returns [None] for reserved identifiers.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let signal = Signal::from_msm_signal_id(Constellation::Galileo, 15).unwrap();
+    /// assert_eq!(signal, Signal::from_str("E7Q").unwrap());
+    /// assert_eq!(signal.msm_signal_id(), Some(15));
+    ///
+    /// assert!(Signal::from_msm_signal_id(Constellation::GPS, 1).is_none());
+    ///
+    /// // signals that MSM cannot describe
+    /// assert!(Signal::from_str("G2D").unwrap().msm_signal_id().is_none());
+    /// ```
+    pub fn from_msm_signal_id(constellation: Constellation, id: u8) -> Option<Self> {
+        msm_table(constellation)
+            .iter()
+            .find(|(signal_id, _, _)| *signal_id == id)
+            .and_then(|(_, carrier, code)| Self::new(constellation, *carrier, *code).ok())
+    }
+
+    /// Returns the RTCM MSM signal identifier (1 to 32) of this [Signal],
+    /// or [None] if MSM messages cannot describe it.
+    pub fn msm_signal_id(&self) -> Option<u8> {
+        msm_table(self.constellation)
+            .iter()
+            .find(|(_, carrier, code)| *carrier == self.carrier && *code == self.code)
+            .map(|(id, _, _)| *id)
+    }
+
+    /// Returns all [Signal]s described by this RTCM MSM signal mask, for this [Constellation].
+    /// The most significant bit stands for signal identifier 1. Reserved identifiers are ignored.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// // GPS 1C, 2W
+    /// let mask = (1 << 30) | (1 << 22);
+    ///
+    /// let signals = Signal::from_msm_signal_mask(Constellation::GPS, mask);
+    /// assert_eq!(
+    ///     signals,
+    ///     vec![Signal::from_str("G1C").unwrap(), Signal::from_str("G2W").unwrap()],
+    /// );
+    ///
+    /// assert_eq!(Signal::msm_signal_mask(&signals), mask);
+    /// ```
+    pub fn from_msm_signal_mask(constellation: Constellation, mask: u32) -> Vec<Self> {
+        (1..=32)
+            .filter(|id| mask & (1 << (32 - id)) != 0)
+            .filter_map(|id| Self::from_msm_signal_id(constellation, id))
+            .collect()
+    }
+
+    /// Builds the RTCM MSM signal mask describing these [Signal]s.
+    /// [Signal]s that MSM messages cannot describe are ignored.
+    /// The [Constellation] is not verified: a mask describes a single [Constellation].
+    pub fn msm_signal_mask(signals: &[Self]) -> u32 {
+        signals
+            .iter()
+            .filter_map(|signal| signal.msm_signal_id())
+            .fold(0, |mask, id| mask | (1 << (32 - id)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::msm_table;
+    use crate::prelude::{Constellation, Signal};
+
+    #[test]
+    fn msm_reciprocity() {
+        for constellation in [
+            Constellation::GPS,
+            Constellation::Glonass,
+            Constellation::Galileo,
+            Constellation::BeiDou,
+            Constellation::QZSS,
+            Constellation::IRNSS,
+            Constellation::SBAS,
+        ] {
+            let table = msm_table(constellation);
+            assert!(!table.is_empty());
+
+            for (id, carrier, code) in table {
+                assert!((1..=32).contains(id));
+
+                // tables only contain valid signals
+                let signal = Signal::from_msm_signal_id(constellation, *id)
+                    .unwrap_or_else(|| panic!("{}: invalid signal id {}", constellation, id));
+
+                assert_eq!(signal.carrier, *carrier);
+                assert_eq!(signal.code, *code);
+                assert_eq!(signal.msm_signal_id(), Some(*id));
+            }
+
+            let signals = Signal::from_msm_signal_mask(constellation, u32::MAX);
+            assert_eq!(signals.len(), table.len());
+            assert_eq!(
+                Signal::from_msm_signal_mask(constellation, Signal::msm_signal_mask(&signals)),
+                signals
+            );
+        }
+
+        assert!(Signal::from_msm_signal_id(Constellation::EGNOS, 22).is_some());
+        assert!(Signal::from_msm_signal_id(Constellation::Mixed, 2).is_none());
+        assert!(Signal::from_msm_signal_id(Constellation::GPS, 33).is_none());
+    }
+}
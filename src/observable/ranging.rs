@@ -0,0 +1,244 @@
+//! Ranging codes properties
+use super::{Signal, TrackingCode};
+use crate::prelude::{Carrier, Constellation};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// GNSS signal [Modulation]s
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Modulation {
+    /// Binary phase shift keying
+    BPSK,
+
+    /// Binary offset carrier BOC(m, n): m x 1.023 MHz sub-carrier,
+    /// n x 1.023 MHz chipping rate
+    BOC(u8, u8),
+
+    /// Multiplexed BOC: BOC(1,1) and BOC(6,1) combination,
+    /// like Galileo E1 CBOC, GPS L1C TMBOC or BeiDou B1C QMBOC
+    MBOC,
+
+    /// Alternative BOC(m, n), like Galileo E5 AltBOC(15, 10)
+    AltBOC(u8, u8),
+}
+
+impl core::fmt::Display for Modulation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BPSK => write!(f, "BPSK"),
+            Self::BOC(m, n) => write!(f, "BOC({},{})", m, n),
+            Self::MBOC => write!(f, "MBOC(6,1,1/11)"),
+            Self::AltBOC(m, n) => write!(f, "AltBOC({},{})", m, n),
+        }
+    }
+}
+
+/// [RangingCode] describes the ranging code of a [Signal].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct RangingCode {
+    /// [Modulation]
+    pub modulation: Modulation,
+
+    /// Chipping rate, in Hz
+    pub chipping_rate_hz: f64,
+
+    /// Primary code length, in chips
+    pub length: u64,
+
+    /// Secondary (overlay) code length, in bits. 1 when there is no secondary code.
+    pub secondary_length: u32,
+}
+
+impl RangingCode {
+    const fn new(
+        modulation: Modulation,
+        chipping_rate_hz: f64,
+        length: u64,
+        secondary_length: u32,
+    ) -> Self {
+        Self {
+            modulation,
+            chipping_rate_hz,
+            length,
+            secondary_length,
+        }
+    }
+
+    /// Returns the primary code period, in seconds.
+    pub fn period_s(&self) -> f64 {
+        self.length as f64 / self.chipping_rate_hz
+    }
+}
+
+/// GPS, QZSS and SBAS L1 C/A
+const CA: RangingCode = RangingCode::new(Modulation::BPSK, 1.023E6, 1023, 1);
+
+/// GPS P code (one week)
+const P: RangingCode = RangingCode::new(Modulation::BPSK, 10.23E6, 6_187_104_000_000, 1);
+
+/// GPS and QZSS L1C data
+const L1CD: RangingCode = RangingCode::new(Modulation::BOC(1, 1), 1.023E6, 10230, 1);
+
+/// GPS and QZSS L1C pilot
+const L1CP: RangingCode = RangingCode::new(Modulation::MBOC, 1.023E6, 10230, 1800);
+
+/// GPS and QZSS L2C moderate length
+const L2CM: RangingCode = RangingCode::new(Modulation::BPSK, 511.5E3, 10230, 1);
+
+/// GPS and QZSS L2C long
+const L2CL: RangingCode = RangingCode::new(Modulation::BPSK, 511.5E3, 767250, 1);
+
+/// GPS and QZSS L5 in phase
+const L5I: RangingCode = RangingCode::new(Modulation::BPSK, 10.23E6, 10230, 10);
+
+/// GPS and QZSS L5 quadrature
+const L5Q: RangingCode = RangingCode::new(Modulation::BPSK, 10.23E6, 10230, 20);
+
+/// SBAS L5
+const SBAS_L5: RangingCode = RangingCode::new(Modulation::BPSK, 10.23E6, 10230, 1);
+
+/// Glonass C/A
+const GLO_CA: RangingCode = RangingCode::new(Modulation::BPSK, 511.0E3, 511, 1);
+
+/// Glonass P (one second)
+const GLO_P: RangingCode = RangingCode::new(Modulation::BPSK, 5.11E6, 5_110_000, 1);
+
+/// Galileo E1 OS
+const fn e1(secondary_length: u32) -> RangingCode {
+    RangingCode::new(Modulation::MBOC, 1.023E6, 4092, secondary_length)
+}
+
+/// Galileo E5a, E5b, Glonass L3, BeiDou B2a and B3
+const fn bpsk10(secondary_length: u32) -> RangingCode {
+    RangingCode::new(Modulation::BPSK, 10.23E6, 10230, secondary_length)
+}
+
+/// Galileo E6 CS
+const fn e6(secondary_length: u32) -> RangingCode {
+    RangingCode::new(Modulation::BPSK, 5.115E6, 5115, secondary_length)
+}
+
+/// BeiDou B1I and B2I
+const B1I: RangingCode = RangingCode::new(Modulation::BPSK, 2.046E6, 2046, 20);
+
+/// QZSS L6
+const L6: RangingCode = RangingCode::new(Modulation::BPSK, 5.115E6, 10230, 1);
+
+impl Signal {
+    /// Returns the [RangingCode] of this [Signal]. Combined tracking codes
+    /// (like 'X') describe the pilot component. Returns [None] for restricted
+    /// or encrypted signals (like Galileo PRS or GPS P(Y) and M codes).
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::observable::Modulation;
+    /// use std::str::FromStr;
+    ///
+    /// let code = Signal::from_str("E1C").unwrap().ranging_code().unwrap();
+    /// assert_eq!(code.modulation, Modulation::MBOC);
+    /// assert_eq!(code.chipping_rate_hz, 1.023E6);
+    /// assert_eq!(code.length, 4092);
+    /// assert_eq!(code.secondary_length, 25);
+    /// assert_eq!(code.period_s(), 4.0E-3);
+    ///
+    /// let code = Signal::from_str("G5Q").unwrap().ranging_code().unwrap();
+    /// assert_eq!(code.modulation, Modulation::BPSK);
+    /// assert_eq!(code.chipping_rate_hz, 10.23E6);
+    ///
+    /// assert!(Signal::from_str("E1A").unwrap().ranging_code().is_none());
+    /// ```
+    pub fn ranging_code(&self) -> Option<RangingCode> {
+        use Carrier as F;
+        use Constellation as C;
+        use TrackingCode as T;
+
+        let code = match (self.constellation, self.carrier, self.code) {
+            (C::GPS | C::QZSS | C::SBAS, F::L1, T::C) => CA,
+            (C::GPS, F::L1 | F::L2, T::P) => P,
+            (C::GPS | C::QZSS, F::L1, T::S) => L1CD,
+            (C::GPS | C::QZSS, F::L1, T::L | T::X) => L1CP,
+            (C::GPS, F::L2, T::C) => CA,
+            (C::GPS | C::QZSS, F::L2, T::S) => L2CM,
+            (C::GPS | C::QZSS, F::L2, T::L | T::X) => L2CL,
+            (C::GPS | C::QZSS, F::L5, T::I) => L5I,
+            (C::GPS | C::QZSS, F::L5, T::Q | T::X) => L5Q,
+            (C::QZSS, F::L1, T::Z) => CA,
+            (C::QZSS, F::L5, T::D) => L5I,
+            (C::QZSS, F::L5, T::P | T::Z) => L5Q,
+            (C::QZSS, F::L6, T::S | T::L | T::X | T::E | T::Z) => L6,
+            (C::SBAS, F::L5, T::I | T::Q | T::X) => SBAS_L5,
+            (C::Glonass, F::G1 | F::G2, T::C) => GLO_CA,
+            (C::Glonass, F::G1 | F::G2, T::P) => GLO_P,
+            (C::Glonass, F::G3, T::I) => bpsk10(5),
+            (C::Glonass, F::G3, T::Q | T::X) => bpsk10(10),
+            (C::Galileo, F::E1, T::B) => e1(1),
+            (C::Galileo, F::E1, T::C | T::X) => e1(25),
+            (C::Galileo, F::E5a, T::I) => bpsk10(20),
+            (C::Galileo, F::E5b, T::I) => bpsk10(4),
+            (C::Galileo, F::E5a | F::E5b, T::Q | T::X) => bpsk10(100),
+            (C::Galileo, F::E5, T::Q | T::X) => {
+                RangingCode::new(Modulation::AltBOC(15, 10), 10.23E6, 10230, 100)
+            },
+            (C::Galileo, F::E6, T::B) => e6(1),
+            (C::Galileo, F::E6, T::C | T::X) => e6(100),
+            (C::BeiDou, F::B1I | F::B2b, T::I | T::X) => B1I,
+            (C::BeiDou, F::B1C, T::D) => RangingCode::new(Modulation::BOC(1, 1), 1.023E6, 10230, 1),
+            (C::BeiDou, F::B1C, T::P | T::X) => {
+                RangingCode::new(Modulation::MBOC, 1.023E6, 10230, 1800)
+            },
+            (C::BeiDou, F::B2a, T::D) => bpsk10(5),
+            (C::BeiDou, F::B2a, T::P | T::X) => bpsk10(100),
+            (C::BeiDou, F::B2b, T::D) => bpsk10(1),
+            (C::BeiDou, F::B3, T::I | T::X) => bpsk10(20),
+            (C::IRNSS, F::L5 | F::S, T::A) => CA,
+            _ => return None,
+        };
+
+        Some(code)
+    }
+
+    /// Returns the chipping rate (in Hz) of this [Signal], see [Signal::ranging_code].
+    pub fn chipping_rate_hz(&self) -> Option<f64> {
+        self.ranging_code().map(|code| code.chipping_rate_hz)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Carrier, Constellation, Signal, TrackingCode};
+
+    #[test]
+    fn ranging_codes() {
+        for constellation in Constellation::VARIANTS {
+            for carrier in Carrier::VARIANTS {
+                for code in TrackingCode::VARIANTS {
+                    let signal = match Signal::new(*constellation, *carrier, *code) {
+                        Ok(signal) => signal,
+                        Err(_) => continue,
+                    };
+
+                    if let Some(ranging) = signal.ranging_code() {
+                        // primary codes last 1 ms to 1.5 s (except GPS P code)
+                        let period = ranging.period_s();
+                        if signal.code != TrackingCode::P {
+                            assert!(
+                                (1.0E-3..=1.5).contains(&period),
+                                "{}: invalid period {}",
+                                signal,
+                                period
+                            );
+                        }
+                        assert!(ranging.secondary_length > 0);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! GNSS signals
+use super::{band_carrier, rinex_band, ParsingError, TrackingCode};
+use crate::prelude::{Carrier, Constellation};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// [Signal] describes a GNSS signal: a [Constellation], a [Carrier] and a [TrackingCode],
+/// validated against the RINEX (V3+) signal plan. This is how bias products (DCB, OSB)
+/// and RTCM MSM masks identify signals.
+/// [Signal] is formatted as the constellation identifier followed by the RINEX band
+/// and tracking code, like "G1C" or "E5Q".
+/// ```
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let signal = Signal::from_str("E5Q").unwrap();
+/// assert_eq!(signal.constellation, Constellation::Galileo);
+/// assert_eq!(signal.carrier, Carrier::E5a);
+/// assert_eq!(signal.code, TrackingCode::Q);
+/// assert_eq!(signal.to_string(), "E5Q");
+///
+/// let signal = Signal::new(Constellation::GPS, Carrier::L1, TrackingCode::C).unwrap();
+/// assert_eq!(signal.to_string(), "G1C");
+///
+/// // invalid combinations
+/// assert!(Signal::new(Constellation::Galileo, Carrier::E1, TrackingCode::P).is_err());
+/// assert!(Signal::new(Constellation::GPS, Carrier::E6, TrackingCode::C).is_err());
+/// assert!(Signal::from_str("G7X").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SignalFields"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Signal {
+    /// [Constellation] broadcasting this [Signal]
+    pub constellation: Constellation,
+
+    /// Signal [Carrier]
+    pub carrier: Carrier,
+
+    /// [TrackingCode]
+    pub code: TrackingCode,
+}
+
+/// Deserialized [Signal] fields, validated by [Signal::new].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename = "Signal")]
+struct SignalFields {
+    constellation: Constellation,
+    carrier: Carrier,
+    code: TrackingCode,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SignalFields> for Signal {
+    type Error = ParsingError;
+
+    fn try_from(fields: SignalFields) -> Result<Self, Self::Error> {
+        Self::new(fields.constellation, fields.carrier, fields.code)
+    }
+}
+
+impl Signal {
+    /// Builds a new [Signal], verifying that this [Constellation] broadcasts this
+    /// [Carrier] and [TrackingCode]. Augmentation systems are all described
+    /// as [Constellation::SBAS], as they share the same signal plan.
+    pub fn new(
+        constellation: Constellation,
+        carrier: Carrier,
+        code: TrackingCode,
+    ) -> Result<Self, ParsingError> {
+        let constellation = if constellation.is_sbas() {
+            Constellation::SBAS
+        } else {
+            constellation
+        };
+
+        if !carrier.is_broadcasted_by(constellation) {
+            return Err(ParsingError::InvalidCarrier(carrier, constellation));
+        }
+
+        if !code.is_valid(constellation, carrier) {
+            return Err(ParsingError::InvalidTrackingCode(
+                code,
+                constellation,
+                carrier,
+            ));
+        }
+
+        Ok(Self {
+            constellation,
+            carrier,
+            code,
+        })
+    }
+
+    /// Returns the carrier frequency of this [Signal], in Hz.
+    pub const fn frequency_hz(&self) -> f64 {
+        self.carrier.frequency_hz()
+    }
+}
+
+impl Constellation {
+    /// Returns the complete signal plan of this [Constellation]: all [Signal]s
+    /// defined by the RINEX (V3+) specifications, sorted by [Carrier] then [TrackingCode].
+    /// Augmentation systems all share the [Constellation::SBAS] signal plan.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let signals = Constellation::Galileo.signals();
+    /// assert!(signals.contains(&Signal::from_str("E1C").unwrap()));
+    /// assert!(signals.contains(&Signal::from_str("E8X").unwrap()));
+    ///
+    /// assert_eq!(Constellation::EGNOS.signals().len(), 4);
+    /// assert!(Constellation::Mixed.signals().is_empty());
+    /// ```
+    pub fn signals(&self) -> Vec<Signal> {
+        Carrier::VARIANTS
+            .iter()
+            .flat_map(|carrier| {
+                TrackingCode::VARIANTS
+                    .iter()
+                    .filter_map(|code| Signal::new(*self, *carrier, *code).ok())
+            })
+            .collect()
+    }
+}
+
+impl core::fmt::Display for Signal {
+    /// Formats this [Signal] like "G1C".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{:x}{}{}",
+            self.constellation,
+            rinex_band(self.carrier),
+            self.code
+        )
+    }
+}
+
+impl core::str::FromStr for Signal {
+    type Err = ParsingError;
+
+    /// Parses [Signal] from its constellation identifier, RINEX band
+    /// and tracking code, like "G1C".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+
+        if !string.is_ascii() || string.len() != 3 {
+            return Err(ParsingError::InvalidFormat);
+        }
+
+        let constellation = Constellation::from_str(&string[..1])?;
+        let constellation = if constellation.is_sbas() {
+            Constellation::SBAS
+        } else {
+            constellation
+        };
+
+        let mut chars = string[1..].chars();
+
+        let (band, code) = match (chars.next(), chars.next()) {
+            (Some(band), Some(code)) => (band, code),
+            _ => return Err(ParsingError::InvalidFormat),
+        };
+
+        let carrier = band_carrier(constellation, band)
+            .ok_or(ParsingError::UnknownBand(band, constellation))?;
+
+        Self::new(constellation, carrier, TrackingCode::from_letter(code)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Signal;
+    use crate::observable::ParsingError;
+    use crate::prelude::{Carrier, Constellation, TrackingCode};
+    use std::str::FromStr;
+
+    #[test]
+    fn signal_reciprocity() {
+        for constellation in Constellation::VARIANTS {
+            for carrier in Carrier::VARIANTS {
+                for code in TrackingCode::VARIANTS {
+                    let signal = match Signal::new(*constellation, *carrier, *code) {
+                        Ok(signal) => signal,
+                        Err(_) => continue,
+                    };
+
+                    let parsed = Signal::from_str(&signal.to_string()).unwrap();
+                    assert_eq!(parsed, signal);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signal_plans() {
+        for (constellation, count) in [
+            (Constellation::GPS, 22),
+            (Constellation::Glonass, 7),
+            (Constellation::Galileo, 19),
+            (Constellation::BeiDou, 21),
+            (Constellation::QZSS, 21),
+            (Constellation::IRNSS, 8),
+            (Constellation::SBAS, 4),
+        ] {
+            let signals = constellation.signals();
+            assert_eq!(
+                signals.len(),
+                count,
+                "invalid {} signal plan",
+                constellation
+            );
+
+            let mut sorted = signals.clone();
+            sorted.sort();
+            assert_eq!(sorted, signals);
+        }
+    }
+
+    #[test]
+    fn signal_parsing() {
+        let signal = Signal::from_str("S5I").unwrap();
+        assert_eq!(signal.constellation, Constellation::SBAS);
+        assert_eq!(signal.carrier, Carrier::L5);
+
+        let signal = Signal::new(Constellation::EGNOS, Carrier::L1, TrackingCode::C).unwrap();
+        assert_eq!(signal.constellation, Constellation::SBAS);
+
+        assert_eq!(Signal::from_str("G1"), Err(ParsingError::InvalidFormat));
+        assert_eq!(Signal::from_str("G1CX"), Err(ParsingError::InvalidFormat));
+        assert!(matches!(
+            Signal::from_str("X1C"),
+            Err(ParsingError::ConstellationParsing(_))
+        ));
+        assert_eq!(
+            Signal::from_str("C1C"),
+            Err(ParsingError::InvalidTrackingCode(
+                TrackingCode::C,
+                Constellation::BeiDou,
+                Carrier::B1C
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn signal_serdes() {
+        let signal = Signal::from_str("G1C").unwrap();
+
+        let content = serde_json::to_string(&signal).unwrap();
+        assert_eq!(
+            content,
+            "{\"constellation\":\"GPS\",\"carrier\":\"L1\",\"code\":\"C\"}"
+        );
+        assert_eq!(serde_json::from_str::<Signal>(&content).unwrap(), signal);
+
+        let bytes = bincode::serialize(&signal).unwrap();
+        assert_eq!(bincode::deserialize::<Signal>(&bytes).unwrap(), signal);
+
+        // deserialization is validated like Signal::new
+        assert!(serde_json::from_str::<Signal>(
+            "{\"constellation\":\"GPS\",\"carrier\":\"E6\",\"code\":\"C\"}"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! String serialization of signal identifiers, for readable datasets.
+//!
+//! By default, [Carrier](crate::prelude::Carrier), [Signal](crate::prelude::Signal),
+//! [TrackingCode](crate::prelude::TrackingCode) and [ObservableKind](crate::prelude::ObservableKind)
+//! derive their serialization, which binary formats encode as compact variant indices.
+//! This module serializes them as their standard RINEX descriptions instead
+//! (like "L1", "G1C", "C" or "L"), which is how bias and correction products name signals.
+//! Deserialization accepts any description compatible with [FromStr].
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use gnss_rs::prelude::*;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Bias {
+//!     #[serde(with = "gnss_rs::observable::string")]
+//!     signal: Signal,
+//!
+//!     #[serde(with = "gnss_rs::observable::string")]
+//!     carrier: Carrier,
+//!
+//!     value_ns: f64,
+//! }
+//! ```
+//!
+//! The [map] submodule serializes maps indexed by these types, which become string keys:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::BTreeMap;
+//! use gnss_rs::prelude::*;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Biases {
+//!     #[serde(with = "gnss_rs::observable::string::map")]
+//!     biases_ns: BTreeMap<Signal, f64>,
+//! }
+//! ```
+use alloc::string::ToString;
+use core::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes this value as its RINEX description.
+pub fn serialize<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+/// Deserializes a value from its RINEX description.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StringVisitor(PhantomData))
+}
+
+struct StringVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr> de::Visitor<'_> for StringVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a RINEX signal description, like \"G1C\"")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        T::from_str(value).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Serialization of maps indexed by signal identifiers, using RINEX descriptions as keys.
+/// This applies to any map type, like `HashMap<Signal, T>` or `BTreeMap<Carrier, T>`.
+pub mod map {
+    use super::StringVisitor;
+
+    use alloc::string::ToString;
+    use core::{fmt::Display, marker::PhantomData, str::FromStr};
+
+    use serde::{
+        de::{self, DeserializeSeed, MapAccess},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes this map, with keys as RINEX descriptions.
+    pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+        K: Display + 'a,
+        V: Serialize + 'a,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        for (key, value) in map {
+            state.serialize_entry(&key.to_string(), value)?;
+        }
+        state.end()
+    }
+
+    /// Deserializes a map, with keys as RINEX descriptions.
+    pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: FromIterator<(K, V)>,
+        K: FromStr,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+
+    struct KeySeed<K>(PhantomData<K>);
+
+    impl<'de, K: FromStr> DeserializeSeed<'de> for KeySeed<K> {
+        type Value = K;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<K, D::Error> {
+            deserializer.deserialize_str(StringVisitor(PhantomData))
+        }
+    }
+
+    struct MapVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+    impl<'de, M, K, V> de::Visitor<'de> for MapVisitor<M, K, V>
+    where
+        M: FromIterator<(K, V)>,
+        K: FromStr,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "a map indexed by RINEX signal descriptions")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut entries = alloc::vec::Vec::with_capacity(access.size_hint().unwrap_or(0));
+            while let Some(key) = access.next_key_seed(KeySeed(PhantomData))? {
+                entries.push((key, access.next_value()?));
+            }
+            Ok(entries.into_iter().collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Carrier, ObservableKind, Signal, TrackingCode};
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::observable::string")]
+        signal: Signal,
+
+        #[serde(with = "crate::observable::string")]
+        carrier: Carrier,
+
+        #[serde(with = "crate::observable::string")]
+        code: TrackingCode,
+
+        #[serde(with = "crate::observable::string")]
+        kind: ObservableKind,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Maps {
+        #[serde(with = "crate::observable::string::map")]
+        signals: HashMap<Signal, u32>,
+
+        #[serde(with = "crate::observable::string::map")]
+        carriers: BTreeMap<Carrier, f64>,
+    }
+
+    #[test]
+    fn string_serdes() {
+        let record = Record {
+            signal: Signal::from_str("E5Q").unwrap(),
+            carrier: Carrier::E5a,
+            code: TrackingCode::Q,
+            kind: ObservableKind::Phase,
+        };
+
+        let content = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            content,
+            "{\"signal\":\"E5Q\",\"carrier\":\"E5a\",\"code\":\"Q\",\"kind\":\"L\"}"
+        );
+
+        let parsed: Record = serde_json::from_str(&content).unwrap();
+        assert_eq!(parsed, record);
+
+        assert!(serde_json::from_str::<Record>(
+            "{\"signal\":\"G7X\",\"carrier\":\"E5a\",\"code\":\"Q\",\"kind\":\"L\"}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn map_serdes() {
+        let maps = Maps {
+            signals: HashMap::from([
+                (Signal::from_str("G1C").unwrap(), 1),
+                (Signal::from_str("G2W").unwrap(), 2),
+            ]),
+            carriers: BTreeMap::from([(Carrier::L1, 10.0), (Carrier::L2, 20.0)]),
+        };
+
+        let content = serde_json::to_string(&maps).unwrap();
+        assert!(content.contains("\"carriers\":{\"L1\":10.0,\"L2\":20.0}"));
+
+        let parsed: Maps = serde_json::from_str(&content).unwrap();
+        assert_eq!(parsed, maps);
+    }
+}
@@ -1,35 +1,633 @@
-//! SBAS (geo service) selector helper.
-use bincode::{config, Decode};
-
-use crate::prelude::Constellation;
+//! SBAS (geo service) selector and coverage helpers.
+use crate::{
+    coverage::SBAS_COVERAGE,
+    prelude::{Constellation, SV},
+    sv::{
+        geo::{GEO_RADIUS_M, WGS84_A_M},
+        geo_elevation_azimuth_deg, sbas_vehicles, sbas_vehicles_active, SbasVehicle,
+    },
+};
 use core::str::FromStr;
+use hifitime::Epoch;
 use once_cell::sync::Lazy;
+use thiserror::Error;
+
+use geo::{
+    BooleanOps, ChamberlainDuquetteArea, Contains, Geometry as GeoGeometry, LineString,
+    MultiPolygon, Point, Polygon, Rect, Translate,
+};
+
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry, JsonObject, JsonValue, Value};
 
-use geo::{Contains, LineString, Point, Polygon};
+use wkt::TryFromWkt;
 
-#[derive(Decode)]
-struct SbasPolygon {
-    name: String,
-    coordinates: Vec<(f64, f64)>,
+/// SBAS coverage database related errors
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SbasCoverageError {
+    #[error("geojson error: {0}")]
+    GeoJson(String),
+    #[error("wkt error: {0}")]
+    Wkt(String),
+    #[error("expecting a feature collection or a feature")]
+    InvalidGeoJson,
+    #[error("service name not defined")]
+    MissingName,
+    #[error("unknown SBAS service \"{0}\"")]
+    UnknownService(String),
+    #[error("{0}: expecting polygons")]
+    InvalidGeometry(Constellation),
+    #[error("{0}: invalid date \"{1}\"")]
+    InvalidDate(Constellation, String),
 }
 
-#[derive(Default, Decode)]
-struct SbasMap {
-    polygons: Vec<SbasPolygon>,
+/// SBAS service area.
+#[derive(Clone, Debug, PartialEq)]
+struct SbasService {
+    constellation: Constellation,
+    polygon: Polygon,
+
+    /// Service area name, when the provider publishes several
+    area: Option<String>,
+
+    /// Start of operations, [None] when not operating yet
+    operational_from: Option<Epoch>,
+
+    /// End of operations, [None] while still operating
+    operational_until: Option<Epoch>,
 }
 
-static SBAS_POLYGONS: Lazy<SbasMap> = Lazy::new(|| {
-    let config = config::standard();
+impl SbasService {
+    /// Returns true if this service is operating at this [Epoch].
+    fn is_operational(&self, epoch: Epoch) -> bool {
+        match self.operational_from {
+            Some(from) => epoch >= from && self.operational_until.is_none_or(|until| epoch < until),
+            None => false,
+        }
+    }
+}
+
+/// GEO satellite serving a [SbasSelection], as seen from the query point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SbasGeo {
+    /// Satellite to track
+    pub sv: SV,
+
+    /// Elevation angle, in degrees
+    pub elevation_deg: f64,
+
+    /// Azimuth angle, in degrees clockwise from north
+    pub azimuth_deg: f64,
+
+    /// True if this satellite broadcasts the DFMC (L5) service
+    pub dfmc: bool,
+}
+
+/// [SbasSelection] describes the SBAS service selected at given coordinates
+/// (see [sbas_selection]), and the GEO satellites actually serving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SbasSelection {
+    /// Selected SBAS [Constellation]
+    pub constellation: Constellation,
+
+    /// GEO satellites of this service, above the horizon, by decreasing elevation
+    pub geos: Vec<SbasGeo>,
+
+    /// True if the DFMC (L5) service may be received
+    pub dfmc: bool,
+}
+
+impl SbasSelection {
+    /// Builds a [SbasSelection] for this [Constellation], from these (active) vehicles
+    /// as seen from this [Point] (x=longitude°, y=latitude°).
+    fn new<'a>(
+        constellation: Constellation,
+        vehicles: impl Iterator<Item = &'a SbasVehicle>,
+        point: Point,
+    ) -> Self {
+        let mut geos = vehicles
+            .filter(|vehicle| same_provider(vehicle.provider(), constellation))
+            .filter_map(|vehicle| {
+                let (elevation_deg, azimuth_deg) =
+                    geo_elevation_azimuth_deg(vehicle.longitude_deg, point.y(), point.x(), 0.0);
+
+                if elevation_deg > 0.0 {
+                    Some(SbasGeo {
+                        sv: vehicle.sv,
+                        elevation_deg,
+                        azimuth_deg,
+                        dfmc: vehicle.dfmc,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        geos.sort_by(|lhs, rhs| rhs.elevation_deg.total_cmp(&lhs.elevation_deg));
+
+        Self {
+            constellation,
+            dfmc: geos.iter().any(|geo| geo.dfmc),
+            geos,
+        }
+    }
+}
+
+/// [SbasCoverage] is a database of SBAS service areas, on which the selection
+/// helpers operate. Our builtin database ([SbasCoverage::builtin]) is a coarse approximation:
+/// operators usually have better service masks, which may be loaded at runtime,
+/// either to replace or to refine it (see [SbasCoverage::merge]).
+/// ```
+/// use geo::Point;
+/// use gnss_rs::{prelude::*, SbasCoverage};
+///
+/// // refined EGNOS contour, only covering France
+/// let refined = SbasCoverage::from_wkt(
+///     Constellation::EGNOS,
+///     "POLYGON((-5 42, 8 42, 8 51, -5 51, -5 42))",
+/// )
+/// .unwrap();
+///
+/// let mut coverage = SbasCoverage::builtin();
+/// coverage.merge(refined);
+///
+/// let paris = Point::new(2.38262, 48.808378); //x=longitude°, y=latitude°
+/// assert_eq!(coverage.selector(paris), Some(Constellation::EGNOS));
+///
+/// let berlin = Point::new(13.4, 52.5);
+/// assert_eq!(coverage.selector(berlin), None);
+///```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SbasCoverage {
+    services: Vec<SbasService>,
+}
+
+impl SbasCoverage {
+    /// Returns our builtin (coarse) coverage database. It is embedded in the library
+    /// at build time, and does not require any file at runtime.
+    pub fn builtin() -> Self {
+        (*SBAS_SERVICES).clone()
+    }
+
+    /// Parses a coverage database from a GeoJSON feature collection (or single feature).
+    /// Each feature is a Polygon (or MultiPolygon) of (longitude°, latitude°) coordinates,
+    /// with a "name" property describing the service (like "EGNOS").
+    /// Optional "operational_from" and "operational_until" properties (like
+    /// "2009-10-01T00:00:00 UTC") describe the service lifetime: services without
+    /// "operational_from" are not considered operational (see [SbasCoverage::selector_at]).
+    pub fn from_geojson(content: &str) -> Result<Self, SbasCoverageError> {
+        let geojson = content
+            .parse::<GeoJson>()
+            .map_err(|e| SbasCoverageError::GeoJson(e.to_string()))?;
+
+        let features = match geojson {
+            GeoJson::FeatureCollection(collection) => collection.features,
+            GeoJson::Feature(feature) => vec![feature],
+            GeoJson::Geometry(_) => return Err(SbasCoverageError::InvalidGeoJson),
+        };
+
+        let mut services = Vec::with_capacity(features.len());
+
+        for feature in features.iter() {
+            let name = feature
+                .property("name")
+                .and_then(|name| name.as_str())
+                .ok_or(SbasCoverageError::MissingName)?;
+
+            let constellation = Constellation::from_str(name)
+                .ok()
+                .filter(|constellation| constellation.is_sbas())
+                .ok_or(SbasCoverageError::UnknownService(name.to_string()))?;
+
+            let epoch = |key: &str| match feature.property(key).and_then(|value| value.as_str()) {
+                Some(value) => Epoch::from_str(value)
+                    .map(Some)
+                    .map_err(|_| SbasCoverageError::InvalidDate(constellation, value.to_string())),
+                None => Ok(None),
+            };
+
+            let (operational_from, operational_until) =
+                (epoch("operational_from")?, epoch("operational_until")?);
+
+            let area = feature
+                .property("area")
+                .and_then(|area| area.as_str())
+                .map(|area| area.to_string());
+
+            let geometry = feature
+                .geometry
+                .clone()
+                .ok_or(SbasCoverageError::InvalidGeometry(constellation))?;
+
+            let polygons = match GeoGeometry::try_from(geometry) {
+                Ok(GeoGeometry::Polygon(polygon)) => vec![polygon],
+                Ok(GeoGeometry::MultiPolygon(polygons)) => polygons.0,
+                _ => return Err(SbasCoverageError::InvalidGeometry(constellation)),
+            };
+
+            for polygon in polygons {
+                services.push(SbasService {
+                    constellation,
+                    polygon,
+                    area: area.clone(),
+                    operational_from,
+                    operational_until,
+                });
+            }
+        }
+
+        Ok(Self { services })
+    }
+
+    /// Parses the service area of this SBAS [Constellation] from a WKT Polygon
+    /// (or MultiPolygon) of (longitude°, latitude°) coordinates.
+    /// WKT does not describe the service lifetime: this service is considered
+    /// operational since the GPS epoch (see [SbasCoverage::selector_at]).
+    pub fn from_wkt(
+        constellation: Constellation,
+        content: &str,
+    ) -> Result<Self, SbasCoverageError> {
+        let polygons = match GeoGeometry::<f64>::try_from_wkt_str(content) {
+            Ok(GeoGeometry::Polygon(polygon)) => vec![polygon],
+            Ok(GeoGeometry::MultiPolygon(polygons)) => polygons.0,
+            Ok(_) => return Err(SbasCoverageError::InvalidGeometry(constellation)),
+            Err(e) => return Err(SbasCoverageError::Wkt(e.to_string())),
+        };
+
+        let services = polygons
+            .into_iter()
+            .map(|polygon| SbasService {
+                constellation,
+                polygon,
+                area: None,
+                operational_from: Some(Epoch::from_gpst_seconds(0.0)),
+                operational_until: None,
+            })
+            .collect();
+
+        Ok(Self { services })
+    }
+
+    /// Builds a "line-of-sight" coverage database from the footprints (see [geo_footprint])
+    /// of the GEO satellites of our SBAS database, for this elevation mask (in degrees).
+    /// Unlike the official service areas, this describes where the signals may be received,
+    /// each footprint being operational during the service period of its satellite.
+    /// ```
+    /// use geo::Point;
+    /// use gnss_rs::{prelude::*, sbas_selector, SbasCoverage};
+    ///
+    /// let line_of_sight = SbasCoverage::line_of_sight(10.0);
+    ///
+    /// // Argentina is not served by any SBAS, but some GEOs are visible
+    /// let cordoba = Point::new(-64.18, -31.42);
+    /// assert_eq!(sbas_selector(cordoba), None);
+    /// assert!(line_of_sight.selector(cordoba).is_some());
+    ///
+    /// let paris = Point::new(2.38262, 48.808378);
+    /// assert_eq!(line_of_sight.selector(paris), Some(Constellation::EGNOS));
+    /// ```
+    pub fn line_of_sight(elevation_mask_deg: f64) -> Self {
+        let services = sbas_vehicles()
+            .flat_map(|vehicle| {
+                geo_footprint(vehicle.longitude_deg, elevation_mask_deg)
+                    .0
+                    .into_iter()
+                    .map(|polygon| SbasService {
+                        constellation: vehicle.provider(),
+                        polygon,
+                        area: Some(vehicle.name.to_string()),
+                        operational_from: Some(vehicle.service_start()),
+                        operational_until: vehicle.decommission,
+                    })
+            })
+            .collect();
+
+        Self { services }
+    }
+
+    /// Merges other service areas into this database. Services described by
+    /// the other database replace ours, and take precedence during selection.
+    pub fn merge(&mut self, other: Self) {
+        self.services.retain(|service| {
+            !other
+                .services
+                .iter()
+                .any(|refined| refined.constellation == service.constellation)
+        });
+
+        self.services.splice(0..0, other.services);
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°),
+    /// see [sbas_selector].
+    pub fn selector(&self, point: Point) -> Option<Constellation> {
+        self.services
+            .iter()
+            .find(|service| service.polygon.contains(&point))
+            .map(|service| service.constellation)
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°)
+    /// and operating at this [Epoch], see [sbas_selector_at].
+    pub fn selector_at(&self, point: Point, epoch: Epoch) -> Option<Constellation> {
+        self.services
+            .iter()
+            .find(|service| service.is_operational(epoch) && service.polygon.contains(&point))
+            .map(|service| service.constellation)
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°)
+    /// and operating at this [Epoch], with its GEO satellites, see [sbas_selection].
+    pub fn selection(&self, point: Point, epoch: Epoch) -> Option<SbasSelection> {
+        let constellation = self.selector_at(point, epoch)?;
+        Some(SbasSelection::new(
+            constellation,
+            sbas_vehicles_active(epoch),
+            point,
+        ))
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°),
+    /// and the name of the service area, when the provider publishes several,
+    /// see [sbas_service_area].
+    pub fn service_area(&self, point: Point) -> Option<(Constellation, Option<&str>)> {
+        self.services
+            .iter()
+            .find(|service| service.polygon.contains(&point))
+            .map(|service| (service.constellation, service.area.as_deref()))
+    }
+
+    /// Returns the service area of this SBAS [Constellation] (all its areas combined),
+    /// see [sbas_coverage].
+    pub fn coverage(&self, constellation: Constellation) -> Option<MultiPolygon> {
+        let polygons = self
+            .services
+            .iter()
+            .filter(|service| service.constellation == constellation)
+            .map(|service| service.polygon.clone())
+            .collect::<Vec<_>>();
+
+        if polygons.is_empty() {
+            None
+        } else {
+            Some(MultiPolygon::new(polygons))
+        }
+    }
+
+    /// Returns this named service area of this SBAS [Constellation], see [sbas_service_area].
+    pub fn area_coverage(&self, constellation: Constellation, area: &str) -> Option<Polygon> {
+        self.services
+            .iter()
+            .find(|service| {
+                service.constellation == constellation && service.area.as_deref() == Some(area)
+            })
+            .map(|service| service.polygon.clone())
+    }
+
+    /// Iterates over all service areas, see [sbas_coverages].
+    pub fn coverages(&self) -> impl Iterator<Item = (Constellation, &Polygon)> {
+        self.services
+            .iter()
+            .map(|service| (service.constellation, &service.polygon))
+    }
+
+    /// Returns the fraction of this area covered by each SBAS service,
+    /// see [sbas_coverage_fraction].
+    pub fn coverage_fraction(&self, area: &Polygon) -> Vec<(Constellation, f64)> {
+        let total = area.chamberlain_duquette_unsigned_area();
+        if total == 0.0 {
+            return Vec::new();
+        }
+
+        let mut fractions = Vec::<(Constellation, f64)>::new();
 
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/sbas_polygons.bin"));
+        for service in self.services.iter() {
+            let covered = service
+                .polygon
+                .intersection(area)
+                .chamberlain_duquette_unsigned_area();
 
-    bincode::decode_from_slice(bytes, config)
-        .unwrap_or_else(|e| {
-            panic!("corrupt SBAS polygons database: {}", e);
+            if covered > 0.0 {
+                // service areas of the same provider are combined
+                match fractions
+                    .iter_mut()
+                    .find(|(constellation, _)| *constellation == service.constellation)
+                {
+                    Some((_, fraction)) => *fraction += covered / total,
+                    None => fractions.push((service.constellation, covered / total)),
+                }
+            }
+        }
+
+        for (_, fraction) in fractions.iter_mut() {
+            *fraction = fraction.min(1.0);
+        }
+
+        fractions.sort_by(|(_, lhs), (_, rhs)| rhs.total_cmp(lhs));
+        fractions
+    }
+}
+
+/// [SbasSelector] owns a coverage database ([SbasCoverage]) and a vehicle database
+/// ([SbasVehicle]s), on which all selection queries operate. It is built once
+/// (for example with refined service masks) and is [Send] + [Sync], so it may be shared
+/// across threads (like request handlers), without global state.
+/// ```
+/// use geo::{Point, Rect, coord};
+/// use std::{str::FromStr, sync::Arc, thread};
+/// use gnss_rs::{prelude::*, SbasCoverage, SbasSelector};
+///
+/// let selector = Arc::new(SbasSelector::new(SbasCoverage::builtin()));
+///
+/// let handler = {
+///     let selector = Arc::clone(&selector);
+///     thread::spawn(move || {
+///         let paris = Point::new(2.38262, 48.808378); //x=longitude°, y=latitude°
+///         selector.select(paris)
+///     })
+/// };
+///
+/// assert_eq!(handler.join().unwrap(), Some(Constellation::EGNOS));
+///
+/// let epoch = Epoch::from_str("2024-06-01T00:00:00 UTC").unwrap();
+/// let sydney = Point::new(151.21, -33.87);
+///
+/// let selection = selector.selection(sydney, epoch).unwrap();
+/// assert_eq!(selection.constellation, Constellation::SPAN);
+///
+/// let korea = Rect::new(coord! { x: 127.0, y: 35.0 }, coord! { x: 128.0, y: 37.0 }).to_polygon();
+/// assert_eq!(selector.coverage_fraction(&korea)[0].0, Constellation::KASS);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbasSelector {
+    coverage: SbasCoverage,
+    vehicles: Vec<SbasVehicle>,
+}
+
+impl Default for SbasSelector {
+    /// Builds a [SbasSelector] from our builtin databases.
+    fn default() -> Self {
+        Self::new(SbasCoverage::builtin())
+    }
+}
+
+impl SbasSelector {
+    /// Builds a [SbasSelector] from this [SbasCoverage] and our builtin vehicle database.
+    pub fn new(coverage: SbasCoverage) -> Self {
+        Self {
+            coverage,
+            vehicles: sbas_vehicles().copied().collect(),
+        }
+    }
+
+    /// Replaces the vehicle database, for example to describe
+    /// satellites that our builtin database does not know of.
+    pub fn with_vehicles(&self, vehicles: Vec<SbasVehicle>) -> Self {
+        let mut s = self.clone();
+        s.vehicles = vehicles;
+        s
+    }
+
+    /// Returns the [SbasCoverage] this [SbasSelector] operates on.
+    pub fn coverage(&self) -> &SbasCoverage {
+        &self.coverage
+    }
+
+    /// Iterates over the vehicle database of this [SbasSelector].
+    pub fn vehicles(&self) -> impl Iterator<Item = &SbasVehicle> {
+        self.vehicles.iter()
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°).
+    pub fn select(&self, point: Point) -> Option<Constellation> {
+        self.coverage.selector(point)
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°)
+    /// and operating at this [Epoch].
+    pub fn select_at(&self, point: Point, epoch: Epoch) -> Option<Constellation> {
+        self.coverage.selector_at(point, epoch)
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°)
+    /// and operating at this [Epoch], with its GEO satellites (from our vehicle database)
+    /// operating at that time.
+    pub fn selection(&self, point: Point, epoch: Epoch) -> Option<SbasSelection> {
+        let constellation = self.coverage.selector_at(point, epoch)?;
+
+        let vehicles = self
+            .vehicles
+            .iter()
+            .filter(|vehicle| vehicle.is_operational(epoch));
+
+        Some(SbasSelection::new(constellation, vehicles, point))
+    }
+
+    /// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°),
+    /// and the name of the service area, when the provider publishes several.
+    pub fn service_area(&self, point: Point) -> Option<(Constellation, Option<&str>)> {
+        self.coverage.service_area(point)
+    }
+
+    /// Returns the fraction of this area covered by each SBAS service,
+    /// sorted by decreasing fraction.
+    pub fn coverage_fraction(&self, area: &Polygon) -> Vec<(Constellation, f64)> {
+        self.coverage.coverage_fraction(area)
+    }
+}
+
+/// Our builtin service areas, built once from the coverage database,
+/// so selection does not allocate.
+static SBAS_SERVICES: Lazy<SbasCoverage> = Lazy::new(|| {
+    let services = SBAS_COVERAGE
+        .iter()
+        .filter_map(|(name, metadata, coordinates)| {
+            // errors will not happen here,
+            // because every single entry is validated in CI
+            let constellation = Constellation::from_str_const(name)?;
+
+            let property = |key: &str| {
+                metadata
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, value)| *value)
+            };
+
+            let epoch = |key: &str| property(key).and_then(|value| Epoch::from_str(value).ok());
+
+            Some(SbasService {
+                constellation,
+                polygon: Polygon::new(LineString::from(coordinates.to_vec()), vec![]),
+                area: property("area").map(|area| area.to_string()),
+                operational_from: epoch("operational_from"),
+                operational_until: epoch("operational_until"),
+            })
         })
-        .0
+        .collect();
+
+    SbasCoverage { services }
 });
 
+/// Returns true if both [Constellation]s describe the same SBAS provider:
+/// SouthPAN vehicles are described as [Constellation::AusNZ].
+fn same_provider(lhs: Constellation, rhs: Constellation) -> bool {
+    match (lhs, rhs) {
+        (Constellation::SPAN, Constellation::AusNZ) => true,
+        (Constellation::AusNZ, Constellation::SPAN) => true,
+        (lhs, rhs) => lhs == rhs,
+    }
+}
+
+/// Number of vertices describing a GEO footprint
+const FOOTPRINT_VERTICES: usize = 360;
+
+/// Returns the visibility footprint of a geostationary satellite located at this
+/// orbital longitude (in decimal degrees east): the area (x=longitude°, y=latitude°) from which
+/// it is seen above this elevation mask (in degrees), using a spherical Earth.
+/// Footprints crossing the antimeridian are split in two polygons.
+/// ```
+/// use geo::{Contains, Point};
+/// use gnss_rs::geo_footprint;
+///
+/// // ASTRA-5B (31.5°E)
+/// let footprint = geo_footprint(31.5, 10.0);
+/// assert!(footprint.contains(&Point::new(2.38, 48.8))); // Paris
+/// assert!(!footprint.contains(&Point::new(-118.19, 33.98))); // Los Angeles
+///
+/// // INMARSAT-4F1 (143.5°E)
+/// let footprint = geo_footprint(143.5, 5.0);
+/// assert_eq!(footprint.0.len(), 2);
+/// assert!(footprint.contains(&Point::new(-170.0, -14.0))); // Samoa
+/// ```
+pub fn geo_footprint(longitude_deg: f64, elevation_mask_deg: f64) -> MultiPolygon {
+    let elevation = elevation_mask_deg.clamp(0.0, 90.0).to_radians();
+
+    // earth central angle between the sub satellite point and the footprint edge
+    let psi = ((WGS84_A_M / GEO_RADIUS_M) * elevation.cos()).acos() - elevation;
+    let (sin_psi, cos_psi) = psi.sin_cos();
+
+    let exterior = (0..FOOTPRINT_VERTICES)
+        .map(|i| {
+            let azimuth = (i as f64 * 360.0 / FOOTPRINT_VERTICES as f64).to_radians();
+            let (sin_az, cos_az) = azimuth.sin_cos();
+
+            let latitude = (sin_psi * cos_az).asin().to_degrees();
+            let longitude = longitude_deg + (sin_az * sin_psi).atan2(cos_psi).to_degrees();
+            (longitude, latitude)
+        })
+        .collect::<Vec<_>>();
+
+    let footprint = Polygon::new(LineString::from(exterior), vec![]);
+    let world = Rect::new((-180.0, -90.0), (180.0, 90.0)).to_polygon();
+
+    let polygons = [-360.0, 0.0, 360.0]
+        .iter()
+        .flat_map(|offset| footprint.translate(*offset, 0.0).intersection(&world).0)
+        .collect();
+
+    MultiPolygon::new(polygons)
+}
+
 /// Helps select a SBAS (geostationary augmentation service) from user coordinates.
 /// ```
 /// use geo::Point;
@@ -45,34 +643,382 @@ static SBAS_POLYGONS: Lazy<SbasMap> = Lazy::new(|| {
 /// assert_eq!(sbas_selector(antarctica), None);
 ///```
 pub fn sbas_selector(point: Point) -> Option<Constellation> {
-    for entry in SBAS_POLYGONS.polygons.iter() {
-        let polygon = Polygon::new(LineString::from(entry.coordinates.clone()), vec![]);
-        if polygon.contains(&point) {
-            if let Ok(constellation) = Constellation::from_str(&entry.name) {
-                // errors will not happen here,
-                // because every single entrie is validated in CI
-                return Some(constellation);
+    SBAS_SERVICES.selector(point)
+}
+
+/// Helps select a SBAS (geostationary augmentation service) from user coordinates,
+/// like [sbas_selector], but only returns services actually operating at this [Epoch].
+/// Services that are not operational yet (like ASBAS or NSAS) are never returned.
+/// ```
+/// use geo::Point;
+/// use std::str::FromStr;
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_selector_at,
+/// };
+///
+/// let alice_springs = Point::new(133.87, -23.70); //x=longitude°, y=latitude°
+///
+/// let epoch = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+/// assert_eq!(sbas_selector_at(alice_springs, epoch), None);
+///
+/// let epoch = Epoch::from_str("2024-06-01T00:00:00 UTC").unwrap();
+/// assert_eq!(sbas_selector_at(alice_springs, epoch), Some(Constellation::SPAN));
+///```
+pub fn sbas_selector_at(point: Point, epoch: Epoch) -> Option<Constellation> {
+    SBAS_SERVICES.selector_at(point, epoch)
+}
+
+/// Selects the SBAS service covering these user coordinates and operating at this [Epoch],
+/// like [sbas_selector_at], and describes the GEO satellites to track:
+/// their elevation and azimuth from this [Point] (x=longitude°, y=latitude°), and whether
+/// they broadcast the DFMC service.
+/// ```
+/// use geo::Point;
+/// use std::str::FromStr;
+/// use gnss_rs::{
+///     sv,
+///     prelude::*,
+///     sbas_selection,
+/// };
+///
+/// let paris = Point::new(2.38262, 48.808378); //x=longitude°, y=latitude°
+/// let epoch = Epoch::from_str("2024-06-01T00:00:00 UTC").unwrap();
+///
+/// let selection = sbas_selection(paris, epoch).unwrap();
+/// assert_eq!(selection.constellation, Constellation::EGNOS);
+/// assert!(selection.dfmc);
+///
+/// // highest GEO first
+/// assert_eq!(selection.geos[0].sv, sv!("S36")); // SES-5
+/// assert!(selection.geos[0].elevation_deg > 30.0);
+///
+/// let prns = selection.geos.iter().map(|geo| geo.sv.prn).collect::<Vec<_>>();
+/// assert!(prns.contains(&23)); // ASTRA-5B
+///```
+pub fn sbas_selection(point: Point, epoch: Epoch) -> Option<SbasSelection> {
+    SBAS_SERVICES.selection(point, epoch)
+}
+
+/// Returns the service area of this SBAS [Constellation], as a [MultiPolygon] of
+/// (x=longitude°, y=latitude°) vertices, combining all its service areas
+/// (see [sbas_service_area]). Returns [None] for constellations
+/// that are not augmentation services, or not described by our database.
+/// ```
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_coverage,
+/// };
+///
+/// let egnos = sbas_coverage(Constellation::EGNOS).unwrap();
+/// assert_eq!(egnos.0.len(), 1);
+///
+/// // Australia and New Zealand
+/// let southpan = sbas_coverage(Constellation::SPAN).unwrap();
+/// assert_eq!(southpan.0.len(), 2);
+///
+/// assert!(sbas_coverage(Constellation::GPS).is_none());
+///```
+pub fn sbas_coverage(constellation: Constellation) -> Option<MultiPolygon> {
+    SBAS_SERVICES.coverage(constellation)
+}
+
+/// Selects the SBAS service covering this [Point] (x=longitude°, y=latitude°), like
+/// [sbas_selector], along with the name of the service area, for providers
+/// publishing several service areas (with their own milestones), like SouthPAN.
+/// ```
+/// use geo::Point;
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_service_area,
+/// };
+///
+/// let sydney = Point::new(151.21, -33.87); //x=longitude°, y=latitude°
+/// assert_eq!(sbas_service_area(sydney), Some((Constellation::SPAN, Some("Australia"))));
+///
+/// let wellington = Point::new(174.78, -41.29);
+/// assert_eq!(sbas_service_area(wellington), Some((Constellation::SPAN, Some("New Zealand"))));
+///
+/// let paris = Point::new(2.38262, 48.808378);
+/// assert_eq!(sbas_service_area(paris), Some((Constellation::EGNOS, None)));
+///```
+pub fn sbas_service_area(point: Point) -> Option<(Constellation, Option<&'static str>)> {
+    SBAS_SERVICES.service_area(point)
+}
+
+/// Iterates over all SBAS service areas of our database, as
+/// [Constellation] and [Polygon] of (x=longitude°, y=latitude°) vertices,
+/// for example to draw them.
+/// ```
+/// use gnss_rs::sbas_coverages;
+///
+/// for (constellation, polygon) in sbas_coverages() {
+///     assert!(constellation.is_sbas());
+///     assert!(polygon.exterior().0.len() > 3);
+/// }
+///```
+pub fn sbas_coverages() -> impl Iterator<Item = (Constellation, &'static Polygon)> {
+    SBAS_SERVICES.coverages()
+}
+
+/// Returns the fraction (within [0, 1]) of this area covered by each SBAS service,
+/// for services covering part of it, sorted by decreasing coverage.
+/// The area is a [Polygon] of (x=longitude°, y=latitude°) vertices: use
+/// `geo::Rect::to_polygon()` for bounding boxes. Service areas may overlap,
+/// so fractions may add up to more than one.
+/// ```
+/// use geo::{polygon, Rect, coord};
+/// use gnss_rs::{
+///     prelude::*,
+///     sbas_coverage_fraction,
+/// };
+///
+/// // France
+/// let france = Rect::new(coord! { x: -5.0, y: 42.0 }, coord! { x: 8.0, y: 51.0 }).to_polygon();
+/// assert_eq!(sbas_coverage_fraction(&france), vec![(Constellation::EGNOS, 1.0)]);
+///
+/// // Antarctica
+/// let antarctica = polygon![(x: 0.0, y: -80.0), (x: 90.0, y: -80.0), (x: 90.0, y: -70.0)];
+/// assert!(sbas_coverage_fraction(&antarctica).is_empty());
+///```
+pub fn sbas_coverage_fraction(area: &Polygon) -> Vec<(Constellation, f64)> {
+    SBAS_SERVICES.coverage_fraction(area)
+}
+
+/// Exports our SBAS coverage database as a GeoJSON [FeatureCollection],
+/// which plotting tools (like QGIS) consume directly. Each service area is a polygon
+/// [Feature], with the provider "name", its "constellation" identifier
+/// and the database metadata as properties.
+/// ```
+/// use gnss_rs::sbas_coverage_geojson;
+///
+/// let collection = sbas_coverage_geojson();
+/// assert_eq!(collection.features.len(), 16);
+///
+/// let geojson = collection.to_string();
+/// assert!(geojson.contains("\"constellation\":\"EGNOS\""));
+///```
+pub fn sbas_coverage_geojson() -> FeatureCollection {
+    let features = SBAS_COVERAGE
+        .iter()
+        .map(|(name, metadata, coordinates)| {
+            let mut properties = JsonObject::new();
+            properties.insert("name".to_string(), JsonValue::from(*name));
+
+            if let Some(constellation) = Constellation::from_str_const(name) {
+                properties.insert(
+                    "constellation".to_string(),
+                    JsonValue::from(constellation.as_str()),
+                );
             }
+
+            for (key, value) in metadata.iter() {
+                properties.insert(key.to_string(), JsonValue::from(*value));
+            }
+
+            let ring = coordinates
+                .iter()
+                .map(|(long, lat)| vec![*long, *lat])
+                .collect();
+
+            Feature {
+                bbox: None,
+                geometry: Some(Geometry::new(Value::Polygon(vec![ring]))),
+                id: None,
+                properties: Some(properties),
+                foreign_members: None,
+            }
+        })
+        .collect();
+
+    FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }
+}
+
+/// Exports our SBAS coverage database as a KML document, which mapping tools
+/// (like Google Earth) consume directly. Each service area is a polygon placemark,
+/// named after the provider, with its "constellation" identifier and the
+/// database metadata as extended data.
+/// ```
+/// use gnss_rs::sbas_coverage_kml;
+///
+/// let kml = sbas_coverage_kml();
+/// assert!(kml.starts_with("<?xml"));
+/// assert_eq!(kml.matches("<Placemark>").count(), 16);
+///```
+pub fn sbas_coverage_kml() -> String {
+    let mut kml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<kml xmlns=\"http://www.opengis.net/kml/2.2\">
+  <Document>
+    <name>SBAS coverage</name>
+",
+    );
+
+    for (name, metadata, coordinates) in SBAS_COVERAGE.iter() {
+        kml.push_str("    <Placemark>\n");
+        kml.push_str(&format!("      <name>{}</name>\n", xml_escape(name)));
+        kml.push_str("      <ExtendedData>\n");
+
+        if let Some(constellation) = Constellation::from_str_const(name) {
+            kml.push_str(&format!(
+                "        <Data name=\"constellation\"><value>{}</value></Data>\n",
+                constellation.as_str()
+            ));
         }
+
+        for (key, value) in metadata.iter() {
+            kml.push_str(&format!(
+                "        <Data name=\"{}\"><value>{}</value></Data>\n",
+                xml_escape(key),
+                xml_escape(value)
+            ));
+        }
+
+        kml.push_str("      </ExtendedData>\n");
+        kml.push_str("      <Polygon><outerBoundaryIs><LinearRing><coordinates>");
+
+        let coordinates = coordinates
+            .iter()
+            .map(|(long, lat)| format!("{},{}", long, lat))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        kml.push_str(&coordinates);
+        kml.push_str("</coordinates></LinearRing></outerBoundaryIs></Polygon>\n");
+        kml.push_str("    </Placemark>\n");
     }
-    None
+
+    kml.push_str("  </Document>\n</kml>\n");
+    kml
+}
+
+/// Escapes XML special characters of this text.
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{prelude::Constellation, sbas::SBAS_POLYGONS, sbas_selector};
-    use geo::Point;
+    use crate::sv::geo_elevation_azimuth_deg;
+    use crate::{
+        geo_footprint,
+        prelude::Constellation,
+        sbas::{xml_escape, SBAS_COVERAGE},
+        sbas_coverage, sbas_coverage_fraction, sbas_coverage_geojson, sbas_coverage_kml,
+        sbas_coverages, sbas_selection, sbas_selector, sbas_selector_at, sbas_selector_latlon,
+        sbas_service_area, SbasCoverage, SbasCoverageError, SbasSelector,
+    };
+    use geo::{coord, Contains, InteriorPoint, Point, Rect};
+    use geojson::GeoJson;
+    use hifitime::Epoch;
     use std::str::FromStr;
 
     #[test]
-    fn test_database() {
-        for entry in SBAS_POLYGONS.polygons.iter() {
-            assert!(
-                Constellation::from_str(&entry.name).is_ok(),
-                "invalid constellation name found \"{}\"",
-                entry.name
-            );
+    fn test_geo_footprint() {
+        for longitude in [-107.3, 0.0, 31.5, 143.5, 179.5] {
+            for mask in [0.0, 5.0, 20.0] {
+                let footprint = geo_footprint(longitude, mask);
+                assert!(footprint.contains(&Point::new(longitude, 0.0)));
+
+                for polygon in footprint.iter() {
+                    for coord in polygon.exterior().coords() {
+                        assert!(coord.x.abs() <= 180.0 + 1.0E-6, "invalid longitude");
+
+                        // antimeridian cuts are not on the footprint edge
+                        if (coord.x.abs() - 180.0).abs() < 1.0E-6 {
+                            continue;
+                        }
+
+                        let (elevation, _) =
+                            geo_elevation_azimuth_deg(longitude, coord.y, coord.x, 0.0);
+
+                        assert!(
+                            (elevation - mask).abs() < 0.5,
+                            "invalid footprint edge ({}, {}) for GEO {}°",
+                            coord.x,
+                            coord.y,
+                            longitude,
+                        );
+                    }
+                }
+            }
         }
+
+        let line_of_sight = SbasCoverage::line_of_sight(5.0);
+        let paris = Point::new(2.38268, 48.808378);
+        assert_eq!(line_of_sight.selector(paris), Some(Constellation::EGNOS));
+
+        let epoch = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+        assert_eq!(line_of_sight.selector_at(paris, epoch), None);
+    }
+
+    #[test]
+    fn test_sbas_selection() {
+        let epoch = Epoch::from_str("2024-06-01T00:00:00 UTC").unwrap();
+
+        for (lat_ddeg, long_ddeg, expected, dfmc) in [
+            (48.808378, 2.38268, Constellation::EGNOS, true),
+            (33.981431, -118.193601, Constellation::WAAS, true),
+            (-27.579847, 131.334992, Constellation::SPAN, true),
+            (60.004390, 89.090326, Constellation::SDCM, false),
+        ] {
+            let point = Point::new(long_ddeg, lat_ddeg);
+            let selection = sbas_selection(point, epoch).unwrap();
+
+            assert_eq!(selection.constellation, expected);
+            assert_eq!(selection.dfmc, dfmc);
+            assert!(!selection.geos.is_empty());
+
+            for geo in selection.geos.iter() {
+                assert!(geo.elevation_deg > 0.0);
+                assert!(geo.sv.sbas_vehicle().is_some());
+            }
+
+            for pair in selection.geos.windows(2) {
+                assert!(pair[0].elevation_deg >= pair[1].elevation_deg);
+            }
+        }
+
+        // not operating yet
+        let epoch = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+        let alice_springs = Point::new(133.87, -23.70);
+        assert_eq!(sbas_selection(alice_springs, epoch), None);
+    }
+
+    #[test]
+    fn test_sbas_selector_service() {
+        fn is_send_sync<T: Send + Sync>() {}
+        is_send_sync::<SbasSelector>();
+
+        let selector = SbasSelector::default();
+        assert_eq!(selector.vehicles().count(), 16);
+
+        let epoch = Epoch::from_str("2024-06-01T00:00:00 UTC").unwrap();
+        let paris = Point::new(2.38268, 48.808378);
+
+        assert_eq!(selector.select(paris), sbas_selector(paris));
+        assert_eq!(
+            selector.select_at(paris, epoch),
+            sbas_selector_at(paris, epoch)
+        );
+        assert_eq!(
+            selector.selection(paris, epoch),
+            sbas_selection(paris, epoch)
+        );
+
+        // without vehicles
+        let selector = selector.with_vehicles(Vec::new());
+        let selection = selector.selection(paris, epoch).unwrap();
+        assert_eq!(selection.constellation, Constellation::EGNOS);
+        assert!(selection.geos.is_empty());
+        assert!(!selection.dfmc);
     }
 
     #[test]
@@ -99,6 +1045,283 @@ mod test {
                 lat_ddeg,
                 long_ddeg
             );
+            assert_eq!(sbas_selector_latlon(lat_ddeg, long_ddeg), expected);
+        }
+    }
+
+    #[test]
+    fn test_regional_coverages() {
+        for (city, lat_ddeg, long_ddeg, expected, area) in [
+            ("Dakar", 14.69, -17.44, Constellation::ASBAS, Some("ASECNA")),
+            (
+                "N'Djamena",
+                12.13,
+                15.06,
+                Constellation::ASBAS,
+                Some("ASECNA"),
+            ),
+            (
+                "Antananarivo",
+                -18.88,
+                47.51,
+                Constellation::ASBAS,
+                Some("ASECNA"),
+            ),
+            (
+                "Johannesburg",
+                -26.20,
+                28.05,
+                Constellation::ASBAS,
+                Some("South Africa"),
+            ),
+            ("Lagos", 6.52, 3.38, Constellation::NSAS, None),
+            ("Abuja", 9.08, 7.40, Constellation::NSAS, None),
+            ("Tamanrasset", 22.79, 5.52, Constellation::ASAL, None),
+            (
+                "Petropavlovsk-Kamchatsky",
+                53.02,
+                158.65,
+                Constellation::SDCM,
+                None,
+            ),
+            ("Anadyr", 64.73, 177.51, Constellation::SDCM, None),
+            ("Lavrentiya", 65.58, -171.0, Constellation::SDCM, None),
+            ("Delhi", 28.61, 77.21, Constellation::GAGAN, None),
+            ("Colombo", 6.93, 79.86, Constellation::GAGAN, None),
+        ] {
+            let point = Point::new(long_ddeg, lat_ddeg);
+            assert_eq!(
+                sbas_service_area(point),
+                Some((expected, area)),
+                "invalid service area for {}",
+                city
+            );
+            assert_eq!(sbas_selector_latlon(lat_ddeg, long_ddeg), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_selectors_agreement() {
+        for lat_ddeg in (-85..=85).step_by(5) {
+            for long_ddeg in (-175..=175).step_by(5) {
+                // avoid polygon boundaries, where selectors may differ
+                let (lat_ddeg, long_ddeg) = (lat_ddeg as f64 + 0.1, long_ddeg as f64 + 0.1);
+                assert_eq!(
+                    sbas_selector(Point::new(long_ddeg, lat_ddeg)),
+                    sbas_selector_latlon(lat_ddeg, long_ddeg),
+                    "selectors differ at lat={}° long={}°",
+                    lat_ddeg,
+                    long_ddeg
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sbas_coverages() {
+        assert_eq!(sbas_coverages().count(), 16);
+
+        for (constellation, polygon) in sbas_coverages() {
+            assert!(constellation.is_sbas());
+            assert!(sbas_coverage(constellation).unwrap().0.contains(polygon));
+        }
+
+        let coverage = SbasCoverage::builtin();
+
+        for area in ["Australia", "New Zealand"] {
+            let polygon = coverage.area_coverage(Constellation::SPAN, area).unwrap();
+            let point = polygon.interior_point().unwrap();
+            assert_eq!(
+                sbas_service_area(point),
+                Some((Constellation::SPAN, Some(area)))
+            );
+            assert_eq!(sbas_selector(point), Some(Constellation::SPAN));
+        }
+
+        assert!(coverage
+            .area_coverage(Constellation::SPAN, "Antarctica")
+            .is_none());
+
+        assert!(sbas_coverage(Constellation::Galileo).is_none());
+    }
+
+    #[test]
+    fn test_coverage_exports() {
+        let geojson = sbas_coverage_geojson().to_string();
+
+        let parsed = geojson.parse::<GeoJson>().unwrap();
+        let collection = match parsed {
+            GeoJson::FeatureCollection(collection) => collection,
+            _ => panic!("invalid geojson export"),
+        };
+
+        assert_eq!(collection.features.len(), sbas_coverages().count());
+
+        for feature in collection.features.iter() {
+            assert!(feature.property("name").is_some());
+            assert!(feature.property("source_note").is_some());
+            assert!(feature.property("approximation").is_some());
+        }
+
+        let kml = sbas_coverage_kml();
+        assert!(kml.contains("<name>EGNOS (Europe)</name>"));
+        assert!(kml.contains("<Data name=\"constellation\"><value>WAAS</value></Data>"));
+        assert!(kml.contains("<coordinates>-25,30 40,30 40,72 -25,72 -25,30</coordinates>"));
+        assert!(kml.trim_end().ends_with("</kml>"));
+
+        assert_eq!(
+            xml_escape("GBAS <UK> & \"co\""),
+            "GBAS &lt;UK&gt; &amp; &quot;co&quot;"
+        );
+    }
+
+    #[test]
+    fn test_operational_dates() {
+        for (name, metadata, _) in SBAS_COVERAGE.iter() {
+            for (key, value) in metadata.iter() {
+                if key.starts_with("operational") {
+                    assert!(
+                        Epoch::from_str(value).is_ok(),
+                        "{}: invalid {} date \"{}\"",
+                        name,
+                        key,
+                        value
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sbas_selector_at() {
+        let early = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+        let today = Epoch::from_str("2025-01-01T00:00:00 UTC").unwrap();
+
+        for (lat_ddeg, long_ddeg, at_early, at_today) in [
+            (
+                48.808378,
+                2.38268,
+                Some(Constellation::EGNOS),
+                Some(Constellation::EGNOS),
+            ),
+            (
+                33.981431,
+                -118.193601,
+                Some(Constellation::WAAS),
+                Some(Constellation::WAAS),
+            ),
+            (
+                37.067846,
+                128.34,
+                Some(Constellation::MSAS),
+                Some(Constellation::KASS),
+            ),
+            (-27.579847, 131.334992, None, Some(Constellation::SPAN)),
+            (-32.473320, 21.112770, None, None), // not operational
+        ] {
+            let point = Point::new(long_ddeg, lat_ddeg);
+            assert_eq!(sbas_selector_at(point, early), at_early);
+            assert_eq!(sbas_selector_at(point, today), at_today);
+        }
+    }
+
+    #[test]
+    fn test_sbas_coverage_fraction() {
+        // half inside WAAS (southern edge at 5°N)
+        let area =
+            Rect::new(coord! { x: -80.0, y: -1.0 }, coord! { x: -78.0, y: 11.0 }).to_polygon();
+        let fractions = sbas_coverage_fraction(&area);
+        assert_eq!(fractions.len(), 1);
+        assert_eq!(fractions[0].0, Constellation::WAAS);
+        assert!((fractions[0].1 - 0.5).abs() < 0.01);
+
+        // korea: MSAS and KASS overlap
+        let area =
+            Rect::new(coord! { x: 127.0, y: 35.0 }, coord! { x: 128.0, y: 37.0 }).to_polygon();
+        let fractions = sbas_coverage_fraction(&area);
+        assert!(fractions.len() > 1);
+        for (_, fraction) in fractions.iter() {
+            assert!(*fraction > 0.0 && *fraction <= 1.0);
         }
+
+        let empty = Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 0.0, y: 0.0 }).to_polygon();
+        assert!(sbas_coverage_fraction(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_custom_coverage() {
+        let builtin = SbasCoverage::builtin();
+        assert_eq!(builtin.coverages().count(), sbas_coverages().count());
+
+        let custom = SbasCoverage::from_geojson(
+            r#"{
+                "type": "FeatureCollection",
+                "features": [
+                    {
+                        "type": "Feature",
+                        "properties": {
+                            "name": "EGNOS",
+                            "operational_from": "2011-03-02T00:00:00 UTC"
+                        },
+                        "geometry": {
+                            "type": "Polygon",
+                            "coordinates": [[[-5, 42], [8, 42], [8, 51], [-5, 51], [-5, 42]]]
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let berlin = Point::new(13.4, 52.5);
+        let paris = Point::new(2.38262, 48.808378);
+        let before = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+        let after = Epoch::from_str("2012-01-01T00:00:00 UTC").unwrap();
+
+        // replaces builtin definition
+        let mut merged = builtin.clone();
+        merged.merge(custom.clone());
+
+        assert_eq!(merged.coverages().count(), builtin.coverages().count());
+        assert_eq!(merged.selector(berlin), None);
+        assert_eq!(merged.selector(paris), Some(Constellation::EGNOS));
+        assert_eq!(merged.selector_at(paris, before), None);
+        assert_eq!(merged.selector_at(paris, after), Some(Constellation::EGNOS));
+        assert_eq!(
+            merged.selector(Point::new(-118.193601, 33.981431)),
+            Some(Constellation::WAAS)
+        );
+
+        // used instead of builtin
+        assert_eq!(custom.selector(Point::new(-118.193601, 33.981431)), None);
+
+        let wkt = SbasCoverage::from_wkt(
+            Constellation::WAAS,
+            "MULTIPOLYGON(((-125 25, -65 25, -65 50, -125 50, -125 25)))",
+        )
+        .unwrap();
+        assert_eq!(
+            wkt.selector_at(Point::new(-100.0, 40.0), after),
+            Some(Constellation::WAAS)
+        );
+
+        assert!(matches!(
+            SbasCoverage::from_wkt(Constellation::WAAS, "POINT(10 20)"),
+            Err(SbasCoverageError::InvalidGeometry(Constellation::WAAS))
+        ));
+        assert!(matches!(
+            SbasCoverage::from_wkt(Constellation::WAAS, "POLYGON((10"),
+            Err(SbasCoverageError::Wkt(_))
+        ));
+        assert!(matches!(
+            SbasCoverage::from_geojson(
+                r#"{"type": "Feature", "properties": {"name": "GPS"}, "geometry": null}"#
+            ),
+            Err(SbasCoverageError::UnknownService(_))
+        ));
+        assert!(matches!(
+            SbasCoverage::from_geojson("not json"),
+            Err(SbasCoverageError::GeoJson(_))
+        ));
     }
 }
@@ -0,0 +1,467 @@
+//! SBAS message types
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Total length of any SBAS message (L1 or DFMC), in bits,
+/// broadcasted every second.
+pub const SBAS_MESSAGE_BITS: usize = 250;
+
+/// SBAS message type parsing issue
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SbasMessageParsingError {
+    #[error("invalid message type format")]
+    InvalidFormat,
+
+    #[error("unknown message type {0}")]
+    Unknown(u8),
+}
+
+/// Parses a message type number, like "MT18" or "18".
+fn parse_id(string: &str) -> Result<u8, SbasMessageParsingError> {
+    let string = string.trim();
+
+    let id = match string.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("mt") => &string[2..],
+        _ => string,
+    };
+
+    id.trim()
+        .parse::<u8>()
+        .map_err(|_| SbasMessageParsingError::InvalidFormat)
+}
+
+/// [L1MessageType] describes the message types of the legacy L1 SBAS service (RTCA DO-229).
+/// ```
+/// use gnss_rs::sbas_message::L1MessageType;
+/// use std::str::FromStr;
+///
+/// let mt = L1MessageType::try_from(18).unwrap();
+/// assert_eq!(mt, L1MessageType::IonosphericGridMask);
+/// assert!(mt.is_ionospheric());
+/// assert_eq!(mt.to_string(), "MT18");
+/// assert_eq!(u8::from(mt), 18);
+///
+/// assert_eq!(L1MessageType::from_str("MT2"), Ok(L1MessageType::FastCorrections2));
+/// assert_eq!(L1MessageType::payload_bits(), 212);
+///
+/// // reserved
+/// assert!(L1MessageType::try_from(40).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum L1MessageType {
+    /// MT0: do not use this SBAS for safety applications (test mode)
+    DoNotUse,
+
+    /// MT1: PRN mask assignments
+    PrnMask,
+
+    /// MT2: fast corrections, PRN mask slots 1 to 13
+    FastCorrections2,
+
+    /// MT3: fast corrections, PRN mask slots 14 to 26
+    FastCorrections3,
+
+    /// MT4: fast corrections, PRN mask slots 27 to 39
+    FastCorrections4,
+
+    /// MT5: fast corrections, PRN mask slots 40 to 51
+    FastCorrections5,
+
+    /// MT6: integrity information
+    Integrity,
+
+    /// MT7: fast correction degradation factors
+    FastCorrectionDegradation,
+
+    /// MT9: GEO navigation message (ranging function)
+    GeoNavigation,
+
+    /// MT10: degradation parameters
+    DegradationParameters,
+
+    /// MT12: SBAS network time to UTC offset parameters
+    NetworkTime,
+
+    /// MT17: GEO satellites almanacs
+    GeoAlmanac,
+
+    /// MT18: ionospheric grid point masks
+    IonosphericGridMask,
+
+    /// MT24: mixed fast and long term satellite corrections
+    MixedCorrections,
+
+    /// MT25: long term satellite error corrections
+    LongTermCorrections,
+
+    /// MT26: ionospheric delay corrections
+    IonosphericDelay,
+
+    /// MT27: SBAS service message
+    ServiceMessage,
+
+    /// MT28: clock and ephemeris covariance matrix
+    ClockEphemerisCovariance,
+
+    /// MT62: internal test message
+    InternalTest,
+
+    /// MT63: null message
+    Null,
+}
+
+impl L1MessageType {
+    /// All [L1MessageType]s, by increasing message type number.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::DoNotUse,
+        Self::PrnMask,
+        Self::FastCorrections2,
+        Self::FastCorrections3,
+        Self::FastCorrections4,
+        Self::FastCorrections5,
+        Self::Integrity,
+        Self::FastCorrectionDegradation,
+        Self::GeoNavigation,
+        Self::DegradationParameters,
+        Self::NetworkTime,
+        Self::GeoAlmanac,
+        Self::IonosphericGridMask,
+        Self::MixedCorrections,
+        Self::LongTermCorrections,
+        Self::IonosphericDelay,
+        Self::ServiceMessage,
+        Self::ClockEphemerisCovariance,
+        Self::InternalTest,
+        Self::Null,
+    ];
+
+    /// Returns the message type number (0..=63).
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::DoNotUse => 0,
+            Self::PrnMask => 1,
+            Self::FastCorrections2 => 2,
+            Self::FastCorrections3 => 3,
+            Self::FastCorrections4 => 4,
+            Self::FastCorrections5 => 5,
+            Self::Integrity => 6,
+            Self::FastCorrectionDegradation => 7,
+            Self::GeoNavigation => 9,
+            Self::DegradationParameters => 10,
+            Self::NetworkTime => 12,
+            Self::GeoAlmanac => 17,
+            Self::IonosphericGridMask => 18,
+            Self::MixedCorrections => 24,
+            Self::LongTermCorrections => 25,
+            Self::IonosphericDelay => 26,
+            Self::ServiceMessage => 27,
+            Self::ClockEphemerisCovariance => 28,
+            Self::InternalTest => 62,
+            Self::Null => 63,
+        }
+    }
+
+    /// Builds [L1MessageType] from its message type number.
+    /// Returns [None] for reserved (or spare) message types.
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::DoNotUse),
+            1 => Some(Self::PrnMask),
+            2 => Some(Self::FastCorrections2),
+            3 => Some(Self::FastCorrections3),
+            4 => Some(Self::FastCorrections4),
+            5 => Some(Self::FastCorrections5),
+            6 => Some(Self::Integrity),
+            7 => Some(Self::FastCorrectionDegradation),
+            9 => Some(Self::GeoNavigation),
+            10 => Some(Self::DegradationParameters),
+            12 => Some(Self::NetworkTime),
+            17 => Some(Self::GeoAlmanac),
+            18 => Some(Self::IonosphericGridMask),
+            24 => Some(Self::MixedCorrections),
+            25 => Some(Self::LongTermCorrections),
+            26 => Some(Self::IonosphericDelay),
+            27 => Some(Self::ServiceMessage),
+            28 => Some(Self::ClockEphemerisCovariance),
+            62 => Some(Self::InternalTest),
+            63 => Some(Self::Null),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of the data field of any L1 message, in bits:
+    /// the [SBAS_MESSAGE_BITS] minus the 8 bit preamble, 6 bit message type and 24 bit CRC.
+    pub const fn payload_bits() -> usize {
+        212
+    }
+
+    /// Returns true if this message carries fast corrections (MT2 to MT5, and MT24).
+    pub const fn is_fast_correction(&self) -> bool {
+        matches!(
+            self,
+            Self::FastCorrections2
+                | Self::FastCorrections3
+                | Self::FastCorrections4
+                | Self::FastCorrections5
+                | Self::MixedCorrections
+        )
+    }
+
+    /// Returns true if this message describes the ionosphere (MT18 and MT26).
+    pub const fn is_ionospheric(&self) -> bool {
+        matches!(self, Self::IonosphericGridMask | Self::IonosphericDelay)
+    }
+}
+
+impl TryFrom<u8> for L1MessageType {
+    type Error = SbasMessageParsingError;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or(SbasMessageParsingError::Unknown(id))
+    }
+}
+
+impl From<L1MessageType> for u8 {
+    fn from(mt: L1MessageType) -> u8 {
+        mt.id()
+    }
+}
+
+impl core::fmt::Display for L1MessageType {
+    /// Formats this [L1MessageType] like "MT18".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "MT{}", self.id())
+    }
+}
+
+impl core::str::FromStr for L1MessageType {
+    type Err = SbasMessageParsingError;
+
+    /// Parses [L1MessageType] from its number, like "MT18" or "18".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(parse_id(string)?)
+    }
+}
+
+/// [DfmcMessageType] describes the message types of the dual frequency
+/// multi constellation (DFMC) L5 SBAS service (EUROCAE ED-259).
+/// ```
+/// use gnss_rs::sbas_message::DfmcMessageType;
+/// use std::str::FromStr;
+///
+/// let mt = DfmcMessageType::try_from(32).unwrap();
+/// assert_eq!(mt, DfmcMessageType::ClockEphemerisCorrections);
+/// assert_eq!(mt.to_string(), "MT32");
+///
+/// assert_eq!(DfmcMessageType::from_str("MT31"), Ok(DfmcMessageType::SatelliteMask));
+/// assert_eq!(DfmcMessageType::payload_bits(), 216);
+///
+/// // legacy L1 message type
+/// assert!(DfmcMessageType::try_from(18).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum DfmcMessageType {
+    /// MT0: do not use this SBAS (test mode)
+    DoNotUse,
+
+    /// MT31: satellite mask
+    SatelliteMask,
+
+    /// MT32: clock and ephemeris corrections, with covariance matrix
+    ClockEphemerisCorrections,
+
+    /// MT34: integrity information
+    Integrity34,
+
+    /// MT35: integrity information
+    Integrity35,
+
+    /// MT36: integrity information
+    Integrity36,
+
+    /// MT37: OBAD parameters and DFREI scale table
+    DegradationParameters,
+
+    /// MT39: SBAS satellite ephemeris and covariance matrix (part 1)
+    SbasEphemeris1,
+
+    /// MT40: SBAS satellite ephemeris and covariance matrix (part 2)
+    SbasEphemeris2,
+
+    /// MT42: GNSS time offsets
+    TimeOffsets,
+
+    /// MT47: SBAS satellites almanacs
+    SbasAlmanac,
+
+    /// MT62: internal test message
+    InternalTest,
+
+    /// MT63: null message
+    Null,
+}
+
+impl DfmcMessageType {
+    /// All [DfmcMessageType]s, by increasing message type number.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::DoNotUse,
+        Self::SatelliteMask,
+        Self::ClockEphemerisCorrections,
+        Self::Integrity34,
+        Self::Integrity35,
+        Self::Integrity36,
+        Self::DegradationParameters,
+        Self::SbasEphemeris1,
+        Self::SbasEphemeris2,
+        Self::TimeOffsets,
+        Self::SbasAlmanac,
+        Self::InternalTest,
+        Self::Null,
+    ];
+
+    /// Returns the message type number (0..=63).
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::DoNotUse => 0,
+            Self::SatelliteMask => 31,
+            Self::ClockEphemerisCorrections => 32,
+            Self::Integrity34 => 34,
+            Self::Integrity35 => 35,
+            Self::Integrity36 => 36,
+            Self::DegradationParameters => 37,
+            Self::SbasEphemeris1 => 39,
+            Self::SbasEphemeris2 => 40,
+            Self::TimeOffsets => 42,
+            Self::SbasAlmanac => 47,
+            Self::InternalTest => 62,
+            Self::Null => 63,
+        }
+    }
+
+    /// Builds [DfmcMessageType] from its message type number.
+    /// Returns [None] for reserved (or spare) message types.
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::DoNotUse),
+            31 => Some(Self::SatelliteMask),
+            32 => Some(Self::ClockEphemerisCorrections),
+            34 => Some(Self::Integrity34),
+            35 => Some(Self::Integrity35),
+            36 => Some(Self::Integrity36),
+            37 => Some(Self::DegradationParameters),
+            39 => Some(Self::SbasEphemeris1),
+            40 => Some(Self::SbasEphemeris2),
+            42 => Some(Self::TimeOffsets),
+            47 => Some(Self::SbasAlmanac),
+            62 => Some(Self::InternalTest),
+            63 => Some(Self::Null),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of the data field of any DFMC message, in bits:
+    /// the [SBAS_MESSAGE_BITS] minus the 4 bit preamble, 6 bit message type and 24 bit CRC.
+    pub const fn payload_bits() -> usize {
+        216
+    }
+}
+
+impl TryFrom<u8> for DfmcMessageType {
+    type Error = SbasMessageParsingError;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or(SbasMessageParsingError::Unknown(id))
+    }
+}
+
+impl From<DfmcMessageType> for u8 {
+    fn from(mt: DfmcMessageType) -> u8 {
+        mt.id()
+    }
+}
+
+impl core::fmt::Display for DfmcMessageType {
+    /// Formats this [DfmcMessageType] like "MT32".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "MT{}", self.id())
+    }
+}
+
+impl core::str::FromStr for DfmcMessageType {
+    type Err = SbasMessageParsingError;
+
+    /// Parses [DfmcMessageType] from its number, like "MT32" or "32".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(parse_id(string)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DfmcMessageType, L1MessageType, SbasMessageParsingError, SBAS_MESSAGE_BITS};
+    use std::str::FromStr;
+
+    #[test]
+    fn message_types() {
+        for id in 0..=63 {
+            if let Some(mt) = L1MessageType::from_id(id) {
+                assert_eq!(mt.id(), id);
+                assert_eq!(L1MessageType::from_str(&mt.to_string()), Ok(mt));
+            }
+
+            if let Some(mt) = DfmcMessageType::from_id(id) {
+                assert_eq!(mt.id(), id);
+                assert_eq!(DfmcMessageType::from_str(&mt.to_string()), Ok(mt));
+            }
+        }
+
+        for pair in L1MessageType::VARIANTS.windows(2) {
+            assert!(pair[0].id() < pair[1].id());
+        }
+
+        for pair in DfmcMessageType::VARIANTS.windows(2) {
+            assert!(pair[0].id() < pair[1].id());
+        }
+
+        assert_eq!(
+            (0..=63).filter_map(L1MessageType::from_id).count(),
+            L1MessageType::VARIANTS.len()
+        );
+
+        assert_eq!(
+            (0..=63).filter_map(DfmcMessageType::from_id).count(),
+            DfmcMessageType::VARIANTS.len()
+        );
+
+        assert_eq!(
+            L1MessageType::payload_bits() + 8 + 6 + 24,
+            SBAS_MESSAGE_BITS
+        );
+        assert_eq!(
+            DfmcMessageType::payload_bits() + 4 + 6 + 24,
+            SBAS_MESSAGE_BITS
+        );
+
+        assert_eq!(
+            L1MessageType::from_str(" mt 26"),
+            Ok(L1MessageType::IonosphericDelay)
+        );
+        assert_eq!(
+            L1MessageType::from_str("MTX"),
+            Err(SbasMessageParsingError::InvalidFormat)
+        );
+        assert_eq!(
+            DfmcMessageType::try_from(1),
+            Err(SbasMessageParsingError::Unknown(1))
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::{Constellation, SV};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for SV {
+    /// Generates mostly valid [SV]s (see [SV::is_valid]), and deliberately
+    /// near-invalid ones (PRN 0 or right outside the [Constellation::prn_range]).
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let constellation = Constellation::arbitrary(u)?;
+
+        let prn = match constellation.prn_range() {
+            Some(range) => {
+                if u.ratio(1, 8)? {
+                    let (start, end) = (*range.start(), *range.end());
+                    *u.choose(&[0, start.saturating_sub(1), end.saturating_add(1)])?
+                } else {
+                    u.int_in_range(range)?
+                }
+            },
+            None => u8::arbitrary(u)?,
+        };
+
+        Ok(Self::new(constellation, prn))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::SV;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_sv() {
+        let bytes = (0..4096).map(|i| (i * 7 % 256) as u8).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+
+        let (mut valid, mut invalid) = (0, 0);
+
+        while !u.is_empty() {
+            let sv = SV::arbitrary(&mut u).unwrap();
+            if sv.is_valid() {
+                valid += 1;
+            } else {
+                invalid += 1;
+            }
+        }
+
+        assert!(valid > 0);
+        assert!(invalid > 0);
+    }
+}
@@ -0,0 +1,624 @@
+//! Satellite blocks (generations)
+use crate::{
+    constellation::Constellation,
+    prelude::{Carrier, Signal, TrackingCode},
+};
+use alloc::vec::Vec;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Block (generation) parsing issue
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BlockParsingError {
+    #[error("unknown block")]
+    Unknown,
+}
+
+/// [GpsBlock] describes the generation of [Constellation::GPS](crate::prelude::Constellation::GPS) satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum GpsBlock {
+    /// Block IIA
+    IIA,
+
+    /// Block IIR (Replenishment)
+    IIR,
+
+    /// Block IIR-M (Modernized), first to broadcast L2C
+    IIRM,
+
+    /// Block IIF (Follow-on), first to broadcast L5
+    IIF,
+
+    /// Block III, first to broadcast L1C
+    III,
+
+    /// Block IIIF (Follow-on)
+    IIIF,
+}
+
+impl core::fmt::Display for GpsBlock {
+    /// Formats this [GpsBlock] like "IIR-M".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::IIA => write!(f, "IIA"),
+            Self::IIR => write!(f, "IIR"),
+            Self::IIRM => write!(f, "IIR-M"),
+            Self::IIF => write!(f, "IIF"),
+            Self::III => write!(f, "III"),
+            Self::IIIF => write!(f, "IIIF"),
+        }
+    }
+}
+
+impl core::str::FromStr for GpsBlock {
+    type Err = BlockParsingError;
+
+    /// Parses [GpsBlock] from "IIR-M" like descriptions, with or without "BLOCK" prefix.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        let string = string
+            .strip_prefix("BLOCK")
+            .or_else(|| string.strip_prefix("Block"))
+            .unwrap_or(string)
+            .trim();
+
+        match string {
+            "IIA" => Ok(Self::IIA),
+            "IIR" => Ok(Self::IIR),
+            "IIR-M" | "IIRM" => Ok(Self::IIRM),
+            "IIF" => Ok(Self::IIF),
+            "III" | "IIIA" => Ok(Self::III),
+            "IIIF" => Ok(Self::IIIF),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [GalileoGeneration] describes the generation of [Constellation::Galileo](crate::prelude::Constellation::Galileo) satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum GalileoGeneration {
+    /// In Orbit Validation satellites
+    IOV,
+
+    /// Full Operational Capability satellites
+    FOC,
+
+    /// Second generation satellites
+    G2,
+}
+
+impl core::fmt::Display for GalileoGeneration {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::IOV => write!(f, "IOV"),
+            Self::FOC => write!(f, "FOC"),
+            Self::G2 => write!(f, "G2"),
+        }
+    }
+}
+
+impl core::str::FromStr for GalileoGeneration {
+    type Err = BlockParsingError;
+
+    /// Parses [GalileoGeneration] from "IOV", "FOC" or "G2" descriptions.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "IOV" => Ok(Self::IOV),
+            "FOC" => Ok(Self::FOC),
+            "G2" | "G2G" => Ok(Self::G2),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [GlonassGeneration] describes the generation of [Constellation::Glonass](crate::prelude::Constellation::Glonass) satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum GlonassGeneration {
+    /// Glonass-M (FDMA only, L3 CDMA on latest satellites)
+    M,
+
+    /// Glonass-K1 (FDMA and L3 CDMA)
+    K1,
+
+    /// Glonass-K2 (FDMA, L1, L2 and L3 CDMA)
+    K2,
+}
+
+impl core::fmt::Display for GlonassGeneration {
+    /// Formats this [GlonassGeneration] like "K1".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::M => write!(f, "M"),
+            Self::K1 => write!(f, "K1"),
+            Self::K2 => write!(f, "K2"),
+        }
+    }
+}
+
+impl core::str::FromStr for GlonassGeneration {
+    type Err = BlockParsingError;
+
+    /// Parses [GlonassGeneration] from "M", "K1" or "K2" descriptions,
+    /// with or without "GLONASS-" prefix.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        let string = string
+            .strip_prefix("GLONASS-")
+            .or_else(|| string.strip_prefix("Glonass-"))
+            .unwrap_or(string);
+
+        match string {
+            "M" => Ok(Self::M),
+            "K1" => Ok(Self::K1),
+            "K2" => Ok(Self::K2),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [BeiDouGeneration] describes the generation of [Constellation::BeiDou](crate::prelude::Constellation::BeiDou) satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum BeiDouGeneration {
+    /// BDS-2 (regional system): B1I, B2I and B3I signals
+    BDS2,
+
+    /// BDS-3 (global system): B1I, B3I, B1C and B2a signals
+    BDS3,
+}
+
+impl core::fmt::Display for BeiDouGeneration {
+    /// Formats this [BeiDouGeneration] like "BDS-3".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BDS2 => write!(f, "BDS-2"),
+            Self::BDS3 => write!(f, "BDS-3"),
+        }
+    }
+}
+
+impl core::str::FromStr for BeiDouGeneration {
+    type Err = BlockParsingError;
+
+    /// Parses [BeiDouGeneration] from "BDS-2" or "BDS-3" descriptions.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "BDS-2" | "BDS2" => Ok(Self::BDS2),
+            "BDS-3" | "BDS3" => Ok(Self::BDS3),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [QzssBlock] describes the generation of [Constellation::QZSS](crate::prelude::Constellation::QZSS) satellites,
+/// which defines their signal capability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum QzssBlock {
+    /// Block I (QZS-1): L1 C/A, L1C, L1-SAIF, L2C, L5 and LEX (L6)
+    I,
+
+    /// Block II quasi-zenith (QZS-2, QZS-4, QZS-1R):
+    /// L1 C/A, L1C, L1S, L2C, L5, L5S and L6
+    IIQ,
+
+    /// Block II geostationary (QZS-3): block II signals, plus L1Sb (SBAS)
+    IIG,
+}
+
+impl core::fmt::Display for QzssBlock {
+    /// Formats this [QzssBlock] like "II-Q".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::I => write!(f, "I"),
+            Self::IIQ => write!(f, "II-Q"),
+            Self::IIG => write!(f, "II-G"),
+        }
+    }
+}
+
+impl core::str::FromStr for QzssBlock {
+    type Err = BlockParsingError;
+
+    /// Parses [QzssBlock] from "I", "II-Q" or "II-G" descriptions.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "I" => Ok(Self::I),
+            "II-Q" | "IIQ" => Ok(Self::IIQ),
+            "II-G" | "IIG" => Ok(Self::IIG),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [IrnssGeneration] describes the generation of [Constellation::IRNSS](crate::prelude::Constellation::IRNSS) (NavIC) satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum IrnssGeneration {
+    /// IRNSS-1 series (IRNSS-1A to IRNSS-1I): L5 and S signals
+    IRNSS1,
+
+    /// NVS series (NVS-01 onwards): L5 and S signals. The new L1 SPS signal
+    /// is not part of our signal plan yet.
+    NVS,
+}
+
+impl core::fmt::Display for IrnssGeneration {
+    /// Formats this [IrnssGeneration] like "IRNSS-1".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::IRNSS1 => write!(f, "IRNSS-1"),
+            Self::NVS => write!(f, "NVS"),
+        }
+    }
+}
+
+impl core::str::FromStr for IrnssGeneration {
+    type Err = BlockParsingError;
+
+    /// Parses [IrnssGeneration] from "IRNSS-1" or "NVS" descriptions.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "IRNSS-1" | "IRNSS1" => Ok(Self::IRNSS1),
+            "NVS" => Ok(Self::NVS),
+            _ => Err(BlockParsingError::Unknown),
+        }
+    }
+}
+
+/// [Block] describes the block (generation) of any satellite,
+/// which defines its signal capability, antenna and attitude models.
+/// See [SV::block](crate::prelude::SV::block).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Block {
+    /// [Constellation::GPS] block
+    GPS(GpsBlock),
+
+    /// [Constellation::Glonass] generation
+    Glonass(GlonassGeneration),
+
+    /// [Constellation::Galileo] generation
+    Galileo(GalileoGeneration),
+
+    /// [Constellation::BeiDou] generation
+    BeiDou(BeiDouGeneration),
+
+    /// [Constellation::QZSS] block
+    QZSS(QzssBlock),
+
+    /// [Constellation::IRNSS] generation
+    IRNSS(IrnssGeneration),
+}
+
+impl Block {
+    /// Returns the [Constellation] of this [Block].
+    pub fn constellation(&self) -> Constellation {
+        match self {
+            Self::GPS(_) => Constellation::GPS,
+            Self::Glonass(_) => Constellation::Glonass,
+            Self::Galileo(_) => Constellation::Galileo,
+            Self::BeiDou(_) => Constellation::BeiDou,
+            Self::QZSS(_) => Constellation::QZSS,
+            Self::IRNSS(_) => Constellation::IRNSS,
+        }
+    }
+}
+
+impl Block {
+    /// Returns true if satellites of this [Block] broadcast this [Signal].
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::{Block, GpsBlock};
+    /// use std::str::FromStr;
+    ///
+    /// let l5 = Signal::from_str("G5Q").unwrap();
+    /// assert!(Block::GPS(GpsBlock::IIF).broadcasts(&l5));
+    /// assert!(!Block::GPS(GpsBlock::IIRM).broadcasts(&l5));
+    /// ```
+    pub fn broadcasts(&self, signal: &Signal) -> bool {
+        use Carrier as F;
+        use TrackingCode as T;
+
+        if signal.constellation != self.constellation() {
+            return false;
+        }
+
+        let (carrier, code) = (signal.carrier, signal.code);
+
+        match self {
+            Self::GPS(block) => match (carrier, code) {
+                (F::L1, T::S | T::L | T::X) => *block >= GpsBlock::III,
+                (F::L1 | F::L2, T::M) => *block >= GpsBlock::IIRM,
+                (F::L2, T::C | T::S | T::L | T::X) => *block >= GpsBlock::IIRM,
+                (F::L5, _) => *block >= GpsBlock::IIF,
+                _ => true,
+            },
+            Self::Glonass(generation) => match carrier {
+                F::G3 => *generation >= GlonassGeneration::K1,
+                _ => true,
+            },
+            Self::Galileo(_) => true,
+            Self::BeiDou(generation) => match (generation, carrier, code) {
+                (BeiDouGeneration::BDS2, F::B1I, _) => true,
+                (BeiDouGeneration::BDS2, F::B2b | F::B3, T::I | T::Q | T::X) => true,
+                (BeiDouGeneration::BDS2, _, _) => false,
+                (BeiDouGeneration::BDS3, F::B2b, T::I | T::Q | T::X) => false,
+                (BeiDouGeneration::BDS3, _, _) => true,
+            },
+            Self::QZSS(block) => match (carrier, code) {
+                (F::L1, T::E | T::B) | (F::L5, T::D | T::P | T::Z) => *block != QzssBlock::I,
+                _ => true,
+            },
+            // both generations share the L5 and S signals we describe
+            Self::IRNSS(_) => true,
+        }
+    }
+
+    /// Returns all [Signal]s broadcasted by satellites of this [Block],
+    /// out of their [Constellation::signals] plan.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::{Block, BeiDouGeneration};
+    /// use std::str::FromStr;
+    ///
+    /// let signals = Block::BeiDou(BeiDouGeneration::BDS3).signals();
+    /// assert!(signals.contains(&Signal::from_str("C5P").unwrap()));
+    ///
+    /// let signals = Block::BeiDou(BeiDouGeneration::BDS2).signals();
+    /// assert!(!signals.contains(&Signal::from_str("C5P").unwrap()));
+    /// ```
+    pub fn signals(&self) -> Vec<Signal> {
+        self.constellation()
+            .signals()
+            .into_iter()
+            .filter(|signal| self.broadcasts(signal))
+            .collect()
+    }
+}
+
+impl core::fmt::Display for Block {
+    /// Formats this [Block] along its [Constellation], like "GPS IIR-M" or "Galileo FOC".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::GPS(block) => write!(f, "GPS {}", block),
+            Self::Glonass(generation) => write!(f, "Glonass-{}", generation),
+            Self::Galileo(generation) => write!(f, "Galileo {}", generation),
+            Self::BeiDou(generation) => write!(f, "{}", generation),
+            Self::QZSS(block) => write!(f, "QZSS {}", block),
+            Self::IRNSS(generation) => write!(f, "{}", generation),
+        }
+    }
+}
+
+impl From<GpsBlock> for Block {
+    fn from(block: GpsBlock) -> Self {
+        Self::GPS(block)
+    }
+}
+
+impl From<GlonassGeneration> for Block {
+    fn from(generation: GlonassGeneration) -> Self {
+        Self::Glonass(generation)
+    }
+}
+
+impl From<GalileoGeneration> for Block {
+    fn from(generation: GalileoGeneration) -> Self {
+        Self::Galileo(generation)
+    }
+}
+
+impl From<BeiDouGeneration> for Block {
+    fn from(generation: BeiDouGeneration) -> Self {
+        Self::BeiDou(generation)
+    }
+}
+
+impl From<QzssBlock> for Block {
+    fn from(block: QzssBlock) -> Self {
+        Self::QZSS(block)
+    }
+}
+
+impl From<IrnssGeneration> for Block {
+    fn from(generation: IrnssGeneration) -> Self {
+        Self::IRNSS(generation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        BeiDouGeneration, Block, GalileoGeneration, GlonassGeneration, GpsBlock, IrnssGeneration,
+        QzssBlock,
+    };
+    use crate::prelude::Constellation;
+    use std::str::FromStr;
+
+    #[test]
+    fn gps_block() {
+        for block in [
+            GpsBlock::IIA,
+            GpsBlock::IIR,
+            GpsBlock::IIRM,
+            GpsBlock::IIF,
+            GpsBlock::III,
+            GpsBlock::IIIF,
+        ] {
+            assert_eq!(GpsBlock::from_str(&block.to_string()), Ok(block));
+        }
+
+        assert_eq!(GpsBlock::from_str("BLOCK IIR-M"), Ok(GpsBlock::IIRM));
+        assert_eq!(GpsBlock::from_str("IIIA"), Ok(GpsBlock::III));
+        assert!(GpsBlock::from_str("IV").is_err());
+    }
+
+    #[test]
+    fn galileo_generation() {
+        for generation in [
+            GalileoGeneration::IOV,
+            GalileoGeneration::FOC,
+            GalileoGeneration::G2,
+        ] {
+            assert_eq!(
+                GalileoGeneration::from_str(&generation.to_string()),
+                Ok(generation)
+            );
+        }
+
+        assert!(GalileoGeneration::from_str("IIF").is_err());
+    }
+
+    #[test]
+    fn glonass_generation() {
+        for generation in [
+            GlonassGeneration::M,
+            GlonassGeneration::K1,
+            GlonassGeneration::K2,
+        ] {
+            assert_eq!(
+                GlonassGeneration::from_str(&generation.to_string()),
+                Ok(generation)
+            );
+        }
+
+        assert_eq!(
+            GlonassGeneration::from_str("GLONASS-K1"),
+            Ok(GlonassGeneration::K1)
+        );
+        assert!(GlonassGeneration::from_str("K3").is_err());
+    }
+
+    #[test]
+    fn beidou_generation() {
+        for generation in [BeiDouGeneration::BDS2, BeiDouGeneration::BDS3] {
+            assert_eq!(
+                BeiDouGeneration::from_str(&generation.to_string()),
+                Ok(generation)
+            );
+        }
+
+        assert_eq!(
+            BeiDouGeneration::from_str("BDS3"),
+            Ok(BeiDouGeneration::BDS3)
+        );
+        assert!(BeiDouGeneration::from_str("BDS-4").is_err());
+    }
+
+    #[test]
+    fn qzss_block() {
+        for block in [QzssBlock::I, QzssBlock::IIQ, QzssBlock::IIG] {
+            assert_eq!(QzssBlock::from_str(&block.to_string()), Ok(block));
+        }
+
+        assert!(QzssBlock::from_str("III").is_err());
+    }
+
+    #[test]
+    fn irnss_generation() {
+        for generation in [IrnssGeneration::IRNSS1, IrnssGeneration::NVS] {
+            assert_eq!(
+                IrnssGeneration::from_str(&generation.to_string()),
+                Ok(generation)
+            );
+        }
+
+        assert!(IrnssGeneration::from_str("IRNSS-2").is_err());
+    }
+
+    #[test]
+    fn block() {
+        for (block, constellation, formatted) in [
+            (Block::from(GpsBlock::IIRM), Constellation::GPS, "GPS IIR-M"),
+            (
+                Block::from(GlonassGeneration::K1),
+                Constellation::Glonass,
+                "Glonass-K1",
+            ),
+            (
+                Block::from(GalileoGeneration::FOC),
+                Constellation::Galileo,
+                "Galileo FOC",
+            ),
+            (
+                Block::from(BeiDouGeneration::BDS3),
+                Constellation::BeiDou,
+                "BDS-3",
+            ),
+            (
+                Block::from(QzssBlock::IIG),
+                Constellation::QZSS,
+                "QZSS II-G",
+            ),
+            (
+                Block::from(IrnssGeneration::NVS),
+                Constellation::IRNSS,
+                "NVS",
+            ),
+        ] {
+            assert_eq!(block.constellation(), constellation);
+            assert_eq!(block.to_string(), formatted);
+        }
+    }
+
+    #[test]
+    fn block_signals() {
+        let blocks = [
+            GpsBlock::IIA,
+            GpsBlock::IIR,
+            GpsBlock::IIRM,
+            GpsBlock::IIF,
+            GpsBlock::III,
+            GpsBlock::IIIF,
+        ];
+
+        // each GPS block extends the previous signal set
+        for pair in blocks.windows(2) {
+            let (older, newer) = (Block::from(pair[0]), Block::from(pair[1]));
+            let (older, newer) = (older.signals(), newer.signals());
+            assert!(older.iter().all(|signal| newer.contains(signal)));
+        }
+
+        let iiif = Block::from(GpsBlock::IIIF).signals();
+        assert_eq!(iiif, Constellation::GPS.signals());
+
+        for block in [
+            Block::from(GlonassGeneration::M),
+            Block::from(GalileoGeneration::IOV),
+            Block::from(BeiDouGeneration::BDS2),
+            Block::from(BeiDouGeneration::BDS3),
+            Block::from(QzssBlock::I),
+            Block::from(IrnssGeneration::IRNSS1),
+        ] {
+            let signals = block.signals();
+            assert!(!signals.is_empty(), "{} broadcasts no signal", block);
+
+            for signal in signals {
+                assert_eq!(signal.constellation, block.constellation());
+            }
+        }
+
+        for generation in [IrnssGeneration::IRNSS1, IrnssGeneration::NVS] {
+            assert_eq!(
+                Block::from(generation).signals(),
+                Constellation::IRNSS.signals()
+            );
+        }
+    }
+}
@@ -0,0 +1,1062 @@
+//! Builtin satellite catalog, described in data/catalog
+use crate::{
+    carrier::{Carrier, SPEED_OF_LIGHT_M_S},
+    constellation::Constellation,
+    observable::Signal,
+    sv::{
+        BeiDouGeneration, Block, ClockType, GalileoGeneration, GlonassGeneration, GpsBlock,
+        IrnssGeneration, OrbitClass, QzssBlock, SV, SVN,
+    },
+};
+
+use core::str::FromStr;
+use hifitime::Epoch;
+use std::string::String;
+
+#[cfg(feature = "cospar")]
+use crate::cospar::COSPAR;
+
+// Includes the satellite catalog
+include!(concat!(env!("OUT_DIR"), "/catalog.rs"));
+
+impl PrnAssignment {
+    /// Returns true if this assignment applies at this [Epoch].
+    fn contains(&self, epoch: Epoch) -> bool {
+        epoch >= self.start && self.end.is_none_or(|end| epoch < end)
+    }
+}
+
+impl CatalogEntry {
+    /// Returns the [SVN] of this entry.
+    fn svn(&self) -> SVN {
+        SVN::new(self.constellation, self.svn)
+    }
+
+    /// Returns the PRN number assigned to this entry at this [Epoch].
+    fn prn_at(&self, epoch: Epoch) -> Option<u8> {
+        self.assignment_at(epoch).map(|assignment| assignment.prn)
+    }
+
+    /// Returns true if this entry was declared operational and not decommissioned yet,
+    /// at this [Epoch]. Satellites with no declaration date are operational from launch.
+    fn is_operational(&self, epoch: Epoch) -> bool {
+        let usable = self.operational.unwrap_or(self.launch);
+        epoch >= usable
+            && self
+                .decommission
+                .is_none_or(|decommission| epoch < decommission)
+    }
+
+    /// Returns the [PrnAssignment] of this entry at this [Epoch].
+    fn assignment_at(&self, epoch: Epoch) -> Option<&PrnAssignment> {
+        self.prn
+            .iter()
+            .find(|assignment| assignment.contains(epoch))
+    }
+
+    /// Returns the readable name of this entry, like "GSAT0211" for Galileo satellites.
+    fn name(&self) -> Option<String> {
+        if let Some(name) = self.name {
+            return Some(name.to_string());
+        }
+        match self.constellation {
+            Constellation::Galileo => Some(format!("GSAT{:04}", self.svn)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the [CatalogEntry] describing this [SV] at this [Epoch], if any.
+fn find(sv: &SV, epoch: Epoch) -> Option<&'static CatalogEntry> {
+    CATALOG.iter().find(|entry| {
+        entry.constellation == sv.constellation && entry.prn_at(epoch) == Some(sv.prn)
+    })
+}
+
+/// Returns the [CatalogEntry] currently assigned to this [SV], if any.
+fn find_current(sv: &SV) -> Option<&'static CatalogEntry> {
+    CATALOG.iter().find(|entry| {
+        entry.constellation == sv.constellation
+            && entry
+                .prn
+                .iter()
+                .any(|assignment| assignment.prn == sv.prn && assignment.end.is_none())
+    })
+}
+
+/// Returns the FDMA frequency channel assigned to this Glonass almanac slot (PRN number)
+/// at this [Epoch], using our builtin catalog. This is a fallback for RINEX files
+/// missing the "GLONASS SLOT / FRQ #" header, see [SV::glonass_fdma_channel].
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::glonass_channel;
+/// use std::str::FromStr;
+///
+/// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+/// assert_eq!(glonass_channel(1, t), Some(1));
+/// assert_eq!(glonass_channel(9, t), Some(-2));
+/// assert_eq!(glonass_channel(30, t), None);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn glonass_channel(slot: u8, epoch: Epoch) -> Option<i8> {
+    SV::new(Constellation::Glonass, slot).glonass_fdma_channel(epoch)
+}
+
+/// Returns all Glonass almanac slot (PRN number) to FDMA frequency channel assignments
+/// at this [Epoch], using our builtin catalog, sorted by slot number.
+/// This is the content of the RINEX "GLONASS SLOT / FRQ #" header.
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::glonass_channels;
+/// use std::str::FromStr;
+///
+/// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+/// let channels = glonass_channels(t);
+/// assert_eq!(channels[0], (1, 1));
+/// assert!(channels.contains(&(9, -2)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn glonass_channels(epoch: Epoch) -> Vec<(u8, i8)> {
+    let mut channels = CATALOG
+        .iter()
+        .filter(|entry| entry.constellation == Constellation::Glonass)
+        .filter_map(|entry| {
+            let assignment = entry.assignment_at(epoch)?;
+            Some((assignment.prn, assignment.channel?))
+        })
+        .collect::<Vec<_>>();
+
+    channels.sort_unstable();
+    channels
+}
+
+impl SV {
+    /// Returns the readable name of the satellite currently assigned to this [SV], if known.
+    pub(crate) fn catalog_name(&self) -> Option<String> {
+        find_current(self)?.name()
+    }
+
+    /// Returns the launch datetime of the satellite broadcasting this [SV]
+    /// at this [Epoch], or currently broadcasting it when [None].
+    pub(crate) fn catalog_launch_datetime(&self, epoch: Option<Epoch>) -> Option<Epoch> {
+        let entry = match epoch {
+            Some(epoch) => find(self, epoch)?,
+            None => find_current(self)?,
+        };
+        Some(entry.launch)
+    }
+
+    /// Returns the [SVN] (physical spacecraft) that was broadcasting
+    /// this PRN number at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G04").svn_at(t), Some(SVN::new(Constellation::GPS, 74)));
+    ///
+    /// // PRN numbers are reassigned over time
+    /// let t = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G01").svn_at(t), Some(SVN::new(Constellation::GPS, 49)));
+    ///
+    /// let t = Epoch::from_str("2012-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G01").svn_at(t), Some(SVN::new(Constellation::GPS, 63)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn svn_at(&self, epoch: Epoch) -> Option<SVN> {
+        find(self, epoch).map(|entry| entry.svn())
+    }
+
+    /// Returns the [SV] (PRN number) broadcasted by this [SVN]
+    /// at this [Epoch], using our builtin catalog. This is the reciprocal of [SV::svn_at].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let svn = SVN::from_str("G074").unwrap();
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(SV::from_svn_at(svn, t), Some(sv!("G04")));
+    ///
+    /// // not launched yet
+    /// let t = Epoch::from_str("2015-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(SV::from_svn_at(svn, t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_svn_at(svn: SVN, epoch: Epoch) -> Option<Self> {
+        let entry = CATALOG.iter().find(|entry| entry.svn() == svn)?;
+        let prn = entry.prn_at(epoch)?;
+        Some(Self::new(entry.constellation, prn))
+    }
+
+    /// Returns the NORAD catalog number of the satellite broadcasting this [SV]
+    /// at this [Epoch], using our builtin catalog (or SBAS database). NORAD numbers identify
+    /// satellites in TLE and other ephemeris sources (like Celestrak).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G04").norad_id(t), Some(43873));
+    /// assert_eq!(sv!("S23").norad_id(t), Some(39617)); // ASTRA-5B
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("S23").norad_id(t), None); // not in service yet
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn norad_id(&self, epoch: Epoch) -> Option<u32> {
+        if self.constellation.is_sbas() {
+            return self
+                .sbas_vehicle()
+                .filter(|vehicle| vehicle.is_operational(epoch))?
+                .norad;
+        }
+        find(self, epoch).map(|entry| entry.norad)
+    }
+
+    /// Returns the [SV] currently broadcasted by the satellite identified by
+    /// this NORAD catalog number, using our builtin catalog (or SBAS database). This is the reciprocal
+    /// of [SV::norad_id]. Returns [None] for unknown or retired satellites.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_norad(43873), Some(sv!("G04")));
+    /// assert_eq!(SV::from_norad(28868), Some(sv!("S38"))); // ANIK-F1R
+    /// assert_eq!(SV::from_norad(1), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_norad(id: u32) -> Option<Self> {
+        let entry = match CATALOG.iter().find(|entry| entry.norad == id) {
+            Some(entry) => entry,
+            None => {
+                return super::sbas_vehicles()
+                    .find(|vehicle| vehicle.norad == Some(id) && vehicle.decommission.is_none())
+                    .map(|vehicle| vehicle.sv);
+            },
+        };
+        let assignment = entry
+            .prn
+            .iter()
+            .find(|assignment| assignment.end.is_none())?;
+        Some(Self::new(entry.constellation, assignment.prn))
+    }
+
+    /// Returns the [SV] broadcasted at this [Epoch] by the satellite identified by
+    /// this NORAD catalog number, using our builtin catalog (or SBAS database).
+    /// This is the reciprocal of [SV::norad_id], for past epochs as well.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(SV::from_norad_at(43873, t), Some(sv!("G04")));
+    /// assert_eq!(SV::from_norad_at(39617, t), Some(sv!("S23"))); // ASTRA-5B
+    ///
+    /// // not launched yet
+    /// let t = Epoch::from_str("2015-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(SV::from_norad_at(43873, t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_norad_at(id: u32, epoch: Epoch) -> Option<Self> {
+        let entry = match CATALOG.iter().find(|entry| entry.norad == id) {
+            Some(entry) => entry,
+            None => {
+                return super::sbas_vehicles()
+                    .find(|vehicle| vehicle.norad == Some(id) && vehicle.is_operational(epoch))
+                    .map(|vehicle| vehicle.sv);
+            },
+        };
+        let prn = entry.prn_at(epoch)?;
+        Some(Self::new(entry.constellation, prn))
+    }
+
+    /// Returns the [COSPAR] number (unique launch identification code) of the satellite
+    /// broadcasting this [SV] at this [Epoch], using our builtin catalog (or SBAS database).
+    /// PRN numbers being reused, the same [SV] may describe several launches over time.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let cospar = sv!("G04").cospar_number(t).unwrap();
+    /// assert_eq!(cospar.to_string(), "2018-109A");
+    ///
+    /// let t = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+    /// let cospar = sv!("G01").cospar_number(t).unwrap();
+    /// assert_eq!(cospar.year, 2009);
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// let cospar = sv!("S36").cospar_number(t).unwrap(); // SES-5
+    /// assert_eq!(cospar.to_string(), "2012-036A");
+    /// ```
+    #[cfg(feature = "cospar")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cospar")))]
+    pub fn cospar_number(&self, epoch: Epoch) -> Option<COSPAR> {
+        if self.constellation.is_sbas() {
+            let vehicle = self
+                .sbas_vehicle()
+                .filter(|vehicle| vehicle.is_operational(epoch))?;
+            return COSPAR::from_str(vehicle.cospar?).ok();
+        }
+        let entry = find(self, epoch)?;
+        COSPAR::from_str(entry.cospar).ok()
+    }
+
+    /// Returns the [Block] (generation) of this [SV] at this [Epoch], using our builtin catalog.
+    /// Antenna, attitude (yaw) models and signal sets are usually keyed on this information.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::{Block, GpsBlock, GalileoGeneration};
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G07").block(t), Some(Block::GPS(GpsBlock::IIRM)));
+    /// assert_eq!(sv!("E11").block(t), Some(Block::Galileo(GalileoGeneration::IOV)));
+    /// assert_eq!(sv!("S23").block(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn block(&self, epoch: Epoch) -> Option<Block> {
+        match self.constellation {
+            Constellation::GPS => self.gps_block(epoch).map(Block::from),
+            Constellation::Glonass => self.glonass_generation(epoch).map(Block::from),
+            Constellation::Galileo => self.galileo_generation(epoch).map(Block::from),
+            Constellation::BeiDou => self.beidou_generation(epoch).map(Block::from),
+            Constellation::QZSS => self.qzss_block(epoch).map(Block::from),
+            Constellation::IRNSS => self.irnss_generation(epoch).map(Block::from),
+            _ => None,
+        }
+    }
+
+    /// Returns the [Signal]s actually broadcasted by this satellite at this [Epoch],
+    /// according to its [Block] (see [Block::signals]), using our builtin catalog.
+    /// Augmentation satellites broadcast the [Constellation::SBAS] L1 and/or L5 signals,
+    /// see [SV::sbas_l1_capable] and [SV::sbas_dfmc_capable].
+    /// Returns [None] when this satellite is not described by our databases.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let l5 = Signal::from_str("G5Q").unwrap();
+    ///
+    /// // IIR-M
+    /// assert!(!sv!("G07").signals(t).unwrap().contains(&l5));
+    ///
+    /// // III
+    /// assert!(sv!("G04").signals(t).unwrap().contains(&l5));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn signals(&self, epoch: Epoch) -> Option<Vec<Signal>> {
+        if self.constellation.is_sbas() {
+            Self::sbas_definitions(self.prn)?;
+
+            let signals = self
+                .constellation
+                .signals()
+                .into_iter()
+                .filter(|signal| match signal.carrier {
+                    Carrier::L1 => self.sbas_l1_capable(),
+                    _ => self.sbas_dfmc_capable(),
+                })
+                .collect();
+
+            return Some(signals);
+        }
+        Some(self.block(epoch)?.signals())
+    }
+
+    /// Returns true if this satellite broadcasts this [Signal] at this [Epoch]:
+    /// it is operational (see [SV::is_operational]) and capable of this [Signal]
+    /// (see [SV::signals]). Augmentation satellites are considered operational
+    /// during their service period, if described by our SBAS database.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let l5 = Signal::from_str("G5Q").unwrap();
+    ///
+    /// assert!(sv!("G04").broadcasts(&l5, t));
+    /// assert!(!sv!("G07").broadcasts(&l5, t));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn broadcasts(&self, signal: &Signal, epoch: Epoch) -> bool {
+        let operational = if self.constellation.is_sbas() {
+            super::sbas_constellation(self.prn as u16 + 100) == Some(self.constellation)
+                && self.is_operational(epoch)
+        } else {
+            self.is_operational(epoch)
+        };
+
+        operational
+            && self
+                .signals(epoch)
+                .is_some_and(|signals| signals.contains(signal))
+    }
+
+    /// Returns the [GpsBlock] of this [Constellation::GPS] satellite,
+    /// at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::GpsBlock;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G04").gps_block(t), Some(GpsBlock::III));
+    /// assert_eq!(sv!("G07").gps_block(t), Some(GpsBlock::IIRM));
+    /// assert_eq!(sv!("E04").gps_block(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn gps_block(&self, epoch: Epoch) -> Option<GpsBlock> {
+        if self.constellation != Constellation::GPS {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        GpsBlock::from_str(entry.block).ok()
+    }
+
+    /// Returns the FDMA frequency channel number (-7..=+6) of this [Constellation::Glonass]
+    /// satellite, at this [Epoch], using our builtin catalog. Channels are shared by antipodal
+    /// satellites and may be reassigned over time. The carrier frequencies are then
+    /// G1 = 1602 MHz + k * 562.5 kHz and G2 = 1246 MHz + k * 437.5 kHz.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("R01").glonass_fdma_channel(t), Some(1));
+    /// assert_eq!(sv!("R09").glonass_fdma_channel(t), Some(-2));
+    /// assert_eq!(sv!("G01").glonass_fdma_channel(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn glonass_fdma_channel(&self, epoch: Epoch) -> Option<i8> {
+        if self.constellation != Constellation::Glonass {
+            return None;
+        }
+        find(self, epoch)?.assignment_at(epoch)?.channel
+    }
+
+    /// Returns the frequency (in Hz) of this [Carrier] as broadcasted by this satellite,
+    /// at this [Epoch]. Glonass G1 and G2 frequencies are resolved using
+    /// the FDMA channel of our builtin catalog (see [SV::glonass_fdma_channel]).
+    /// Returns [None] if this satellite does not broadcast this [Carrier],
+    /// or its frequency channel is unknown.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("R01").carrier_frequency_hz(Carrier::G1, t), Some(1602.5625E6));
+    /// assert_eq!(sv!("R01").carrier_frequency_hz(Carrier::G3, t), Some(1202.025E6));
+    /// assert_eq!(sv!("G01").carrier_frequency_hz(Carrier::L1, t), Some(1575.42E6));
+    /// assert_eq!(sv!("G01").carrier_frequency_hz(Carrier::E6, t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn carrier_frequency_hz(&self, carrier: Carrier, epoch: Epoch) -> Option<f64> {
+        if !carrier.is_broadcasted_by(self.constellation) {
+            return None;
+        }
+
+        match carrier {
+            Carrier::G1 | Carrier::G2 => {
+                carrier.glonass_fdma_frequency_hz(self.glonass_fdma_channel(epoch)?)
+            },
+            _ => Some(carrier.frequency_hz()),
+        }
+    }
+
+    /// Returns the wavelength (in meters) of this [Carrier] as broadcasted by this satellite,
+    /// at this [Epoch], see [SV::carrier_frequency_hz].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let lambda = sv!("R09").carrier_wavelength_m(Carrier::G1, t).unwrap();
+    /// assert!((lambda - 0.18727).abs() < 1.0E-5);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn carrier_wavelength_m(&self, carrier: Carrier, epoch: Epoch) -> Option<f64> {
+        self.carrier_frequency_hz(carrier, epoch)
+            .map(|frequency| SPEED_OF_LIGHT_M_S / frequency)
+    }
+
+    /// Returns the [GalileoGeneration] of this [Constellation::Galileo] satellite,
+    /// at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::GalileoGeneration;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("E11").galileo_generation(t), Some(GalileoGeneration::IOV));
+    /// assert_eq!(sv!("E01").galileo_generation(t), Some(GalileoGeneration::FOC));
+    /// assert_eq!(sv!("G01").galileo_generation(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn galileo_generation(&self, epoch: Epoch) -> Option<GalileoGeneration> {
+        if self.constellation != Constellation::Galileo {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        GalileoGeneration::from_str(entry.block).ok()
+    }
+
+    /// Returns the [GlonassGeneration] of this [Constellation::Glonass] satellite,
+    /// at this [Epoch], using our builtin catalog. Note that Glonass PRN numbers
+    /// are orbital slot numbers.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::GlonassGeneration;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("R09").glonass_generation(t), Some(GlonassGeneration::K1));
+    /// assert_eq!(sv!("R01").glonass_generation(t), Some(GlonassGeneration::M));
+    /// assert_eq!(sv!("G01").glonass_generation(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn glonass_generation(&self, epoch: Epoch) -> Option<GlonassGeneration> {
+        if self.constellation != Constellation::Glonass {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        GlonassGeneration::from_str(entry.block).ok()
+    }
+
+    /// Returns the [BeiDouGeneration] of this [Constellation::BeiDou] satellite,
+    /// at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::BeiDouGeneration;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("C06").beidou_generation(t), Some(BeiDouGeneration::BDS2));
+    /// assert_eq!(sv!("C19").beidou_generation(t), Some(BeiDouGeneration::BDS3));
+    /// assert_eq!(sv!("G19").beidou_generation(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn beidou_generation(&self, epoch: Epoch) -> Option<BeiDouGeneration> {
+        if self.constellation != Constellation::BeiDou {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        BeiDouGeneration::from_str(entry.block).ok()
+    }
+
+    /// Returns the [QzssBlock] of this [Constellation::QZSS] satellite,
+    /// at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::QzssBlock;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("J01").qzss_block(t), Some(QzssBlock::I));
+    /// assert_eq!(sv!("J07").qzss_block(t), Some(QzssBlock::IIG));
+    ///
+    /// // QZS-1 has been retired
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("J01").qzss_block(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn qzss_block(&self, epoch: Epoch) -> Option<QzssBlock> {
+        if self.constellation != Constellation::QZSS {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        QzssBlock::from_str(entry.block).ok()
+    }
+
+    /// Returns the [IrnssGeneration] of this [Constellation::IRNSS] (NavIC) satellite,
+    /// at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::IrnssGeneration;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("I02").irnss_generation(t), Some(IrnssGeneration::IRNSS1));
+    /// assert_eq!(sv!("I10").irnss_generation(t), Some(IrnssGeneration::NVS));
+    /// assert_eq!(sv!("I08").irnss_generation(t), None); // IRNSS-1H launch failure
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn irnss_generation(&self, epoch: Epoch) -> Option<IrnssGeneration> {
+        if self.constellation != Constellation::IRNSS {
+            return None;
+        }
+        let entry = find(self, epoch)?;
+        IrnssGeneration::from_str(entry.block).ok()
+    }
+
+    /// Returns true if this [SV] was broadcasted by an operational satellite at this [Epoch],
+    /// using our builtin catalog: the satellite has been launched, declared usable (commissioned)
+    /// and not decommissioned yet. Augmentation satellites follow the service period
+    /// of our SBAS database (see [SbasVehicle::is_operational](super::SbasVehicle::is_operational)).
+    /// Returns false for satellites that are not catalogued.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert!(sv!("G04").is_operational(t));
+    ///
+    /// // C01 was launched in 2010, but declared operational in 2012
+    /// let t = Epoch::from_str("2011-01-01T00:00:00 UTC").unwrap();
+    /// assert!(!sv!("C01").is_operational(t));
+    ///
+    /// let t = Epoch::from_str("2013-01-01T00:00:00 UTC").unwrap();
+    /// assert!(sv!("C01").is_operational(t));
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert!(sv!("S23").is_operational(t));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn is_operational(&self, epoch: Epoch) -> bool {
+        if self.constellation.is_sbas() {
+            return self
+                .sbas_vehicle()
+                .is_some_and(|vehicle| vehicle.is_operational(epoch));
+        }
+        find(self, epoch).is_some_and(|entry| entry.is_operational(epoch))
+    }
+
+    /// Returns the IGS/ANTEX antenna designator of this [SV] at this [Epoch],
+    /// like "BLOCK IIR-M" or "GALILEO-2", using our builtin catalog.
+    /// This is the key to phase center offsets and variations in ANTEX files.
+    /// Returns [None] when the satellite is not catalogued, or has no ANTEX designator (yet).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G07").antenna_type(t), Some("BLOCK IIR-M"));
+    /// assert_eq!(sv!("G04").antenna_type(t), Some("BLOCK IIIA"));
+    /// assert_eq!(sv!("E01").antenna_type(t), Some("GALILEO-2"));
+    /// assert_eq!(sv!("C01").antenna_type(t), Some("BEIDOU-2G"));
+    /// assert_eq!(sv!("C19").antenna_type(t), Some("BEIDOU-3M-CAST"));
+    /// assert_eq!(sv!("C27").antenna_type(t), Some("BEIDOU-3M-SECM"));
+    /// assert_eq!(sv!("S23").antenna_type(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn antenna_type(&self, epoch: Epoch) -> Option<&'static str> {
+        let entry = find(self, epoch)?;
+
+        if let Some(antenna) = entry.antenna {
+            return Some(antenna);
+        }
+
+        let orbit = entry.orbit.unwrap_or(OrbitClass::MEO);
+
+        match self.block(epoch)? {
+            Block::GPS(block) => match block {
+                GpsBlock::IIA => Some("BLOCK IIA"),
+                GpsBlock::IIR => Some("BLOCK IIR-B"),
+                GpsBlock::IIRM => Some("BLOCK IIR-M"),
+                GpsBlock::IIF => Some("BLOCK IIF"),
+                GpsBlock::III => Some("BLOCK IIIA"),
+                GpsBlock::IIIF => None,
+            },
+            Block::Glonass(generation) => match generation {
+                GlonassGeneration::M => Some("GLONASS-M"),
+                GlonassGeneration::K1 => Some("GLONASS-K1"),
+                GlonassGeneration::K2 => None,
+            },
+            Block::Galileo(generation) => match generation {
+                GalileoGeneration::IOV => Some("GALILEO-1"),
+                GalileoGeneration::FOC => Some("GALILEO-2"),
+                GalileoGeneration::G2 => None,
+            },
+            Block::BeiDou(generation) => match (generation, orbit) {
+                (BeiDouGeneration::BDS2, OrbitClass::GEO) => Some("BEIDOU-2G"),
+                (BeiDouGeneration::BDS2, OrbitClass::IGSO) => Some("BEIDOU-2I"),
+                (BeiDouGeneration::BDS2, _) => Some("BEIDOU-2M"),
+                (BeiDouGeneration::BDS3, OrbitClass::GEO) => Some("BEIDOU-3G-CAST"),
+                (BeiDouGeneration::BDS3, OrbitClass::IGSO) => Some("BEIDOU-3I"),
+                (BeiDouGeneration::BDS3, _) => Some("BEIDOU-3M-CAST"),
+            },
+            Block::QZSS(block) => match block {
+                QzssBlock::I => Some("QZSS"),
+                QzssBlock::IIQ => Some("QZSS-2I"),
+                QzssBlock::IIG => Some("QZSS-2G"),
+            },
+            Block::IRNSS(generation) => match (generation, orbit) {
+                (IrnssGeneration::IRNSS1, OrbitClass::GEO) => Some("IRNSS-1GEO"),
+                (IrnssGeneration::IRNSS1, _) => Some("IRNSS-1IGSO"),
+                (IrnssGeneration::NVS, _) => None,
+            },
+        }
+    }
+
+    /// Returns the orbital longitude (in decimal degrees east, within [-180, 180])
+    /// of this geostationary [SV] at this [Epoch]. This applies to augmentation satellites
+    /// (from our SBAS database) and catalogued BeiDou, QZSS and IRNSS GEO satellites.
+    /// Returns [None] for other satellites.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("S23").geo_longitude_deg(t), Some(31.5)); // ASTRA-5B
+    /// assert_eq!(sv!("S38").geo_longitude_deg(t), Some(-107.3)); // ANIK-F1R
+    /// assert_eq!(sv!("C03").geo_longitude_deg(t), Some(110.5));
+    /// assert_eq!(sv!("J07").geo_longitude_deg(t), Some(127.0)); // QZS-3
+    /// assert_eq!(sv!("G01").geo_longitude_deg(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn geo_longitude_deg(&self, epoch: Epoch) -> Option<f64> {
+        if self.constellation.is_sbas() {
+            return self
+                .sbas_vehicle()
+                .filter(|vehicle| vehicle.is_operational(epoch))
+                .map(|vehicle| vehicle.longitude_deg);
+        }
+        find(self, epoch)?.longitude
+    }
+
+    /// Returns the [ClockType] active onboard this [SV] at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::ClockType;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("G04").clock_type(t), Some(ClockType::Rubidium));
+    /// assert_eq!(sv!("E01").clock_type(t), Some(ClockType::PassiveHydrogenMaser));
+    /// assert_eq!(sv!("R01").clock_type(t), Some(ClockType::Cesium));
+    /// assert_eq!(sv!("S23").clock_type(t), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn clock_type(&self, epoch: Epoch) -> Option<ClockType> {
+        let entry = find(self, epoch)?;
+        ClockType::from_str(entry.clock).ok()
+    }
+
+    /// Returns the readable name of this [SV] at this [Epoch], using our builtin catalog,
+    /// like "QZS-2" for J02, or "GSAT0211" for E02.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("J02").catalog_name_at(t), Some("QZS-2".to_string()));
+    /// assert_eq!(sv!("E02").catalog_name_at(t), Some("GSAT0211".to_string()));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn catalog_name_at(&self, epoch: Epoch) -> Option<String> {
+        find(self, epoch)?.name()
+    }
+
+    /// Returns the [OrbitClass] of this [SV] at this [Epoch].
+    /// Unlike [SV::orbit_class], which relies on the current PRN plan,
+    /// this uses our builtin catalog first, and remains correct when PRN numbers
+    /// have been reassigned to satellites on different orbits.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::OrbitClass;
+    /// use std::str::FromStr;
+    ///
+    /// // C13 used to be a MEO satellite
+    /// let t = Epoch::from_str("2014-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("C13").orbit_class_at(t), Some(OrbitClass::MEO));
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("C13").orbit_class_at(t), Some(OrbitClass::IGSO));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn orbit_class_at(&self, epoch: Epoch) -> Option<OrbitClass> {
+        match find(self, epoch) {
+            Some(entry) => Some(entry.orbit.unwrap_or(OrbitClass::MEO)),
+            None => self.orbit_class(),
+        }
+    }
+
+    /// Returns the GSAT number (like "GSAT0211") of this [Constellation::Galileo]
+    /// satellite, at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("E02").galileo_gsat(t), Some("GSAT0211".to_string()));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn galileo_gsat(&self, epoch: Epoch) -> Option<String> {
+        if self.constellation != Constellation::Galileo {
+            return None;
+        }
+        find(self, epoch)?.name()
+    }
+
+    /// Returns the flight model number of this [Constellation::Galileo] satellite,
+    /// within its [GalileoGeneration], at this [Epoch], using our builtin catalog.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert_eq!(sv!("E02").galileo_fm_number(t), Some(11)); // FOC FM11
+    /// assert_eq!(sv!("E12").galileo_fm_number(t), Some(2)); // IOV FM2
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn galileo_fm_number(&self, epoch: Epoch) -> Option<u8> {
+        if self.constellation != Constellation::Galileo {
+            return None;
+        }
+        find(self, epoch)?.fm
+    }
+
+    /// Returns true if this [Constellation::Galileo] satellite is on an eccentric orbit
+    /// at this [Epoch]. This concerns GSAT0201 and GSAT0202 (E18 and E14), which were
+    /// injected into a wrong orbit. Eccentric orbits cause significant periodic relativistic
+    /// clock effects, that must be accounted for.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// assert!(sv!("E14").is_galileo_eccentric(t));
+    /// assert!(sv!("E18").is_galileo_eccentric(t));
+    /// assert!(!sv!("E01").is_galileo_eccentric(t));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn is_galileo_eccentric(&self, epoch: Epoch) -> bool {
+        self.constellation == Constellation::Galileo
+            && find(self, epoch).is_some_and(|entry| entry.eccentric)
+    }
+}
+
+impl Constellation {
+    /// Returns all operational [SV]s of this [Constellation] at this [Epoch],
+    /// by increasing PRN number, using our builtin catalog (see [SV::is_operational]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let qzss = Constellation::QZSS.operational_svs(t);
+    /// assert_eq!(qzss, vec![sv!("J01"), sv!("J02"), sv!("J03"), sv!("J07")]);
+    ///
+    /// assert!(Constellation::Galileo.operational_svs(t).contains(&sv!("E11")));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn operational_svs(&self, epoch: Epoch) -> Vec<SV> {
+        self.sv_iter()
+            .filter(|sv| sv.is_operational(epoch))
+            .collect()
+    }
+
+    /// Returns all satellites of this [Constellation] broadcasting this [Signal]
+    /// at this [Epoch], see [SV::broadcasts].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2020-01-01T00:00:00 UTC").unwrap();
+    /// let l5 = Signal::from_str("G5Q").unwrap();
+    ///
+    /// let svs = Constellation::GPS.svs_broadcasting(&l5, t);
+    /// assert!(svs.contains(&sv!("G04")));
+    /// assert!(!svs.contains(&sv!("G07")));
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    /// let egnos = Signal::from_str("S1C").unwrap();
+    /// assert!(Constellation::EGNOS.svs_broadcasting(&egnos, t).contains(&sv!("S23")));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn svs_broadcasting(&self, signal: &Signal, epoch: Epoch) -> Vec<SV> {
+        self.sv_iter()
+            .filter(|sv| sv.broadcasts(signal, epoch))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{glonass_channels, CATALOG};
+    use crate::prelude::{Constellation, Epoch, SV};
+    use crate::sv::OrbitClass;
+
+    #[test]
+    fn catalog_consistency() {
+        for (i, entry) in CATALOG.iter().enumerate() {
+            assert!(
+                !entry.prn.is_empty(),
+                "{} has no PRN assignment",
+                entry.svn()
+            );
+
+            for assignment in entry.prn.iter() {
+                let sv = SV::new(entry.constellation, assignment.prn);
+                assert!(sv.is_valid(), "invalid PRN assignment for {}", entry.svn());
+
+                if let Some(end) = assignment.end {
+                    assert!(end > assignment.start, "invalid period for {}", entry.svn());
+                }
+
+                let block = sv.block(assignment.start).unwrap_or_else(|| {
+                    panic!("invalid block for {}", entry.svn());
+                });
+                assert_eq!(block.constellation(), entry.constellation);
+
+                if let Some(operational) = entry.operational {
+                    assert!(
+                        operational >= entry.launch,
+                        "invalid dates for {}",
+                        entry.svn()
+                    );
+                }
+
+                if entry.constellation != Constellation::IRNSS {
+                    assert!(
+                        sv.antenna_type(assignment.start).is_some(),
+                        "missing antenna for {}",
+                        entry.svn()
+                    );
+                }
+
+                assert!(
+                    sv.clock_type(assignment.start).is_some(),
+                    "invalid clock for {}",
+                    entry.svn()
+                );
+
+                assert_eq!(
+                    entry.longitude.is_some(),
+                    entry.orbit == Some(OrbitClass::GEO),
+                    "invalid longitude for {}",
+                    entry.svn()
+                );
+
+                // current PRN plan
+                if assignment.end.is_none() {
+                    assert_eq!(
+                        sv.orbit_class_at(assignment.start),
+                        sv.orbit_class(),
+                        "inconsistent orbit for {}",
+                        entry.svn()
+                    );
+                }
+
+                if matches!(
+                    entry.constellation,
+                    Constellation::QZSS | Constellation::IRNSS
+                ) {
+                    assert!(entry.name.is_some(), "missing name for {}", entry.svn());
+                }
+
+                if entry.constellation == Constellation::Glonass {
+                    let channel = assignment.channel.unwrap_or_else(|| {
+                        panic!("missing FDMA channel for {}", entry.svn());
+                    });
+                    assert!(
+                        (-7..=6).contains(&channel),
+                        "invalid channel for {}",
+                        entry.svn()
+                    );
+                } else {
+                    assert!(
+                        assignment.channel.is_none(),
+                        "invalid channel for {}",
+                        entry.svn()
+                    );
+                }
+
+                if entry.constellation == Constellation::Galileo {
+                    assert!(entry.fm.is_some(), "missing FM number for {}", entry.svn());
+                }
+
+                // test reciprocal at the start of each period
+                assert_eq!(SV::from_svn_at(entry.svn(), assignment.start), Some(sv));
+                assert_eq!(sv.svn_at(assignment.start), Some(entry.svn()));
+            }
+
+            #[cfg(feature = "cospar")]
+            {
+                let cospar = entry
+                    .cospar
+                    .parse::<crate::cospar::COSPAR>()
+                    .unwrap_or_else(|_| panic!("invalid COSPAR for {}", entry.svn()));
+                assert_eq!(
+                    cospar.year as i32,
+                    entry.launch.to_gregorian_utc().0,
+                    "inconsistent COSPAR for {}",
+                    entry.svn()
+                );
+            }
+
+            // SVN and NORAD numbers are unique
+            for other in CATALOG.iter().skip(i + 1) {
+                assert_ne!(entry.svn(), other.svn(), "duplicate {}", entry.svn());
+                assert_ne!(entry.norad, other.norad, "duplicate NORAD {}", entry.norad);
+
+                // PRN periods never overlap
+                if entry.constellation == other.constellation {
+                    for a in entry.prn.iter() {
+                        for b in other.prn.iter().filter(|b| b.prn == a.prn) {
+                            assert!(
+                                !a.contains(b.start) && !b.contains(a.start),
+                                "{} and {} overlap",
+                                entry.svn(),
+                                other.svn()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let t = Epoch::from_gregorian_utc_at_midnight(2000, 1, 1);
+        assert_eq!(SV::new(Constellation::GPS, 4).svn_at(t), None);
+    }
+
+    #[test]
+    fn glonass_slot_channels() {
+        for year in [2010, 2015, 2020] {
+            let epoch = Epoch::from_gregorian_utc_at_midnight(year, 1, 1);
+            let channels = glonass_channels(epoch);
+
+            assert!(!channels.is_empty());
+
+            for (i, (slot, channel)) in channels.iter().enumerate() {
+                // one channel per slot
+                assert!(channels[i + 1..].iter().all(|(other, _)| other != slot));
+
+                // channels are only shared by antipodal satellites
+                let sharing = channels.iter().filter(|(_, c)| c == channel).count();
+                assert!(sharing <= 2, "channel {} used {} times", channel, sharing);
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Onboard clocks
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// Clock type parsing issue
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ClockTypeParsingError {
+    #[error("unknown clock type")]
+    Unknown,
+}
+
+/// [ClockType] describes the atomic frequency standard onboard a satellite.
+/// Clock stability drives the quality of broadcast and precise clock products.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum ClockType {
+    /// Rubidium clock
+    Rubidium,
+
+    /// Cesium beam clock
+    Cesium,
+
+    /// Passive Hydrogen Maser
+    PassiveHydrogenMaser,
+
+    /// Rubidium Atomic Frequency Standard
+    RAFS,
+}
+
+impl core::fmt::Display for ClockType {
+    /// Formats this [ClockType] using its usual abbreviation, like "PHM".
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Rubidium => write!(f, "Rb"),
+            Self::Cesium => write!(f, "Cs"),
+            Self::PassiveHydrogenMaser => write!(f, "PHM"),
+            Self::RAFS => write!(f, "RAFS"),
+        }
+    }
+}
+
+impl core::str::FromStr for ClockType {
+    type Err = ClockTypeParsingError;
+
+    /// Parses [ClockType] from its abbreviation ("Rb", "Cs", "PHM", "RAFS")
+    /// or full name, case insensitive.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim().to_lowercase();
+        match string.as_str() {
+            "rb" | "rubidium" => Ok(Self::Rubidium),
+            "cs" | "cesium" | "caesium" => Ok(Self::Cesium),
+            "phm" | "passive hydrogen maser" => Ok(Self::PassiveHydrogenMaser),
+            "rafs" => Ok(Self::RAFS),
+            _ => Err(ClockTypeParsingError::Unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ClockType;
+    use std::str::FromStr;
+
+    #[test]
+    fn clock_type() {
+        for (content, expected) in [
+            ("Rb", ClockType::Rubidium),
+            ("Cs", ClockType::Cesium),
+            ("PHM", ClockType::PassiveHydrogenMaser),
+            ("RAFS", ClockType::RAFS),
+        ] {
+            let clock = ClockType::from_str(content).unwrap();
+            assert_eq!(clock, expected);
+            assert_eq!(clock.to_string(), content);
+        }
+
+        assert_eq!(ClockType::from_str("rubidium"), Ok(ClockType::Rubidium));
+        assert!(ClockType::from_str("quartz").is_err());
+    }
+}
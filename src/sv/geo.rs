@@ -0,0 +1,182 @@
+//! Geostationary satellites geometry
+use crate::prelude::{Epoch, SV};
+
+/// Geostationary orbit radius, in meters
+pub(crate) const GEO_RADIUS_M: f64 = 42_164_170.0;
+
+/// WGS84 semi major axis, in meters
+pub(crate) const WGS84_A_M: f64 = 6_378_137.0;
+
+/// WGS84 first eccentricity, squared
+const WGS84_E2: f64 = 6.694_379_990_14e-3;
+
+/// Returns the (elevation, azimuth) angles (in degrees) under which an observer
+/// sees a geostationary satellite located at this orbital longitude (in decimal degrees east),
+/// using simple geostationary geometry (no orbital perturbation).
+/// Observer coordinates are geodetic (WGS84): latitude and longitude in decimal degrees,
+/// altitude in meters. Azimuth is counted clockwise from north, within [0, 360[.
+/// Negative elevations mean the satellite is below the horizon.
+/// ```
+/// use gnss_rs::sv::geo_elevation_azimuth_deg;
+///
+/// // satellite at the zenith
+/// let (elevation, _) = geo_elevation_azimuth_deg(31.5, 0.0, 31.5, 0.0);
+/// assert!((elevation - 90.0).abs() < 1.0E-6);
+///
+/// // ASTRA-5B (31.5°E) seen from Paris
+/// let (elevation, azimuth) = geo_elevation_azimuth_deg(31.5, 48.8, 2.38, 0.0);
+/// assert!(elevation > 5.0);
+/// assert!(azimuth > 90.0 && azimuth < 180.0); // looking south east
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn geo_elevation_azimuth_deg(
+    sat_longitude_deg: f64,
+    latitude_deg: f64,
+    longitude_deg: f64,
+    altitude_m: f64,
+) -> (f64, f64) {
+    let (lat, lon) = (latitude_deg.to_radians(), longitude_deg.to_radians());
+    let sat_lon = sat_longitude_deg.to_radians();
+
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let (sin_lon, cos_lon) = lon.sin_cos();
+
+    // observer ECEF coordinates
+    let n = WGS84_A_M / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+    let x = (n + altitude_m) * cos_lat * cos_lon;
+    let y = (n + altitude_m) * cos_lat * sin_lon;
+    let z = (n * (1.0 - WGS84_E2) + altitude_m) * sin_lat;
+
+    // line of sight
+    let dx = GEO_RADIUS_M * sat_lon.cos() - x;
+    let dy = GEO_RADIUS_M * sat_lon.sin() - y;
+    let dz = -z;
+
+    // local ENU frame
+    let east = -sin_lon * dx + cos_lon * dy;
+    let north = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+    let up = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+
+    let elevation = up.atan2((east * east + north * north).sqrt()).to_degrees();
+    let azimuth = east.atan2(north).to_degrees().rem_euclid(360.0);
+
+    (elevation, azimuth)
+}
+
+/// Converts ECEF (WGS84) coordinates (in meters) to geodetic
+/// (latitude, longitude) coordinates, in decimal degrees (Bowring's method).
+pub(crate) fn ecef_to_geodetic_deg(x_m: f64, y_m: f64, z_m: f64) -> (f64, f64) {
+    let b_m = WGS84_A_M * (1.0 - WGS84_E2).sqrt();
+    let ep2 = WGS84_E2 / (1.0 - WGS84_E2);
+    let p_m = x_m.hypot(y_m);
+
+    let theta = (z_m * WGS84_A_M).atan2(p_m * b_m);
+    let (sin_theta, cos_theta) = theta.sin_cos();
+
+    let latitude =
+        (z_m + ep2 * b_m * sin_theta.powi(3)).atan2(p_m - WGS84_E2 * WGS84_A_M * cos_theta.powi(3));
+
+    (latitude.to_degrees(), y_m.atan2(x_m).to_degrees())
+}
+
+impl SV {
+    /// Returns the (elevation, azimuth) angles (in degrees) under which an observer
+    /// sees this geostationary [SV] at this [Epoch], see [geo_elevation_azimuth_deg].
+    /// Observer coordinates are geodetic (WGS84): latitude and longitude in decimal degrees,
+    /// altitude in meters. Returns [None] when [SV::geo_longitude_deg] is unknown.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+    ///
+    /// // is this EGNOS GEO above 5° in Paris?
+    /// let (elevation, _) = sv!("S23").geo_elevation_azimuth_deg(t, 48.8, 2.38, 0.0).unwrap();
+    /// assert!(elevation > 5.0);
+    ///
+    /// // not visible from Hawaii
+    /// let (elevation, _) = sv!("S23").geo_elevation_azimuth_deg(t, 19.8, -155.5, 0.0).unwrap();
+    /// assert!(elevation < 0.0);
+    ///
+    /// assert!(sv!("G01").geo_elevation_azimuth_deg(t, 48.8, 2.38, 0.0).is_none());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn geo_elevation_azimuth_deg(
+        &self,
+        epoch: Epoch,
+        latitude_deg: f64,
+        longitude_deg: f64,
+        altitude_m: f64,
+    ) -> Option<(f64, f64)> {
+        let sat_longitude_deg = self.geo_longitude_deg(epoch)?;
+        Some(geo_elevation_azimuth_deg(
+            sat_longitude_deg,
+            latitude_deg,
+            longitude_deg,
+            altitude_m,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ecef_to_geodetic_deg, geo_elevation_azimuth_deg, WGS84_A_M, WGS84_E2};
+
+    #[test]
+    fn geo_geometry() {
+        // zenith
+        let (elevation, _) = geo_elevation_azimuth_deg(100.0, 0.0, 100.0, 0.0);
+        assert!((elevation - 90.0).abs() < 1.0E-6);
+
+        // same meridian: due south (northern hemisphere) or due north (southern hemisphere)
+        let (elevation, azimuth) = geo_elevation_azimuth_deg(0.0, 45.0, 0.0, 0.0);
+        assert!((azimuth - 180.0).abs() < 1.0E-6);
+        assert!((elevation - 38.2).abs() < 0.1);
+
+        let (_, azimuth) = geo_elevation_azimuth_deg(0.0, -45.0, 0.0, 0.0);
+        assert!(azimuth.abs() < 1.0E-6);
+
+        // east / west
+        let (_, azimuth) = geo_elevation_azimuth_deg(10.0, 0.0, 0.0, 0.0);
+        assert!((azimuth - 90.0).abs() < 1.0E-6);
+
+        let (_, azimuth) = geo_elevation_azimuth_deg(-10.0, 0.0, 0.0, 0.0);
+        assert!((azimuth - 270.0).abs() < 1.0E-6);
+
+        // beyond the horizon
+        let (elevation, _) = geo_elevation_azimuth_deg(0.0, 0.0, 90.0, 0.0);
+        assert!(elevation < 0.0);
+    }
+
+    #[test]
+    fn ecef_geodetic() {
+        for (latitude_deg, longitude_deg, altitude_m) in [
+            (0.0_f64, 0.0_f64, 0.0),
+            (48.8, 2.38, 100.0),
+            (-33.9, 151.2, 50.0),
+            (89.9, -120.0, 3000.0),
+            (-45.0, -179.0, 0.0),
+        ] {
+            let (lat, lon) = (latitude_deg.to_radians(), longitude_deg.to_radians());
+            let n = WGS84_A_M / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+            let x = (n + altitude_m) * lat.cos() * lon.cos();
+            let y = (n + altitude_m) * lat.cos() * lon.sin();
+            let z = (n * (1.0 - WGS84_E2) + altitude_m) * lat.sin();
+
+            let (lat, lon) = ecef_to_geodetic_deg(x, y, z);
+            assert!(
+                (lat - latitude_deg).abs() < 1.0E-6,
+                "{} {}",
+                lat,
+                latitude_deg
+            );
+            assert!(
+                (lon - longitude_deg).abs() < 1.0E-6,
+                "{} {}",
+                lon,
+                longitude_deg
+            );
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Satellites grouping
+use crate::{
+    prelude::{Constellation, SV},
+    sv::ordering::igs_rank,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+impl SV {
+    /// Groups these [SV]s by [Constellation]. Groups are returned in the canonical
+    /// RINEX/IGS order (G, R, E, C, J, I then S, see [SV::igs_cmp]), each group is
+    /// sorted by PRN number and does not contain duplicates, so it may be fed
+    /// with all satellites of an observation session.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let svs = [sv!("E01"), sv!("G12"), sv!("R03"), sv!("G02"), sv!("G12")];
+    /// let groups = SV::group_by_constellation(svs);
+    ///
+    /// assert_eq!(
+    ///     groups,
+    ///     vec![
+    ///         (Constellation::GPS, vec![sv!("G02"), sv!("G12")]),
+    ///         (Constellation::Glonass, vec![sv!("R03")]),
+    ///         (Constellation::Galileo, vec![sv!("E01")]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn group_by_constellation<I: IntoIterator<Item = Self>>(
+        svs: I,
+    ) -> Vec<(Constellation, Vec<Self>)> {
+        let mut groups = BTreeMap::<Constellation, Vec<Self>>::new();
+
+        for sv in svs {
+            groups.entry(sv.constellation).or_default().push(sv);
+        }
+
+        for group in groups.values_mut() {
+            group.sort_unstable_by_key(|sv| sv.prn);
+            group.dedup();
+        }
+
+        let mut groups = groups.into_iter().collect::<Vec<_>>();
+        groups.sort_by_key(|(constellation, _)| igs_rank(*constellation));
+        groups
+    }
+
+    /// Counts unique [SV]s per [Constellation], in the same order as
+    /// [SV::group_by_constellation].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let svs = [sv!("G12"), sv!("E01"), sv!("G02"), sv!("G12")];
+    /// let counts = SV::count_by_constellation(svs);
+    ///
+    /// assert_eq!(counts, vec![(Constellation::GPS, 2), (Constellation::Galileo, 1)]);
+    /// ```
+    pub fn count_by_constellation<I: IntoIterator<Item = Self>>(
+        svs: I,
+    ) -> Vec<(Constellation, usize)> {
+        Self::group_by_constellation(svs)
+            .into_iter()
+            .map(|(constellation, group)| (constellation, group.len()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+
+    #[test]
+    fn grouping() {
+        let mut svs = Constellation::VARIANTS
+            .iter()
+            .flat_map(|constellation| constellation.sv_iter())
+            .collect::<Vec<_>>();
+
+        svs.reverse();
+
+        let groups = SV::group_by_constellation(svs.iter().copied().chain(svs.iter().copied()));
+
+        for (constellation, group) in groups.iter() {
+            assert_eq!(
+                group,
+                &constellation.sv_iter().collect::<Vec<_>>(),
+                "invalid {} group",
+                constellation
+            );
+        }
+
+        let order = groups
+            .iter()
+            .map(|(constellation, _)| *constellation)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            &order[..6],
+            &[
+                Constellation::GPS,
+                Constellation::Glonass,
+                Constellation::Galileo,
+                Constellation::BeiDou,
+                Constellation::QZSS,
+                Constellation::IRNSS,
+            ]
+        );
+
+        // augmentation systems come last, as they share the 'S' identifier
+        assert!(order[6..]
+            .iter()
+            .all(|constellation| constellation.is_sbas()));
+
+        let counts = SV::count_by_constellation(svs);
+        assert_eq!(counts.len(), groups.len());
+        assert_eq!(counts[0], (Constellation::GPS, 32));
+        assert_eq!(counts[2], (Constellation::Galileo, 36));
+    }
+}
@@ -0,0 +1,149 @@
+//! Compact (dense) satellite index
+use crate::prelude::{Constellation, SV};
+
+/// Satellite index groups, in RTKLIB order:
+/// (constellation, first PRN, number of satellites).
+/// All augmentation systems share the same group.
+pub(crate) const INDEX_GROUPS: [(Constellation, u8, usize); 7] = [
+    (Constellation::GPS, 1, 32),
+    (Constellation::Glonass, 1, 27),
+    (Constellation::Galileo, 1, 36),
+    (Constellation::QZSS, 1, 10),
+    (Constellation::BeiDou, 1, 63),
+    (Constellation::IRNSS, 1, 14),
+    (Constellation::SBAS, 20, 39),
+];
+
+impl SV {
+    /// Total number of satellites that may be indexed, see [SV::to_index].
+    pub const INDEX_COUNT: usize = 221;
+
+    /// Returns the index group and the offset of this [SV] within it.
+    pub(crate) fn index_group(&self) -> Option<(usize, usize)> {
+        let group = if self.constellation.is_sbas() {
+            INDEX_GROUPS.len() - 1
+        } else {
+            INDEX_GROUPS
+                .iter()
+                .position(|(constellation, _, _)| *constellation == self.constellation)?
+        };
+
+        let (_, first_prn, count) = INDEX_GROUPS[group];
+        let offset = self.prn.checked_sub(first_prn)? as usize;
+
+        if offset < count {
+            Some((group, offset))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a dense index, uniquely identifying this [SV] in 0..[SV::INDEX_COUNT].
+    /// This follows the RTKLIB satellite numbering: GPS (1-32), Glonass (1-27),
+    /// Galileo (1-36), QZSS (1-10), BeiDou (1-63), IRNSS (1-14) and finally SBAS (120-158).
+    /// This numbering is guaranteed not to change within a major version,
+    /// so it may be used to index flat arrays.
+    /// Returns [None] for invalid satellites (see [SV::is_valid]).
+    /// All augmentation systems share the SBAS range.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G01").to_index(), Some(0));
+    /// assert_eq!(sv!("R01").to_index(), Some(32));
+    /// assert_eq!(sv!("G33").to_index(), None);
+    ///
+    /// let index = sv!("E11").to_index().unwrap();
+    /// assert_eq!(SV::from_index(index), Some(sv!("E11")));
+    /// ```
+    pub fn to_index(&self) -> Option<usize> {
+        let (group, offset) = self.index_group()?;
+
+        let start = INDEX_GROUPS[..group]
+            .iter()
+            .map(|(_, _, count)| count)
+            .sum::<usize>();
+
+        Some(start + offset)
+    }
+
+    /// Builds the [SV] identified by this dense index, see [SV::to_index].
+    /// Returns [None] if index is out of 0..[SV::INDEX_COUNT].
+    ///
+    /// When built with std library, SBAS vehicles are identified
+    /// using our builtin database (like [SV::from_str]).
+    pub fn from_index(index: usize) -> Option<Self> {
+        let mut start = 0;
+
+        for (constellation, first_prn, count) in INDEX_GROUPS.iter() {
+            if index < start + count {
+                let prn = first_prn + (index - start) as u8;
+                return Some(Self::new_identified(*constellation, prn));
+            }
+            start += count;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::INDEX_GROUPS;
+    use crate::prelude::{Constellation, SV};
+    use std::str::FromStr;
+
+    #[test]
+    fn index_count() {
+        assert_eq!(
+            INDEX_GROUPS
+                .iter()
+                .map(|(_, _, count)| count)
+                .sum::<usize>(),
+            SV::INDEX_COUNT
+        );
+
+        for (constellation, first_prn, count) in INDEX_GROUPS {
+            let range = constellation.prn_range().unwrap();
+            assert_eq!(*range.start(), first_prn);
+            assert_eq!(*range.end() as usize, first_prn as usize + count - 1);
+        }
+    }
+
+    #[test]
+    fn reciprocal() {
+        for index in 0..SV::INDEX_COUNT {
+            let sv = SV::from_index(index).unwrap();
+            assert!(sv.is_valid(), "invalid satellite {:?}", sv);
+            assert_eq!(sv.to_index(), Some(index), "reciprocal failed for {:?}", sv);
+        }
+
+        assert_eq!(SV::from_index(SV::INDEX_COUNT), None);
+    }
+
+    #[test]
+    fn indexing() {
+        for (desc, index) in [
+            ("G01", Some(0)),
+            ("G32", Some(31)),
+            ("R01", Some(32)),
+            ("E01", Some(59)),
+            ("J01", Some(95)),
+            ("C01", Some(105)),
+            ("I01", Some(168)),
+            ("S20", Some(182)),
+            ("S58", Some(220)),
+            ("G33", None),
+            ("S03", None),
+        ] {
+            let sv = SV::from_str(desc).unwrap();
+            assert_eq!(sv.to_index(), index, "invalid index for {}", desc);
+        }
+
+        // all augmentation systems share the same range
+        assert_eq!(SV::new(Constellation::EGNOS, 23).to_index(), Some(185));
+        assert_eq!(SV::new(Constellation::SBAS, 23).to_index(), Some(185));
+        assert_eq!(SV::new(Constellation::Mixed, 1).to_index(), None);
+    }
+}
@@ -0,0 +1,275 @@
+//! Dense satellite map
+use crate::prelude::SV;
+
+/// [SvMap] maps each [SV] to a value, backed by a flat array indexed
+/// by [SV::to_index]. It does not allocate and is both faster
+/// and more cache friendly than a `HashMap<SV, T>`, which makes it suitable
+/// to store per-satellite states (ambiguities, biases..) in positioning filters.
+///
+/// Only valid satellites may be stored (see [SV::is_valid]).
+/// All augmentation systems share the same PRN range, so SBAS vehicles
+/// are identified by PRN only.
+/// ```
+/// use gnss_rs::sv;
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::SvMap;
+/// use std::str::FromStr;
+///
+/// let mut map = SvMap::<f64>::new();
+/// assert_eq!(map.insert(sv!("G01"), 1.0), None);
+/// assert_eq!(map.insert(sv!("G01"), 2.0), Some(1.0));
+///
+/// assert_eq!(map.get(&sv!("G01")), Some(&2.0));
+/// assert_eq!(map.get(&sv!("E01")), None);
+///
+/// *map.entry(sv!("E01")).unwrap().or_insert(0.0) += 10.0;
+/// assert_eq!(map.get(&sv!("E01")), Some(&10.0));
+///
+/// let content = map.iter()
+///     .map(|(sv, value)| (sv, *value))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(content, vec![(sv!("G01"), 2.0), (sv!("E01"), 10.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvMap<T> {
+    values: [Option<T>; SV::INDEX_COUNT],
+}
+
+impl<T> Default for SvMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SvMap<T> {
+    /// Builds a new empty [SvMap].
+    pub fn new() -> Self {
+        Self {
+            values: core::array::from_fn(|_| None),
+        }
+    }
+
+    /// Inserts a value for this [SV], returning the previous value, if any.
+    /// Invalid satellites (see [SV::is_valid]) are never inserted
+    /// and the value is dropped.
+    pub fn insert(&mut self, sv: SV, value: T) -> Option<T> {
+        let index = sv.to_index()?;
+        self.values[index].replace(value)
+    }
+
+    /// Removes the value of this [SV], returning it, if any.
+    pub fn remove(&mut self, sv: &SV) -> Option<T> {
+        let index = sv.to_index()?;
+        self.values[index].take()
+    }
+
+    /// Returns a reference to the value of this [SV], if any.
+    pub fn get(&self, sv: &SV) -> Option<&T> {
+        let index = sv.to_index()?;
+        self.values[index].as_ref()
+    }
+
+    /// Returns a mutable reference to the value of this [SV], if any.
+    pub fn get_mut(&mut self, sv: &SV) -> Option<&mut T> {
+        let index = sv.to_index()?;
+        self.values[index].as_mut()
+    }
+
+    /// Returns true if a value exists for this [SV].
+    pub fn contains_key(&self, sv: &SV) -> bool {
+        self.get(sv).is_some()
+    }
+
+    /// Returns the entry of this [SV], for in-place manipulation.
+    /// Returns [None] for invalid satellites (see [SV::is_valid]).
+    pub fn entry(&mut self, sv: SV) -> Option<SvMapEntry<'_, T>> {
+        let index = sv.to_index()?;
+        Some(SvMapEntry {
+            sv,
+            slot: &mut self.values[index],
+        })
+    }
+
+    /// Returns the number of satellites in the map.
+    pub fn len(&self) -> usize {
+        self.values.iter().filter(|value| value.is_some()).count()
+    }
+
+    /// Returns true if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(|value| value.is_none())
+    }
+
+    /// Removes all values from the map.
+    pub fn clear(&mut self) {
+        for value in self.values.iter_mut() {
+            *value = None;
+        }
+    }
+
+    /// Retains only the satellites for which the predicate returns true.
+    pub fn retain<F: FnMut(SV, &mut T) -> bool>(&mut self, mut f: F) {
+        for (index, value) in self.values.iter_mut().enumerate() {
+            if let Some(inner) = value {
+                let sv = SV::from_index(index).unwrap();
+                if !f(sv, inner) {
+                    *value = None;
+                }
+            }
+        }
+    }
+
+    /// Iterates over (satellite, value) pairs, in [SV::to_index] order.
+    pub fn iter(&self) -> impl Iterator<Item = (SV, &T)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| Some((SV::from_index(index)?, value.as_ref()?)))
+    }
+
+    /// Iterates over (satellite, mutable value) pairs, in [SV::to_index] order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SV, &mut T)> + '_ {
+        self.values
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, value)| Some((SV::from_index(index)?, value.as_mut()?)))
+    }
+
+    /// Iterates over the satellites of the map, in [SV::to_index] order.
+    pub fn keys(&self) -> impl Iterator<Item = SV> + '_ {
+        self.iter().map(|(sv, _)| sv)
+    }
+
+    /// Iterates over the values of the map, in [SV::to_index] order.
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.values.iter().filter_map(|value| value.as_ref())
+    }
+}
+
+impl<T> FromIterator<(SV, T)> for SvMap<T> {
+    fn from_iter<I: IntoIterator<Item = (SV, T)>>(iter: I) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl<T> Extend<(SV, T)> for SvMap<T> {
+    fn extend<I: IntoIterator<Item = (SV, T)>>(&mut self, iter: I) {
+        for (sv, value) in iter {
+            self.insert(sv, value);
+        }
+    }
+}
+
+/// Single entry of an [SvMap], see [SvMap::entry].
+pub struct SvMapEntry<'a, T> {
+    sv: SV,
+    slot: &'a mut Option<T>,
+}
+
+impl<'a, T> SvMapEntry<'a, T> {
+    /// Returns the [SV] of this entry.
+    pub fn key(&self) -> SV {
+        self.sv
+    }
+
+    /// Returns true if a value exists for this entry.
+    pub fn is_occupied(&self) -> bool {
+        self.slot.is_some()
+    }
+
+    /// Inserts this value if the entry is vacant,
+    /// and returns a mutable reference to the value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.slot.get_or_insert(default)
+    }
+
+    /// Inserts the result of this function if the entry is vacant,
+    /// and returns a mutable reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        self.slot.get_or_insert_with(f)
+    }
+
+    /// Modifies the value in place if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Self {
+        if let Some(value) = self.slot.as_mut() {
+            f(value);
+        }
+        self
+    }
+}
+
+impl<'a, T: Default> SvMapEntry<'a, T> {
+    /// Inserts the default value if the entry is vacant,
+    /// and returns a mutable reference to the value.
+    pub fn or_default(self) -> &'a mut T {
+        self.slot.get_or_insert_with(T::default)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SvMap;
+    use crate::prelude::{Constellation, SV};
+    use std::str::FromStr;
+
+    #[test]
+    fn insertion() {
+        let mut map = SvMap::<usize>::new();
+        assert!(map.is_empty());
+
+        for index in 0..SV::INDEX_COUNT {
+            let sv = SV::from_index(index).unwrap();
+            assert_eq!(map.insert(sv, index), None);
+            assert_eq!(map.get(&sv), Some(&index));
+        }
+
+        assert_eq!(map.len(), SV::INDEX_COUNT);
+
+        // invalid satellites
+        assert_eq!(map.insert(SV::new(Constellation::GPS, 33), 0), None);
+        assert!(map.entry(SV::new(Constellation::Mixed, 1)).is_none());
+        assert_eq!(map.len(), SV::INDEX_COUNT);
+
+        for (sv, value) in map.iter_mut() {
+            assert_eq!(sv.to_index(), Some(*value));
+            *value += 1;
+        }
+
+        map.retain(|sv, _| sv.constellation == Constellation::GPS);
+        assert_eq!(map.len(), 32);
+
+        for (index, value) in map.values().enumerate() {
+            assert_eq!(*value, index + 1);
+        }
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn entries() {
+        let mut map = SvMap::<u32>::new();
+        let g01 = SV::from_str("G01").unwrap();
+
+        let entry = map.entry(g01).unwrap();
+        assert_eq!(entry.key(), g01);
+        assert!(!entry.is_occupied());
+
+        *map.entry(g01).unwrap().or_default() += 1;
+        map.entry(g01).unwrap().and_modify(|value| *value *= 10);
+        assert_eq!(map.get(&g01), Some(&10));
+
+        assert_eq!(*map.entry(g01).unwrap().or_insert_with(|| 100), 10);
+        assert_eq!(map.remove(&g01), Some(10));
+        assert!(!map.contains_key(&g01));
+
+        let map = [(g01, 1), (SV::from_str("R01").unwrap(), 2)]
+            .into_iter()
+            .collect::<SvMap<u32>>();
+
+        assert_eq!(map.keys().count(), 2);
+    }
+}
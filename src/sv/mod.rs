@@ -1,17 +1,22 @@
 //! Space vehicle definition
+use alloc::vec::Vec;
+use core::str::FromStr;
 use hifitime::TimeScale;
 use thiserror::Error;
 
-use crate::constellation::{Constellation, ParsingError as ConstellationParsingError};
+use crate::{
+    ascii,
+    constellation::{Constellation, ParsingError as ConstellationParsingError},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "std")]
-use hifitime::{Duration, Epoch};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 
 #[cfg(feature = "std")]
-use std::str::FromStr;
+use hifitime::{Duration, Epoch};
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -19,14 +24,64 @@ use pyo3::prelude::*;
 #[cfg(feature = "python")]
 mod python;
 
-// #[cfg(feature = "cospar")]
-// use crate::prelude::COSPAR;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "std")]
+mod catalog;
+
+#[cfg(feature = "std")]
+pub(crate) mod geo;
+
+#[cfg(feature = "std")]
+mod sbas;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod string;
+
+mod block;
+mod clock;
+mod group;
+mod index;
+mod map;
+mod nmea;
+mod orbit;
+mod ordering;
+mod rtcm;
+mod set;
+mod sp3;
+mod svn;
+mod ublox;
+
+pub use block::{
+    BeiDouGeneration, Block, BlockParsingError, GalileoGeneration, GlonassGeneration, GpsBlock,
+    IrnssGeneration, QzssBlock,
+};
+pub use clock::{ClockType, ClockTypeParsingError};
+pub use map::{SvMap, SvMapEntry};
+pub use orbit::OrbitClass;
+pub use set::{SvSet, SvSetIter};
+pub use svn::SVN;
+
+#[cfg(feature = "std")]
+pub use catalog::{glonass_channel, glonass_channels};
+
+#[cfg(feature = "std")]
+pub use geo::geo_elevation_azimuth_deg;
+
+#[cfg(feature = "std")]
+pub use sbas::{
+    sbas_provider_vehicles, sbas_provider_vehicles_active, sbas_vehicles, sbas_vehicles_active,
+    SbasVehicle,
+};
 
 /// ̀SV describes a Satellite Vehicle
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "python", pyo3(module = "gnss"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SV {
     /// PRN identification number for this vehicle
     pub prn: u8,
@@ -47,6 +102,15 @@ pub enum ParsingError {
 
     #[error("failed to parse PRN numer")]
     PrnParsing,
+
+    #[error("invalid PRN number for this constellation")]
+    InvalidPrn,
+
+    #[error("invalid SP3 satellite identifier")]
+    InvalidSp3Format,
+
+    #[error("non GNSS satellite '{0}'")]
+    NonGnssSatellite(char),
 }
 
 impl SV {
@@ -65,15 +129,121 @@ impl SV {
     ///
     /// assert_eq!(sv.constellation, Constellation::GPS);
     /// assert_eq!(sv.prn, 1);
-    /// assert_eq!(sv.launch_datetime(), None); // only for SBAS vehicles
+    /// assert!(sv.launch_datetime().is_some()); // builtin catalog
     /// ```
     pub const fn new(constellation: Constellation, prn: u8) -> Self {
         Self { prn, constellation }
     }
 
+    /// Builds desired Satellite Vehicle ([SV]), only if the PRN number
+    /// is valid for this [Constellation] (see [Constellation::prn_range]).
+    /// ```
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::ParsingError;
+    ///
+    /// assert!(SV::try_new(Constellation::GPS, 1).is_ok());
+    /// assert_eq!(SV::try_new(Constellation::GPS, 200), Err(ParsingError::InvalidPrn));
+    /// assert_eq!(SV::try_new(Constellation::Mixed, 1), Err(ParsingError::InvalidPrn));
+    /// ```
+    pub fn try_new(constellation: Constellation, prn: u8) -> Result<Self, ParsingError> {
+        let sv = Self::new(constellation, prn);
+
+        if sv.is_valid() {
+            Ok(sv)
+        } else {
+            Err(ParsingError::InvalidPrn)
+        }
+    }
+
+    /// Returns true if this [SV] has a valid PRN number for its [Constellation]
+    /// (see [Constellation::prn_range]). Note that neither [SV::new] nor
+    /// [SV::from_str] verify this, prefer [SV::try_new] to build valid satellites.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(sv!("G01").is_valid());
+    /// assert!(sv!("E36").is_valid());
+    /// assert!(!sv!("G33").is_valid());
+    /// assert!(!sv!("C254").is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.constellation
+            .prn_range()
+            .is_some_and(|range| range.contains(&self.prn))
+    }
+
+    /// Parses a [SV] from a description where the [Constellation] may be omitted,
+    /// like the PRN lists of RINEX V2 records (" 7", "07"), in which case
+    /// this [Constellation] is implied. Complete "CNN" descriptions are parsed
+    /// like [SV::from_str], regardless of this [Constellation].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_str_with_constellation(Constellation::GPS, " 7"), Ok(sv!("G07")));
+    /// assert_eq!(SV::from_str_with_constellation(Constellation::GPS, "07"), Ok(sv!("G07")));
+    /// assert_eq!(SV::from_str_with_constellation(Constellation::GPS, "R07"), Ok(sv!("R07")));
+    /// assert!(SV::from_str_with_constellation(Constellation::GPS, "  ").is_err());
+    /// ```
+    pub fn from_str_with_constellation(
+        constellation: Constellation,
+        content: &str,
+    ) -> Result<Self, ParsingError> {
+        let content = content.trim();
+
+        if content.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Self::from_str(content);
+        }
+
+        let prn = content
+            .parse::<u8>()
+            .map_err(|_| ParsingError::PrnParsing)?;
+
+        Ok(Self::new_identified(constellation, prn))
+    }
+
+    /// Parses a list of [SV]s described in 3 character wide fields, like the
+    /// satellite lists of RINEX V2 observation records ("G01G02  7 12R03"),
+    /// where this [Constellation] is implied when omitted.
+    /// Blank fields are ignored. See [SV::from_str_with_constellation].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let svs = SV::from_str_list_with_constellation(Constellation::GPS, "G01  7 12R03").unwrap();
+    /// assert_eq!(svs, vec![sv!("G01"), sv!("G07"), sv!("G12"), sv!("R03")]);
+    /// ```
+    pub fn from_str_list_with_constellation(
+        constellation: Constellation,
+        content: &str,
+    ) -> Result<Vec<Self>, ParsingError> {
+        if !content.is_ascii() {
+            return Err(ParsingError::PrnParsing);
+        }
+
+        content
+            .as_bytes()
+            .chunks(3)
+            .filter_map(|field| {
+                // ASCII content is valid UTF-8 on any boundary
+                let field = core::str::from_utf8(field).ok()?;
+                if field.trim().is_empty() {
+                    None
+                } else {
+                    Some(Self::from_str_with_constellation(constellation, field))
+                }
+            })
+            .collect()
+    }
+
     /// Tries to identify this [Constellation::SBAS] satellite from
     /// a PRN number ranging from 0..100 (RINEX like format).
-    /// Simply substract 100 to the true satellite ID number.
+    /// Simply substract 100 to the true satellite ID number,
+    /// or use [SV::from_sbas_absolute_prn] to work with true satellite ID numbers.
     ///
     /// ```
     /// use gnss_rs::sv;
@@ -110,6 +280,151 @@ impl SV {
         }
     }
 
+    /// Const (compile time) equivalent of [SV::from_str](core::str::FromStr),
+    /// which returns [None] on parsing failure. This is what the [sv!](crate::sv!) macro relies on.
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database.
+    /// ```
+    /// use gnss_rs::prelude::*;
+    ///
+    /// const G01: Option<SV> = SV::from_str_const("G01");
+    /// assert_eq!(G01, Some(SV::new(Constellation::GPS, 1)));
+    ///
+    /// assert_eq!(SV::from_str_const("E 5"), Some(SV::new(Constellation::Galileo, 5)));
+    /// assert_eq!(SV::from_str_const("X01"), None);
+    /// assert_eq!(SV::from_str_const("G1x"), None);
+    /// ```
+    pub const fn from_str_const(string: &str) -> Option<Self> {
+        let bytes = string.as_bytes();
+
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let (letter, prn) = bytes.split_at(1);
+
+        let constellation = match core::str::from_utf8(letter) {
+            Ok(letter) => match Constellation::from_str_const(letter) {
+                Some(constellation) => constellation,
+                None => return None,
+            },
+            Err(_) => return None,
+        };
+
+        let prn = match ascii::parse_u8(prn.trim_ascii()) {
+            Some(prn) => prn,
+            None => return None,
+        };
+
+        #[cfg(feature = "std")]
+        if constellation.is_sbas() {
+            if let Some(constellation) = sbas_constellation(prn as u16 + 100) {
+                return Some(Self::new(constellation, prn));
+            }
+        }
+
+        Some(Self::new(constellation, prn))
+    }
+
+    /// Builds a [Constellation::SBAS] satellite from its absolute PRN number (120..=158),
+    /// as found in raw SBAS frames, while RINEX uses the (PRN - 100) convention.
+    /// When built with std library, the satellite is identified using our builtin database
+    /// (like [SV::new_sbas]), otherwise it is described as generic [Constellation::SBAS].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let egnos_geo23 = SV::from_sbas_absolute_prn(123).unwrap();
+    /// assert_eq!(egnos_geo23, sv!("S23"));
+    /// assert_eq!(egnos_geo23.constellation, Constellation::EGNOS);
+    ///
+    /// assert_eq!(SV::from_sbas_absolute_prn(23), None);
+    /// ```
+    pub fn from_sbas_absolute_prn(prn: u8) -> Option<Self> {
+        let sv = Self::new(Constellation::SBAS, prn.checked_sub(100)?);
+        if sv.is_valid() {
+            Some(Self::new_identified(sv.constellation, sv.prn))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the absolute PRN number (120..=158) of this augmentation satellite,
+    /// which is the reciprocal of [SV::from_sbas_absolute_prn].
+    /// Returns [None] for other constellations.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("S23").sbas_absolute_prn(), Some(123));
+    /// assert_eq!(sv!("G23").sbas_absolute_prn(), None);
+    /// ```
+    pub fn sbas_absolute_prn(&self) -> Option<u8> {
+        if self.constellation.is_sbas() && self.is_valid() {
+            Some(self.prn + 100)
+        } else {
+            None
+        }
+    }
+
+    /// Builds a [SV], identifying augmentation satellites using our builtin database
+    /// when possible (like [SV::from_str]).
+    #[cfg(feature = "std")]
+    pub(crate) fn new_identified(constellation: Constellation, prn: u8) -> Self {
+        if constellation.is_sbas() {
+            if let Some(sv) = Self::new_sbas(prn) {
+                return sv;
+            }
+        }
+        Self::new(constellation, prn)
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn new_identified(constellation: Constellation, prn: u8) -> Self {
+        Self::new(constellation, prn)
+    }
+
+    /// Builds a [Constellation::QZSS] satellite from its absolute PRN number (193..=202),
+    /// as used in NMEA and almanacs, while RINEX uses the (PRN - 192) convention.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_qzss_prn(193), Some(sv!("J01")));
+    /// assert_eq!(SV::from_qzss_prn(199), Some(sv!("J07")));
+    /// assert_eq!(SV::from_qzss_prn(7), None);
+    /// ```
+    pub fn from_qzss_prn(prn: u8) -> Option<Self> {
+        let sv = Self::new(Constellation::QZSS, prn.checked_sub(192)?);
+        if sv.is_valid() {
+            Some(sv)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the absolute PRN number (193..=202) of this [Constellation::QZSS] satellite,
+    /// which is the reciprocal of [SV::from_qzss_prn].
+    /// Returns [None] for other constellations.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("J01").qzss_absolute_prn(), Some(193));
+    /// assert_eq!(sv!("G01").qzss_absolute_prn(), None);
+    /// ```
+    pub fn qzss_absolute_prn(&self) -> Option<u8> {
+        if self.constellation == Constellation::QZSS && self.is_valid() {
+            Some(self.prn + 192)
+        } else {
+            None
+        }
+    }
+
     /// Returns [Timescale] to which [Self] belongs to.
     /// ```
     /// extern crate gnss_rs as gnss;
@@ -138,44 +453,94 @@ impl SV {
             .reduce(|e, _| e)
     }
 
+    /// Returns true if this augmentation satellite broadcasts the legacy L1 service,
+    /// according to our SBAS database.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(sv!("S23").sbas_l1_capable());
+    /// assert!(!sv!("G01").sbas_l1_capable());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_l1_capable(&self) -> bool {
+        self.constellation.is_sbas()
+            && Self::sbas_definitions(self.prn).is_some_and(|definition| definition.l1)
+    }
+
+    /// Returns true if this augmentation satellite broadcasts the dual frequency
+    /// multi constellation (DFMC) L5 service, according to our SBAS database.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(sv!("S23").sbas_dfmc_capable()); // ASTRA-5B
+    /// assert!(!sv!("S25").sbas_dfmc_capable()); // Luch-5A
+    /// assert!(!sv!("G01").sbas_dfmc_capable());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_dfmc_capable(&self) -> bool {
+        self.constellation.is_sbas()
+            && Self::sbas_definitions(self.prn).is_some_and(|definition| definition.dfmc)
+    }
+
     /// Returns launch date and time expressed as UTC [Epoch].  
-    /// This API is limited to [Constellation::SBAS] vehicles for which we have a builtin database.
+    /// This API is limited to satellites described by our builtin databases.
+    /// PRN numbers being reused, this describes the satellite currently broadcasting this PRN,
+    /// prefer [SV::duration_since_launch] for past epochs.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let launch = sv!("G04").launch_datetime().unwrap();
+    /// assert_eq!(launch, Epoch::from_str("2018-12-23T00:00:00 UTC").unwrap());
+    ///
+    /// let launch = sv!("E11").launch_datetime().unwrap();
+    /// assert_eq!(launch, Epoch::from_str("2011-10-21T00:00:00 UTC").unwrap());
+    /// ```
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn launch_datetime(&self) -> Option<Epoch> {
+        if !self.constellation.is_sbas() {
+            return self.catalog_launch_datetime(None);
+        }
+
         let definition = SV::sbas_definitions(self.prn)?;
 
-        if let Ok(epoch) = Epoch::from_str(definition.launch) {
-            // failures will not happen here,
-            // all entires are tested in CI/CD
-            Some(epoch)
-        } else {
-            None
-        }
+        // failures will not happen here,
+        // all entires are tested in CI/CD
+        Epoch::from_str(definition.launch).ok()
     }
 
-    // /// Returns the [COSPAR] number (unique launch identification code)
-    // /// for this satellite, if known. This API is limited to [Constellation::SBAS] vehicles
-    // /// for which we have a builtin database.
-    // #[cfg(feature = "cospar")]
-    // #[cfg_attr(docsrs, doc(cfg(feature = "cospar")))]
-    // pub fn cospar_number(&self) -> Option<COSPAR> {
-    //     let definitions = SV::sbas_definitions(self.prn)?;
-    //     let launch_datetime = self.launch_datetime()?;
-    //     Some(COSPAR::new(
-    //         launch_datetime.year(),
-    //         definitions.cospar_number,
-    //         definitions.cospar_code,
-    //     ))
-    // }
-
     /// Returns the space flight [Duration] at this particular point in time
-    /// expressed as [Epoch], for this [SV]. This is limited to [Constellation::SBAS]
-    /// vehicles for which we have a builtin database.
+    /// expressed as [Epoch], for this [SV]. This is limited to satellites
+    /// described by our builtin databases. PRN numbers being reused, this applies
+    /// to the satellite that was broadcasting this PRN at that time.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// use hifitime::Unit;
+    ///
+    /// // SVN49 was launched in 2009 and broadcasted PRN 1
+    /// let now = Epoch::from_str("2010-03-24T00:00:00 UTC").unwrap();
+    /// let duration = sv!("G01").duration_since_launch(now).unwrap();
+    /// assert_eq!(duration, 365.0 * Unit::Day);
+    /// ```
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn duration_since_launch(&self, now: Epoch) -> Option<Duration> {
-        let datetime = self.launch_datetime()?;
+        let datetime = if self.constellation.is_sbas() {
+            self.launch_datetime()?
+        } else {
+            self.catalog_launch_datetime(Some(now))?
+        };
         Some(now - datetime)
     }
 
@@ -196,7 +561,7 @@ impl core::str::FromStr for SV {
     /// is more detailed for SBAS vehicles, because
     /// we have a database builtin. For example, S23 is EutelSAT 5WB.
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let constellation = Constellation::from_str(&string[0..1])?;
+        let constellation = Constellation::from_str(string.get(0..1).unwrap_or_default())?;
 
         if let Ok(prn) = string[1..].trim().parse::<u8>() {
             let mut ret = SV::new(constellation, prn);
@@ -227,7 +592,7 @@ impl core::str::FromStr for SV {
     /// and S23 can only be interpreted as SBAS-23, because the SBAS
     /// database is not builtin without std library.
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let constellation = Constellation::from_str(&string[0..1])?;
+        let constellation = Constellation::from_str(string.get(0..1).unwrap_or_default())?;
         if let Ok(prn) = string[1..].trim().parse::<u8>() {
             Ok(SV::new(constellation, prn))
         } else {
@@ -239,20 +604,38 @@ impl core::str::FromStr for SV {
 #[cfg(not(feature = "std"))]
 impl core::fmt::Display for SV {
     /// Formats this [SV] with possible details (if known in our database).
+    /// The alternate flag (`{:#}`) formats a readable description, like "GPS PRN 14".
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{:x}", self)
+        if f.alternate() {
+            self.fmt_description(f)
+        } else {
+            write!(f, "{:x}", self)
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl core::fmt::Display for SV {
     /// Formats this [SV] with possible details (if known in our database).
+    /// The alternate flag (`{:#}`) formats a readable description, like "GPS PRN 14",
+    /// or "EGNOS ASTRA-5B (S23)", "Galileo GSAT0211 (E02)" and "QZSS QZS-2 (J02)" for vehicles known to our databases
+    /// (current PRN assignment).
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if self.constellation.is_sbas() {
             if let Some(sbas) = SV::sbas_definitions(self.prn) {
-                write!(f, "{}", sbas.name)
+                if f.alternate() {
+                    return write!(f, "{:#} {} ({:x})", self.constellation, sbas.name, self);
+                } else {
+                    return write!(f, "{}", sbas.name);
+                }
+            }
+        }
+
+        if f.alternate() {
+            if let Some(name) = self.catalog_name() {
+                write!(f, "{:#} {} ({:x})", self.constellation, name, self)
             } else {
-                write!(f, "{:x}", self)
+                self.fmt_description(f)
             }
         } else {
             write!(f, "{:x}", self)
@@ -260,12 +643,40 @@ impl core::fmt::Display for SV {
     }
 }
 
+impl SV {
+    /// Formats a readable description of this [SV], like "GPS PRN 14".
+    /// Augmentation satellites are described by their absolute PRN number (like "SBAS PRN 123").
+    fn fmt_description(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let prn = self.sbas_absolute_prn().unwrap_or(self.prn);
+        write!(f, "{:#} PRN {}", self.constellation, prn)
+    }
+}
+
 impl core::fmt::LowerHex for SV {
     /// Formats this [SV] in CNN format, where:
     /// - C is a single letter [Constellation] identifier
     /// - NN is a two-digit PRN number
+    ///
+    /// Use the sign flag (`{:+x}`) to obtain blank padded PRN numbers,
+    /// as expected by RINEX V2 files (like "G 1").
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(format!("{:x}", sv!("G01")), "G01");
+    /// assert_eq!(format!("{:+x}", sv!("G01")), "G 1");
+    /// assert_eq!(format!("{:+x}", sv!("E14")), "E14");
+    ///
+    /// // reciprocal
+    /// assert_eq!(SV::from_str("G 1").unwrap(), sv!("G01"));
+    /// ```
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{:x}{:02}", self.constellation, self.prn)
+        if f.sign_plus() {
+            write!(f, "{:x}{:2}", self.constellation, self.prn)
+        } else {
+            write!(f, "{:x}{:02}", self.constellation, self.prn)
+        }
     }
 }
 
@@ -309,7 +720,7 @@ mod test {
 
     #[test]
     fn sbas_from_str() {
-        for (desc, parsed, displayed, lowerhex) in vec![
+        for (desc, parsed, displayed, lowerhex) in [
             ("S 3", SV::new(Constellation::SBAS, 3), "S03", "S03"),
             (
                 "S22",
@@ -346,17 +757,199 @@ mod test {
                 "corrupt launch datetime: \"{}\"",
                 sbas.launch
             );
+
+            assert!(
+                (-180.0..=180.0).contains(&sbas.longitude),
+                "invalid longitude for \"{}\"",
+                sbas.name
+            );
+
+            assert!(
+                sbas.l1 || sbas.dfmc,
+                "\"{}\" does not broadcast any service",
+                sbas.name
+            );
         }
     }
+    #[test]
+    fn test_is_valid() {
+        for (sv, valid) in [
+            (SV::new(Constellation::GPS, 0), false),
+            (SV::new(Constellation::GPS, 1), true),
+            (SV::new(Constellation::GPS, 32), true),
+            (SV::new(Constellation::GPS, 33), false),
+            (SV::new(Constellation::Glonass, 24), true),
+            (SV::new(Constellation::Galileo, 36), true),
+            (SV::new(Constellation::Galileo, 37), false),
+            (SV::new(Constellation::BeiDou, 63), true),
+            (SV::new(Constellation::BeiDou, 254), false),
+            (SV::new(Constellation::QZSS, 7), true),
+            (SV::new(Constellation::QZSS, 193), false),
+            (SV::new(Constellation::IRNSS, 14), true),
+            (SV::new(Constellation::SBAS, 3), false),
+            (SV::new(Constellation::EGNOS, 23), true),
+            (SV::new(Constellation::Mixed, 1), false),
+        ] {
+            assert_eq!(sv.is_valid(), valid, "invalid results for {:?}", sv);
+            assert_eq!(
+                SV::try_new(sv.constellation, sv.prn).is_ok(),
+                valid,
+                "invalid results for {:?}",
+                sv
+            );
+        }
+    }
+
+    #[test]
+    fn const_parsing() {
+        for constellation in Constellation::VARIANTS {
+            for prn in 0..=60 {
+                let sv = SV::new(*constellation, prn);
+                for desc in [format!("{:x}", sv), format!("{:+x}", sv), sv.to_string()] {
+                    assert_eq!(
+                        SV::from_str_const(&desc),
+                        SV::from_str(&desc).ok(),
+                        "const parsing differs for \"{}\"",
+                        desc
+                    );
+                }
+            }
+        }
+
+        for desc in ["G", "X01", "G1x", "G256", "g01", "s23", "S 23"] {
+            assert_eq!(
+                SV::from_str_const(desc),
+                SV::from_str(desc).ok(),
+                "const parsing differs for \"{}\"",
+                desc
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_with_constellation() {
+        for (content, expected) in [
+            (" 7", "G07"),
+            ("07", "G07"),
+            ("7", "G07"),
+            ("G07", "G07"),
+            ("E07", "E07"),
+            (" E07 ", "E07"),
+        ] {
+            let sv = SV::from_str_with_constellation(Constellation::GPS, content).unwrap();
+            assert_eq!(sv, SV::from_str(expected).unwrap());
+        }
+
+        for content in ["", "   ", "G", "-1", "7G"] {
+            assert!(SV::from_str_with_constellation(Constellation::GPS, content).is_err());
+        }
+
+        let svs = SV::from_str_list_with_constellation(Constellation::Galileo, "  1E02G03  4   ")
+            .unwrap();
+        assert_eq!(
+            svs,
+            vec![
+                SV::new(Constellation::Galileo, 1),
+                SV::new(Constellation::Galileo, 2),
+                SV::new(Constellation::GPS, 3),
+                SV::new(Constellation::Galileo, 4),
+            ]
+        );
+
+        assert!(SV::from_str_list_with_constellation(Constellation::GPS, "G01 x ").is_err());
+    }
+
+    #[test]
+    fn alternate_formatting() {
+        for (sv, expected) in [
+            (SV::new(Constellation::GPS, 14), "GPS PRN 14"),
+            (SV::new(Constellation::Galileo, 29), "Galileo PRN 29"),
+            (SV::new(Constellation::BeiDou, 30), "BeiDou PRN 30"),
+            (SV::new(Constellation::SBAS, 50), "SBAS PRN 150"),
+        ] {
+            assert_eq!(format!("{:#}", sv), expected);
+        }
+
+        let egnos = SV::from_str("S23").unwrap();
+        assert_eq!(format!("{:#}", egnos), "EGNOS ASTRA-5B (S23)");
+
+        let e02 = SV::from_str("E02").unwrap();
+        assert_eq!(format!("{:#}", e02), "Galileo GSAT0211 (E02)");
+
+        let j02 = SV::from_str("J02").unwrap();
+        assert_eq!(format!("{:#}", j02), "QZSS QZS-2 (J02)");
+    }
+
+    #[test]
+    fn rinex2_formatting() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                let formatted = format!("{:+x}", sv);
+                assert_eq!(formatted.len(), 3);
+
+                if sv.prn < 10 {
+                    assert_eq!(formatted.chars().nth(1), Some(' '));
+                } else {
+                    assert_eq!(formatted, format!("{:x}", sv));
+                }
+
+                let parsed = SV::from_str(&formatted).unwrap();
+                assert_eq!(parsed.prn, sv.prn);
+            }
+        }
+    }
+
+    #[test]
+    fn sbas_prn() {
+        for sv in Constellation::SBAS.sv_iter() {
+            let prn = sv.sbas_absolute_prn().unwrap();
+            assert!((120..=158).contains(&prn));
+
+            let identified = SV::from_sbas_absolute_prn(prn).unwrap();
+            assert_eq!(identified.prn, sv.prn);
+            assert!(identified.constellation.is_sbas());
+            assert_eq!(identified.sbas_absolute_prn(), Some(prn));
+        }
+
+        assert_eq!(SV::from_sbas_absolute_prn(119), None);
+        assert_eq!(SV::from_sbas_absolute_prn(159), None);
+        assert_eq!(
+            SV::from_sbas_absolute_prn(148),
+            Some(SV::new(Constellation::ASAL, 48))
+        );
+    }
+
+    #[test]
+    fn qzss_prn() {
+        for sv in Constellation::QZSS.sv_iter() {
+            let prn = sv.qzss_absolute_prn().unwrap();
+            assert!((193..=202).contains(&prn));
+            assert_eq!(SV::from_qzss_prn(prn), Some(sv));
+        }
+
+        assert_eq!(SV::from_qzss_prn(192), None);
+        assert_eq!(SV::from_qzss_prn(203), None);
+        assert_eq!(SV::new(Constellation::QZSS, 11).qzss_absolute_prn(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema() {
+        let schema = schemars::schema_for!(SV);
+        let properties = schema.get("properties").unwrap();
+        assert!(properties.get("prn").is_some());
+        assert!(properties.get("constellation").is_some());
+    }
+
     #[test]
     fn test_beidou_geo() {
-        assert_eq!(SV::from_str("G01").unwrap().is_beidou_geo(), false);
-        assert_eq!(SV::from_str("E01").unwrap().is_beidou_geo(), false);
-        assert_eq!(SV::from_str("C01").unwrap().is_beidou_geo(), true);
-        assert_eq!(SV::from_str("C02").unwrap().is_beidou_geo(), true);
-        assert_eq!(SV::from_str("C06").unwrap().is_beidou_geo(), false);
-        assert_eq!(SV::from_str("C48").unwrap().is_beidou_geo(), false);
-        assert_eq!(SV::from_str("C59").unwrap().is_beidou_geo(), true);
-        assert_eq!(SV::from_str("C60").unwrap().is_beidou_geo(), true);
+        assert!(!SV::from_str("G01").unwrap().is_beidou_geo());
+        assert!(!SV::from_str("E01").unwrap().is_beidou_geo());
+        assert!(SV::from_str("C01").unwrap().is_beidou_geo());
+        assert!(SV::from_str("C02").unwrap().is_beidou_geo());
+        assert!(!SV::from_str("C06").unwrap().is_beidou_geo());
+        assert!(!SV::from_str("C48").unwrap().is_beidou_geo());
+        assert!(SV::from_str("C59").unwrap().is_beidou_geo());
+        assert!(SV::from_str("C60").unwrap().is_beidou_geo());
     }
 }
@@ -0,0 +1,121 @@
+//! NMEA-0183 satellite numbering
+use crate::prelude::{Constellation, SV};
+
+impl SV {
+    /// Builds a [SV] from its NMEA-0183 satellite ID number:
+    /// - 1..=32: [Constellation::GPS]
+    /// - 33..=64: augmentation satellites 120..=151
+    /// - 65..=96: [Constellation::Glonass] slots
+    /// - 152..=158: augmentation satellites 152..=158 (NMEA 4.11 extension)
+    /// - 193..=202: [Constellation::QZSS]
+    /// - 301..=336: [Constellation::Galileo] (extended numbering)
+    /// - 401..=463: [Constellation::BeiDou] (extended numbering).
+    ///
+    /// NMEA 4.11 receivers may also report [Constellation::Galileo] and [Constellation::BeiDou]
+    /// satellites with their plain PRN number, in which case the constellation is implied
+    /// by the system ID and [SV::new] should be used instead.
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database (like [SV::from_sbas_absolute_prn]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_nmea_id(7), Some(sv!("G07")));
+    /// assert_eq!(SV::from_nmea_id(36), Some(sv!("S23"))); // PRN 123
+    /// assert_eq!(SV::from_nmea_id(72), Some(sv!("R08")));
+    /// assert_eq!(SV::from_nmea_id(193), Some(sv!("J01")));
+    /// assert_eq!(SV::from_nmea_id(311), Some(sv!("E11")));
+    /// assert_eq!(SV::from_nmea_id(0), None);
+    /// ```
+    pub fn from_nmea_id(id: u16) -> Option<Self> {
+        let sv = match id {
+            1..=32 => Self::new(Constellation::GPS, id as u8),
+            33..=64 => return Self::from_sbas_absolute_prn((id + 87) as u8),
+            65..=96 => Self::new(Constellation::Glonass, (id - 64) as u8),
+            152..=158 => return Self::from_sbas_absolute_prn(id as u8),
+            193..=202 => return Self::from_qzss_prn(id as u8),
+            301..=336 => Self::new(Constellation::Galileo, (id - 300) as u8),
+            401..=463 => Self::new(Constellation::BeiDou, (id - 400) as u8),
+            _ => return None,
+        };
+
+        if sv.is_valid() {
+            Some(sv)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the NMEA-0183 satellite ID number of this [SV],
+    /// which is the reciprocal of [SV::from_nmea_id].
+    /// Returns [None] for invalid satellites and constellations
+    /// that do not have an NMEA numbering (like [Constellation::IRNSS]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G07").to_nmea_id(), Some(7));
+    /// assert_eq!(sv!("S23").to_nmea_id(), Some(36));
+    /// assert_eq!(sv!("S55").to_nmea_id(), Some(155));
+    /// assert_eq!(sv!("R08").to_nmea_id(), Some(72));
+    /// assert_eq!(sv!("C19").to_nmea_id(), Some(419));
+    /// assert_eq!(sv!("I01").to_nmea_id(), None);
+    /// ```
+    pub fn to_nmea_id(&self) -> Option<u16> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let prn = self.prn as u16;
+
+        match self.constellation {
+            Constellation::GPS => Some(prn),
+            Constellation::Glonass => Some(prn + 64),
+            Constellation::QZSS => Some(prn + 192),
+            Constellation::Galileo => Some(prn + 300),
+            Constellation::BeiDou => Some(prn + 400),
+            c if c.is_sbas() => {
+                if prn + 100 <= 151 {
+                    Some(prn + 100 - 87)
+                } else {
+                    Some(prn + 100)
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+
+    #[test]
+    fn nmea_reciprocal() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                match sv.to_nmea_id() {
+                    Some(id) => {
+                        let parsed = SV::from_nmea_id(id).unwrap();
+                        assert_eq!(parsed.prn, sv.prn, "reciprocal failed for {:?}", sv);
+                        assert_eq!(parsed.constellation.is_sbas(), constellation.is_sbas());
+                        if !constellation.is_sbas() {
+                            assert_eq!(parsed, sv);
+                        }
+                    },
+                    None => assert_eq!(sv.constellation, Constellation::IRNSS),
+                }
+            }
+        }
+
+        for id in [0, 97, 151, 159, 192, 203, 300, 337, 400, 464, 1000] {
+            assert_eq!(SV::from_nmea_id(id), None, "invalid id {} accepted", id);
+        }
+
+        // Glonass slots beyond constellation definition
+        assert_eq!(SV::from_nmea_id(96), None);
+        assert_eq!(SV::new(Constellation::Glonass, 28).to_nmea_id(), None);
+    }
+}
@@ -0,0 +1,214 @@
+//! Orbit classification
+use crate::prelude::{Constellation, SV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+// Includes the orbit class of the catalog satellites
+include!(concat!(env!("OUT_DIR"), "/orbits.rs"));
+
+/// [Constellation::BeiDou] IGSO satellites: BDS-2 (C06 to C10, C13 and C16),
+/// BDS-3 experimental (C31) and BDS-3 (C38 to C40).
+const BEIDOU_IGSO_PRN: [u8; 11] = [6, 7, 8, 9, 10, 13, 16, 31, 38, 39, 40];
+
+/// [Constellation::QZSS] satellites and their orbit: QZS-1 (J01), QZS-2 (J02),
+/// QZS-4 (J03) and QZS-1R (J04) are on quasi-zenith orbits,
+/// while QZS-3 (J07) is geostationary.
+const QZSS_ORBITS: [(u8, OrbitClass); 5] = [
+    (1, OrbitClass::QZO),
+    (2, OrbitClass::QZO),
+    (3, OrbitClass::QZO),
+    (4, OrbitClass::QZO),
+    (7, OrbitClass::GEO),
+];
+
+/// [OrbitClass] describes the type of orbit a [SV] is placed on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum OrbitClass {
+    /// Medium Earth Orbit
+    MEO,
+
+    /// Geostationary Earth Orbit
+    GEO,
+
+    /// Inclined Geosynchronous Orbit
+    IGSO,
+
+    /// Quasi-Zenith Orbit (highly inclined and eccentric geosynchronous orbit)
+    QZO,
+}
+
+impl core::fmt::Display for OrbitClass {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::MEO => write!(f, "MEO"),
+            Self::GEO => write!(f, "GEO"),
+            Self::IGSO => write!(f, "IGSO"),
+            Self::QZO => write!(f, "QZO"),
+        }
+    }
+}
+
+impl SV {
+    /// Returns True if this [SV] is a [Constellation::BeiDou] inclined geosynchronous (IGSO) satellite.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(sv!("C06").is_beidou_igso());
+    /// assert!(sv!("C38").is_beidou_igso());
+    /// assert!(!sv!("C01").is_beidou_igso()); // GEO
+    /// assert!(!sv!("C20").is_beidou_igso()); // MEO
+    /// assert!(!sv!("G06").is_beidou_igso());
+    /// ```
+    pub fn is_beidou_igso(&self) -> bool {
+        self.constellation == Constellation::BeiDou && BEIDOU_IGSO_PRN.contains(&self.prn)
+    }
+
+    /// Returns True if this [SV] is a [Constellation::QZSS] geostationary satellite (like QZS-3),
+    /// as opposed to the satellites on quasi-zenith orbits.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(sv!("J07").is_qzss_geo());
+    /// assert!(!sv!("J01").is_qzss_geo());
+    /// assert!(!sv!("C07").is_qzss_geo());
+    /// ```
+    pub fn is_qzss_geo(&self) -> bool {
+        self.constellation == Constellation::QZSS
+            && QZSS_ORBITS
+                .iter()
+                .any(|(prn, orbit)| *prn == self.prn && *orbit == OrbitClass::GEO)
+    }
+
+    /// Returns the [OrbitClass] of this [SV]:
+    /// - [Constellation::BeiDou] satellites are either GEO, IGSO or MEO
+    /// - [Constellation::QZSS] satellites are QZO, except QZS-3 (J07) which is GEO
+    /// - [Constellation::IRNSS] satellites are either GEO or IGSO, as described by our builtin catalog
+    ///   (IGSO for unknown PRN numbers)
+    /// - augmentation satellites are GEO
+    /// - other satellites are MEO.
+    ///
+    /// Returns [None] for [Constellation::Mixed].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::OrbitClass;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G01").orbit_class(), Some(OrbitClass::MEO));
+    /// assert_eq!(sv!("C01").orbit_class(), Some(OrbitClass::GEO));
+    /// assert_eq!(sv!("C08").orbit_class(), Some(OrbitClass::IGSO));
+    /// assert_eq!(sv!("C20").orbit_class(), Some(OrbitClass::MEO));
+    /// assert_eq!(sv!("J01").orbit_class(), Some(OrbitClass::QZO));
+    /// assert_eq!(sv!("J07").orbit_class(), Some(OrbitClass::GEO));
+    /// assert_eq!(sv!("S23").orbit_class(), Some(OrbitClass::GEO));
+    /// ```
+    pub fn orbit_class(&self) -> Option<OrbitClass> {
+        match self.constellation {
+            Constellation::BeiDou => {
+                if self.is_beidou_geo() {
+                    Some(OrbitClass::GEO)
+                } else if self.is_beidou_igso() {
+                    Some(OrbitClass::IGSO)
+                } else {
+                    Some(OrbitClass::MEO)
+                }
+            },
+            Constellation::QZSS => {
+                if self.is_qzss_geo() {
+                    Some(OrbitClass::GEO)
+                } else {
+                    Some(OrbitClass::QZO)
+                }
+            },
+            Constellation::IRNSS => Some(
+                CATALOG_ORBITS
+                    .iter()
+                    .find(|(constellation, prn, _)| {
+                        *constellation == Constellation::IRNSS && *prn == self.prn
+                    })
+                    .map(|(_, _, orbit)| *orbit)
+                    .unwrap_or(OrbitClass::IGSO),
+            ),
+            Constellation::Mixed => None,
+            c => {
+                if c.is_sbas() {
+                    Some(OrbitClass::GEO)
+                } else {
+                    Some(OrbitClass::MEO)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OrbitClass;
+    use crate::prelude::{Constellation, SV};
+
+    #[test]
+    fn orbit_class() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                let orbit_class = sv.orbit_class().unwrap();
+
+                if sv.is_beidou_geo() || constellation.is_sbas() {
+                    assert_eq!(orbit_class, OrbitClass::GEO);
+                }
+
+                if orbit_class == OrbitClass::QZO {
+                    assert_eq!(sv.constellation, Constellation::QZSS);
+                }
+            }
+        }
+
+        for prn in super::BEIDOU_IGSO_PRN {
+            let sv = SV::new(Constellation::BeiDou, prn);
+            assert!(!sv.is_beidou_geo());
+            assert_eq!(sv.orbit_class(), Some(OrbitClass::IGSO));
+        }
+
+        for (prn, orbit) in super::QZSS_ORBITS {
+            let sv = SV::new(Constellation::QZSS, prn);
+            assert_eq!(sv.orbit_class(), Some(orbit));
+        }
+
+        assert_eq!(SV::new(Constellation::Mixed, 1).orbit_class(), None);
+        assert_eq!(
+            SV::new(Constellation::Galileo, 14).orbit_class(),
+            Some(OrbitClass::MEO)
+        );
+        assert_eq!(
+            SV::new(Constellation::IRNSS, 1).orbit_class(),
+            Some(OrbitClass::IGSO)
+        );
+
+        for prn in [3, 6, 7, 10] {
+            assert_eq!(
+                SV::new(Constellation::IRNSS, prn).orbit_class(),
+                Some(OrbitClass::GEO)
+            );
+        }
+
+        // consistent with the catalog
+        for (constellation, prn, orbit) in super::CATALOG_ORBITS {
+            assert_eq!(
+                SV::new(*constellation, *prn).orbit_class(),
+                Some(*orbit),
+                "{:?} {}",
+                constellation,
+                prn
+            );
+        }
+    }
+}
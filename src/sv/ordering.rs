@@ -0,0 +1,89 @@
+//! Canonical (RINEX/IGS) satellites ordering
+use crate::prelude::{Constellation, SV};
+use core::cmp::Ordering;
+
+/// Returns the rank of this [Constellation] in the conventional
+/// G, R, E, C, J, I, S order. Augmentation systems are all ranked as 'S'.
+pub(crate) fn igs_rank(constellation: Constellation) -> u8 {
+    match constellation {
+        Constellation::GPS => 0,
+        Constellation::Glonass => 1,
+        Constellation::Galileo => 2,
+        Constellation::BeiDou => 3,
+        Constellation::QZSS => 4,
+        Constellation::IRNSS => 5,
+        Constellation::Mixed => 7,
+        _ => 6,
+    }
+}
+
+impl SV {
+    /// Compares two [SV]s in the canonical RINEX/IGS order: by [Constellation]
+    /// (G, R, E, C, J, I then S), then by PRN number. All augmentation systems
+    /// are grouped together, as they share the 'S' identifier.
+    /// Note that the derived [Ord] implementation sorts by PRN number first,
+    /// prefer this method (or [SV::sort_igs]) to emit files that look canonical.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G12").igs_cmp(&sv!("R01")), Ordering::Less);
+    /// assert_eq!(sv!("E01").igs_cmp(&sv!("R24")), Ordering::Greater);
+    /// assert_eq!(sv!("S23").igs_cmp(&sv!("S36")), Ordering::Less);
+    /// ```
+    pub fn igs_cmp(&self, other: &Self) -> Ordering {
+        igs_rank(self.constellation)
+            .cmp(&igs_rank(other.constellation))
+            .then(self.prn.cmp(&other.prn))
+            .then(self.constellation.cmp(&other.constellation))
+    }
+
+    /// Sorts these [SV]s in the canonical RINEX/IGS order, see [SV::igs_cmp].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let mut svs = vec![sv!("C05"), sv!("E01"), sv!("G12"), sv!("R01"), sv!("G02")];
+    /// SV::sort_igs(&mut svs);
+    /// assert_eq!(svs, vec![sv!("G02"), sv!("G12"), sv!("R01"), sv!("E01"), sv!("C05")]);
+    /// ```
+    pub fn sort_igs(svs: &mut [Self]) {
+        svs.sort_unstable_by(|a, b| a.igs_cmp(b));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn igs_ordering() {
+        let mut svs = Constellation::VARIANTS
+            .iter()
+            .rev()
+            .flat_map(|constellation| constellation.sv_iter())
+            .collect::<Vec<_>>();
+
+        SV::sort_igs(&mut svs);
+
+        let letters = svs.iter().map(|sv| format!("{:x}", sv.constellation)).fold(
+            String::new(),
+            |mut letters, letter| {
+                if !letters.ends_with(&letter) {
+                    letters.push_str(&letter);
+                }
+                letters
+            },
+        );
+
+        assert_eq!(letters, "GRECJIS");
+
+        for pair in svs.windows(2) {
+            assert_ne!(pair[0].igs_cmp(&pair[1]), Ordering::Greater);
+        }
+    }
+}
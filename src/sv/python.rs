@@ -1,5 +1,11 @@
 use crate::prelude::{Constellation, TimeScale, SV};
-use pyo3::prelude::*;
+use pyo3::{basic::CompareOp, prelude::*, types::PyType};
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 #[pymethods]
 impl SV {
@@ -61,7 +67,33 @@ impl SV {
         Ok(ts)
     }
 
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_cls: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
     fn __str__(&self) -> String {
         format!("{:x}{:02}", self.constellation, self.prn)
     }
+
+    fn __repr__(&self) -> String {
+        format!("<SV {:x}>", self)
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Comparisons follow the canonical RINEX/IGS order, see [SV::igs_cmp].
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.igs_cmp(other))
+    }
+
+    /// Pickling support
+    fn __getnewargs__(&self) -> (Constellation, u8) {
+        (self.constellation, self.prn)
+    }
 }
@@ -0,0 +1,171 @@
+//! RTCM 3 satellite numbering
+use crate::prelude::{Constellation, SV};
+
+impl SV {
+    /// Builds a [SV] from the RTCM 3 GPS satellite ID (DF009), used in legacy
+    /// observation (1001-1004) and ephemeris (1019) messages:
+    /// - 1..=32: [Constellation::GPS]
+    /// - 40..=58: augmentation satellites 120..=138.
+    ///
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database (like [SV::from_sbas_absolute_prn]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_rtcm_gps_id(7), Some(sv!("G07")));
+    /// assert_eq!(SV::from_rtcm_gps_id(43), Some(sv!("S23"))); // PRN 123
+    /// assert_eq!(SV::from_rtcm_gps_id(33), None);
+    /// ```
+    pub fn from_rtcm_gps_id(id: u8) -> Option<Self> {
+        match id {
+            1..=32 => Some(Self::new(Constellation::GPS, id)),
+            40..=58 => Self::from_sbas_absolute_prn(id + 80),
+            _ => None,
+        }
+    }
+
+    /// Returns the RTCM 3 GPS satellite ID (DF009) of this [SV],
+    /// which is the reciprocal of [SV::from_rtcm_gps_id].
+    /// Returns [None] for other constellations and augmentation satellites beyond PRN 138.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G07").to_rtcm_gps_id(), Some(7));
+    /// assert_eq!(sv!("S23").to_rtcm_gps_id(), Some(43));
+    /// assert_eq!(sv!("S48").to_rtcm_gps_id(), None);
+    /// ```
+    pub fn to_rtcm_gps_id(&self) -> Option<u8> {
+        if self.constellation.is_sbas() {
+            match self.sbas_absolute_prn()? {
+                prn @ 120..=138 => Some(prn - 80),
+                _ => None,
+            }
+        } else if self.constellation == Constellation::GPS && self.is_valid() {
+            Some(self.prn)
+        } else {
+            None
+        }
+    }
+
+    /// Builds a [Constellation::Glonass] [SV] from the RTCM 3 satellite slot number (DF038),
+    /// used in legacy observation (1009-1012) and ephemeris (1020) messages.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_rtcm_glonass_id(8), Some(sv!("R08")));
+    /// assert_eq!(SV::from_rtcm_glonass_id(0), None);
+    /// ```
+    pub fn from_rtcm_glonass_id(id: u8) -> Option<Self> {
+        let sv = Self::new(Constellation::Glonass, id);
+        if sv.is_valid() {
+            Some(sv)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the RTCM 3 satellite slot number (DF038) of this [Constellation::Glonass] [SV],
+    /// which is the reciprocal of [SV::from_rtcm_glonass_id].
+    pub fn to_rtcm_glonass_id(&self) -> Option<u8> {
+        if self.constellation == Constellation::Glonass && self.is_valid() {
+            Some(self.prn)
+        } else {
+            None
+        }
+    }
+
+    /// Builds a [SV] from its satellite ID (1..=64) within the satellite mask
+    /// of RTCM 3 Multiple Signal Messages (MSM) of this [Constellation]:
+    /// - [Constellation::SBAS] (any augmentation system): ID 1 is PRN 120
+    /// - [Constellation::QZSS]: ID 1 is PRN 193 (J01)
+    /// - other constellations: ID is the PRN number (Glonass slot number).
+    ///
+    /// The same rule applies to the Galileo (DF252), BeiDou (DF488)
+    /// and QZSS (DF429) ephemeris satellite IDs.
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database (like [SV::from_sbas_absolute_prn]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_rtcm_msm(Constellation::Galileo, 11), Some(sv!("E11")));
+    /// assert_eq!(SV::from_rtcm_msm(Constellation::BeiDou, 59), Some(sv!("C59")));
+    /// assert_eq!(SV::from_rtcm_msm(Constellation::SBAS, 4), Some(sv!("S23"))); // PRN 123
+    /// assert_eq!(SV::from_rtcm_msm(Constellation::QZSS, 1), Some(sv!("J01")));
+    /// assert_eq!(SV::from_rtcm_msm(Constellation::GPS, 33), None);
+    /// ```
+    pub fn from_rtcm_msm(constellation: Constellation, id: u8) -> Option<Self> {
+        if constellation.is_sbas() {
+            return Self::from_sbas_absolute_prn(id.checked_add(119)?);
+        }
+
+        let sv = Self::new(constellation, id);
+        if sv.is_valid() {
+            Some(sv)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the MSM satellite ID (1..=64) of this [SV],
+    /// which is the reciprocal of [SV::from_rtcm_msm].
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("E11").to_rtcm_msm(), Some(11));
+    /// assert_eq!(sv!("S23").to_rtcm_msm(), Some(4));
+    /// assert_eq!(sv!("G33").to_rtcm_msm(), None);
+    /// ```
+    pub fn to_rtcm_msm(&self) -> Option<u8> {
+        if self.constellation.is_sbas() {
+            Some(self.sbas_absolute_prn()? - 119)
+        } else if self.is_valid() {
+            Some(self.prn)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+
+    #[test]
+    fn rtcm_reciprocal() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                let id = sv.to_rtcm_msm().unwrap();
+                assert!((1..=64).contains(&id));
+
+                let parsed = SV::from_rtcm_msm(*constellation, id).unwrap();
+                assert_eq!(parsed.prn, sv.prn, "reciprocal failed for {:?}", sv);
+
+                if let Some(id) = sv.to_rtcm_gps_id() {
+                    let parsed = SV::from_rtcm_gps_id(id).unwrap();
+                    assert_eq!(parsed.prn, sv.prn, "reciprocal failed for {:?}", sv);
+                }
+
+                if let Some(id) = sv.to_rtcm_glonass_id() {
+                    assert_eq!(SV::from_rtcm_glonass_id(id), Some(sv));
+                }
+            }
+        }
+
+        assert_eq!(SV::from_rtcm_msm(Constellation::SBAS, 0), None);
+        assert_eq!(SV::from_rtcm_msm(Constellation::SBAS, 40), None);
+        assert_eq!(SV::from_rtcm_msm(Constellation::Mixed, 1), None);
+        assert_eq!(SV::from_rtcm_gps_id(39), None);
+        assert_eq!(SV::from_rtcm_gps_id(59), None);
+        assert_eq!(SV::from_rtcm_glonass_id(28), None);
+    }
+}
@@ -0,0 +1,414 @@
+//! SBAS vehicles database
+use super::{SBAS_VEHICLES, SV};
+use crate::constellation::Constellation;
+use alloc::vec::Vec;
+use core::str::FromStr;
+use hifitime::Epoch;
+use lazy_static::lazy_static;
+
+/// [SbasVehicle] describes one geostationary augmentation satellite
+/// of our builtin SBAS database.
+/// ```
+/// use gnss_rs::sv;
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let vehicle = sv!("S23").sbas_vehicle().unwrap();
+/// assert_eq!(vehicle.sv, SV::new(Constellation::EGNOS, 23));
+/// assert_eq!(vehicle.name, "ASTRA-5B");
+/// assert_eq!(vehicle.absolute_prn(), 123);
+/// assert_eq!(vehicle.longitude_deg, 31.5);
+/// assert_eq!(vehicle.operator, "SES");
+/// assert_eq!(vehicle.norad, Some(39617));
+/// assert!(vehicle.l1 && vehicle.dfmc);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SbasVehicle {
+    /// [SV] identification, expressed in the RINEX (PRN - 100) convention,
+    /// and tied to its augmentation [Constellation] (provider)
+    pub sv: SV,
+
+    /// Readable satellite name, like "ASTRA-5B"
+    pub name: &'static str,
+
+    /// COSPAR (international launch) identification code, like "2014-011B", if known
+    pub cospar: Option<&'static str>,
+
+    /// NORAD catalog number, if known
+    pub norad: Option<u32>,
+
+    /// Operating entity, like "SES"
+    pub operator: &'static str,
+
+    /// Launch (or service entry) date, as [Epoch]
+    pub launch: Epoch,
+
+    /// Service start date, when it differs from launch
+    pub operational: Option<Epoch>,
+
+    /// Decommission date, for retired vehicles
+    pub decommission: Option<Epoch>,
+
+    /// Orbital slot longitude, in decimal degrees east
+    pub longitude_deg: f64,
+
+    /// True if this satellite broadcasts the legacy L1 service
+    pub l1: bool,
+
+    /// True if this satellite broadcasts the dual frequency
+    /// multi constellation (DFMC) L5 service
+    pub dfmc: bool,
+}
+
+impl SbasVehicle {
+    /// Returns the augmentation [Constellation] (provider) operating this vehicle.
+    pub const fn provider(&self) -> Constellation {
+        self.sv.constellation
+    }
+
+    /// Returns the absolute PRN number (120..=158) of this vehicle.
+    pub const fn absolute_prn(&self) -> u8 {
+        self.sv.prn + 100
+    }
+
+    /// Returns the service start date of this vehicle,
+    /// which is the launch date unless specified otherwise.
+    pub fn service_start(&self) -> Epoch {
+        self.operational.unwrap_or(self.launch)
+    }
+
+    /// Returns true if this vehicle was in service at this [Epoch]:
+    /// after its service start and before its decommission.
+    pub fn is_operational(&self, epoch: Epoch) -> bool {
+        self.service_start() <= epoch
+            && self
+                .decommission
+                .is_none_or(|decommission| epoch < decommission)
+    }
+}
+
+lazy_static! {
+    static ref SBAS_DATABASE: Vec<SbasVehicle> = SBAS_VEHICLES
+        .iter()
+        .map(|entry| SbasVehicle {
+            // database content is verified in CI/CD
+            sv: SV::new(
+                Constellation::from_str(entry.constellation).unwrap(),
+                (entry.prn - 100) as u8,
+            ),
+            name: entry.name,
+            cospar: entry.cospar,
+            norad: entry.norad,
+            operator: entry.operator,
+            launch: Epoch::from_str(entry.launch).unwrap(),
+            operational: entry
+                .operational
+                .map(|operational| Epoch::from_str(operational).unwrap()),
+            decommission: entry
+                .decommission
+                .map(|decommission| Epoch::from_str(decommission).unwrap()),
+            longitude_deg: entry.longitude,
+            l1: entry.l1,
+            dfmc: entry.dfmc,
+        })
+        .collect();
+}
+
+/// Iterates over all vehicles of our builtin SBAS database,
+/// sorted by PRN number.
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::sbas_vehicles;
+///
+/// let names = sbas_vehicles()
+///     .map(|vehicle| vehicle.name)
+///     .collect::<Vec<_>>();
+///
+/// assert!(names.contains(&"ASTRA-5B"));
+/// assert!(names.contains(&"ANIK-F1R"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_vehicles() -> impl Iterator<Item = &'static SbasVehicle> {
+    SBAS_DATABASE.iter()
+}
+
+/// Iterates over all vehicles of our builtin SBAS database
+/// operated by this augmentation [Constellation] (provider).
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::sbas_provider_vehicles;
+///
+/// let egnos = sbas_provider_vehicles(Constellation::EGNOS)
+///     .map(|vehicle| vehicle.sv.prn)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(egnos, vec![23, 26, 36]);
+/// assert_eq!(sbas_provider_vehicles(Constellation::GPS).count(), 0);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_provider_vehicles(
+    provider: Constellation,
+) -> impl Iterator<Item = &'static SbasVehicle> {
+    sbas_vehicles().filter(move |vehicle| vehicle.provider() == provider)
+}
+
+/// Iterates over the vehicles of our builtin SBAS database that were in service
+/// at this [Epoch] (see [SbasVehicle::is_operational]), sorted by PRN number.
+/// ```
+/// use gnss_rs::sv;
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::sbas_vehicles_active;
+/// use std::str::FromStr;
+///
+/// let t = Epoch::from_str("2021-01-01T00:00:00 UTC").unwrap();
+/// let active = sbas_vehicles_active(t)
+///     .map(|vehicle| vehicle.sv)
+///     .collect::<Vec<_>>();
+///
+/// assert!(active.contains(&sv!("S22"))); // INMARSAT-4F1
+/// assert!(!active.contains(&sv!("S23"))); // ASTRA-5B, in service since november 2021
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_vehicles_active(epoch: Epoch) -> impl Iterator<Item = &'static SbasVehicle> {
+    sbas_vehicles().filter(move |vehicle| vehicle.is_operational(epoch))
+}
+
+/// Iterates over the vehicles of this augmentation [Constellation] (provider)
+/// that were in service at this [Epoch], sorted by PRN number.
+/// ```
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::sbas_provider_vehicles_active;
+/// use std::str::FromStr;
+///
+/// let t = Epoch::from_str("2022-01-01T00:00:00 UTC").unwrap();
+/// let egnos = sbas_provider_vehicles_active(Constellation::EGNOS, t)
+///     .map(|vehicle| vehicle.name)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(egnos, vec!["ASTRA-5B", "SES-5"]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn sbas_provider_vehicles_active(
+    provider: Constellation,
+    epoch: Epoch,
+) -> impl Iterator<Item = &'static SbasVehicle> {
+    sbas_provider_vehicles(provider).filter(move |vehicle| vehicle.is_operational(epoch))
+}
+
+impl SV {
+    /// Returns the [SbasVehicle] description of this augmentation satellite,
+    /// from our builtin SBAS database. Returns [None] for other satellites,
+    /// or when the PRN number is not operated by this provider.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let vehicle = sv!("S38").sbas_vehicle().unwrap();
+    /// assert_eq!(vehicle.provider(), Constellation::WAAS);
+    /// assert_eq!(vehicle.name, "ANIK-F1R");
+    ///
+    /// assert!(sv!("S01").sbas_vehicle().is_none());
+    /// assert!(SV::new(Constellation::EGNOS, 38).sbas_vehicle().is_none());
+    /// assert!(sv!("G23").sbas_vehicle().is_none());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_vehicle(&self) -> Option<&'static SbasVehicle> {
+        if !self.constellation.is_sbas() {
+            return None;
+        }
+        sbas_vehicles().find(|vehicle| {
+            vehicle.sv.prn == self.prn
+                && (self.constellation == Constellation::SBAS
+                    || vehicle.sv.constellation == self.constellation)
+        })
+    }
+
+    /// Returns the entity operating this augmentation satellite, like "SES" for S23 (ASTRA-5B),
+    /// according to our builtin SBAS database.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("S23").sbas_operator(), Some("SES"));
+    /// assert_eq!(sv!("S38").sbas_operator(), Some("Telesat"));
+    /// assert_eq!(sv!("G01").sbas_operator(), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_operator(&self) -> Option<&'static str> {
+        Some(self.sbas_vehicle()?.operator)
+    }
+
+    /// Returns the service start date of this augmentation satellite,
+    /// according to our builtin SBAS database (see [SbasVehicle::service_start]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// let start = sv!("S23").sbas_service_start().unwrap();
+    /// assert_eq!(start, Epoch::from_str("2021-11-01T00:00:00 UTC").unwrap());
+    /// assert_eq!(sv!("G01").sbas_service_start(), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_service_start(&self) -> Option<Epoch> {
+        Some(self.sbas_vehicle()?.service_start())
+    }
+
+    /// Returns the decommission date of this augmentation satellite,
+    /// according to our builtin SBAS database. Returns [None] while still in service.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("S23").sbas_decommission(), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_decommission(&self) -> Option<Epoch> {
+        self.sbas_vehicle()?.decommission
+    }
+
+    /// Identifies an augmentation satellite from its readable name (case insensitive),
+    /// like "ASTRA-5B", using our builtin SBAS database.
+    /// Use [SV::sbas_matching_name] for partial matches.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::sbas_from_name("ASTRA-5B"), Some(sv!("S23")));
+    /// assert_eq!(SV::sbas_from_name("anik-f1r"), Some(sv!("S38")));
+    /// assert_eq!(SV::sbas_from_name("ASTRA"), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        sbas_vehicles()
+            .find(|vehicle| vehicle.name.eq_ignore_ascii_case(name))
+            .map(|vehicle| vehicle.sv)
+    }
+
+    /// Returns all augmentation satellites of our builtin SBAS database whose name
+    /// contains this pattern (case insensitive), sorted by PRN number.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::sbas_matching_name("inmarsat"), vec![sv!("S22"), sv!("S26")]);
+    /// assert_eq!(SV::sbas_matching_name("Luch-5"), vec![sv!("S25"), sv!("S40")]);
+    /// assert!(SV::sbas_matching_name("GPS").is_empty());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sbas_matching_name(pattern: &str) -> Vec<Self> {
+        let pattern = pattern.trim().to_ascii_lowercase();
+        sbas_vehicles()
+            .filter(|vehicle| vehicle.name.to_ascii_lowercase().contains(&pattern))
+            .map(|vehicle| vehicle.sv)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        sbas_provider_vehicles, sbas_provider_vehicles_active, sbas_vehicles, sbas_vehicles_active,
+    };
+    use crate::prelude::{Constellation, Epoch, SV};
+    use std::str::FromStr;
+
+    #[test]
+    fn sbas_database() {
+        assert_eq!(sbas_vehicles().count(), 16);
+
+        for vehicle in sbas_vehicles() {
+            assert!(vehicle.provider().is_sbas());
+            assert!((120..=158).contains(&vehicle.absolute_prn()));
+            assert_eq!(
+                SV::new(Constellation::SBAS, vehicle.sv.prn).sbas_vehicle(),
+                Some(vehicle)
+            );
+            assert_eq!(vehicle.sv.launch_datetime(), Some(vehicle.launch));
+            assert!(!vehicle.operator.is_empty());
+
+            #[cfg(feature = "cospar")]
+            if let Some(cospar) = vehicle.cospar {
+                assert!(
+                    crate::cospar::COSPAR::from_str(cospar).is_ok(),
+                    "invalid COSPAR number for \"{}\"",
+                    vehicle.name
+                );
+            }
+
+            if let Some(decommission) = vehicle.decommission {
+                assert!(decommission > vehicle.service_start());
+            }
+        }
+
+        let total = Constellation::VARIANTS
+            .iter()
+            .filter(|constellation| constellation.is_sbas())
+            .map(|constellation| sbas_provider_vehicles(*constellation).count())
+            .sum::<usize>();
+
+        assert_eq!(total, 16);
+    }
+
+    #[test]
+    fn sbas_service_period() {
+        let t0 = Epoch::from_str("2010-01-01T00:00:00 UTC").unwrap();
+        let t1 = Epoch::from_str("2012-01-01T00:00:00 UTC").unwrap();
+        let t2 = Epoch::from_str("2014-01-01T00:00:00 UTC").unwrap();
+
+        let mut vehicle = *SV::new(Constellation::SBAS, 23).sbas_vehicle().unwrap();
+
+        vehicle.launch = t0;
+        assert_eq!(vehicle.service_start(), t0);
+        assert!(vehicle.is_operational(t0));
+
+        vehicle.operational = Some(t1);
+        vehicle.decommission = Some(t2);
+        assert_eq!(vehicle.service_start(), t1);
+        assert!(!vehicle.is_operational(t0));
+        assert!(vehicle.is_operational(t1));
+        assert!(!vehicle.is_operational(t2));
+    }
+
+    #[test]
+    fn sbas_active_vehicles() {
+        let t = Epoch::from_str("2014-01-01T00:00:00 UTC").unwrap();
+        assert_eq!(sbas_vehicles_active(t).count(), 0);
+
+        let t = Epoch::from_str("2024-01-01T00:00:00 UTC").unwrap();
+        assert_eq!(sbas_vehicles_active(t).count(), 16);
+
+        for constellation in Constellation::VARIANTS {
+            let active = sbas_provider_vehicles_active(*constellation, t)
+                .map(|vehicle| vehicle.sv)
+                .collect::<Vec<_>>();
+
+            if constellation.is_sbas() && *constellation != Constellation::SBAS {
+                assert_eq!(active, constellation.operational_svs(t));
+            } else {
+                assert!(active.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn sbas_names() {
+        for vehicle in sbas_vehicles() {
+            assert_eq!(SV::sbas_from_name(vehicle.name), Some(vehicle.sv));
+            assert_eq!(
+                SV::sbas_from_name(&vehicle.name.to_lowercase()),
+                Some(vehicle.sv)
+            );
+            assert!(SV::sbas_matching_name(vehicle.name).contains(&vehicle.sv));
+        }
+
+        assert_eq!(SV::sbas_from_name(""), None);
+        assert_eq!(SV::sbas_matching_name("").len(), 16);
+    }
+}
@@ -0,0 +1,310 @@
+//! Fixed size satellite set
+use crate::{prelude::SV, sv::index::INDEX_GROUPS};
+
+/// Index groups, in canonical RINEX order (G, R, E, C, J, I, S).
+const CANONICAL_GROUPS: [usize; 7] = [0, 1, 2, 4, 3, 5, 6];
+
+/// [SvSet] is a fixed size satellite set, backed by one bitmask per [Constellation](crate::prelude::Constellation).
+/// It does not allocate and offers O(1) insertion and membership tests,
+/// which makes it much faster than a `HashSet<SV>` when filtering many epochs.
+///
+/// Only valid satellites may be stored (see [SV::is_valid]).
+/// All augmentation systems share the same PRN range, so SBAS vehicles
+/// are identified by PRN only.
+/// ```
+/// use gnss_rs::sv;
+/// use gnss_rs::prelude::*;
+/// use gnss_rs::sv::SvSet;
+/// use std::str::FromStr;
+///
+/// let mut set = SvSet::new();
+/// assert!(set.insert(sv!("E11")));
+/// assert!(set.insert(sv!("G05")));
+/// assert!(set.insert(sv!("G01")));
+/// assert!(!set.insert(sv!("G01"))); // already present
+///
+/// assert!(set.contains(&sv!("G05")));
+/// assert_eq!(set.len(), 3);
+///
+/// // canonical order
+/// assert_eq!(set.to_string(), "G01 G05 E11");
+///
+/// let other = [sv!("G05"), sv!("C01")].into_iter().collect::<SvSet>();
+/// assert_eq!((set & other).to_string(), "G05");
+/// assert_eq!((set | other).to_string(), "G01 G05 E11 C01");
+/// assert_eq!((set - other).to_string(), "G01 E11");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvSet {
+    masks: [u64; 7],
+}
+
+impl SvSet {
+    /// Builds a new empty [SvSet].
+    pub const fn new() -> Self {
+        Self { masks: [0; 7] }
+    }
+
+    /// Inserts this [SV] into the set.
+    /// Returns true if it was not already present.
+    /// Invalid satellites (see [SV::is_valid]) are never inserted.
+    pub fn insert(&mut self, sv: SV) -> bool {
+        if let Some((group, offset)) = sv.index_group() {
+            let present = self.masks[group] & (1 << offset) > 0;
+            self.masks[group] |= 1 << offset;
+            !present
+        } else {
+            false
+        }
+    }
+
+    /// Removes this [SV] from the set.
+    /// Returns true if it was present.
+    pub fn remove(&mut self, sv: &SV) -> bool {
+        if let Some((group, offset)) = sv.index_group() {
+            let present = self.masks[group] & (1 << offset) > 0;
+            self.masks[group] &= !(1 << offset);
+            present
+        } else {
+            false
+        }
+    }
+
+    /// Returns true if this [SV] is present in the set.
+    pub fn contains(&self, sv: &SV) -> bool {
+        if let Some((group, offset)) = sv.index_group() {
+            self.masks[group] & (1 << offset) > 0
+        } else {
+            false
+        }
+    }
+
+    /// Returns the number of satellites in the set.
+    pub fn len(&self) -> usize {
+        self.masks
+            .iter()
+            .map(|mask| mask.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.masks.iter().all(|mask| *mask == 0)
+    }
+
+    /// Removes all satellites from the set.
+    pub fn clear(&mut self) {
+        self.masks = [0; 7];
+    }
+
+    /// Returns the satellites present in either sets.
+    pub fn union(&self, rhs: &Self) -> Self {
+        let mut s = *self;
+        for (mask, rhs) in s.masks.iter_mut().zip(rhs.masks.iter()) {
+            *mask |= rhs;
+        }
+        s
+    }
+
+    /// Returns the satellites present in both sets.
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        let mut s = *self;
+        for (mask, rhs) in s.masks.iter_mut().zip(rhs.masks.iter()) {
+            *mask &= rhs;
+        }
+        s
+    }
+
+    /// Returns the satellites present in Self but not in rhs.
+    pub fn difference(&self, rhs: &Self) -> Self {
+        let mut s = *self;
+        for (mask, rhs) in s.masks.iter_mut().zip(rhs.masks.iter()) {
+            *mask &= !rhs;
+        }
+        s
+    }
+
+    /// Returns the satellites present in only one of the sets.
+    pub fn symmetric_difference(&self, rhs: &Self) -> Self {
+        let mut s = *self;
+        for (mask, rhs) in s.masks.iter_mut().zip(rhs.masks.iter()) {
+            *mask ^= rhs;
+        }
+        s
+    }
+
+    /// Returns true if all satellites of Self are present in rhs.
+    pub fn is_subset(&self, rhs: &Self) -> bool {
+        self.difference(rhs).is_empty()
+    }
+
+    /// Iterates over the satellites of the set, in canonical RINEX order
+    /// (G, R, E, C, J, I, S), then by increasing PRN number.
+    pub fn iter(&self) -> SvSetIter<'_> {
+        SvSetIter {
+            set: self,
+            group: 0,
+            mask: self.masks[CANONICAL_GROUPS[0]],
+        }
+    }
+}
+
+/// Iterator over the satellites of an [SvSet].
+pub struct SvSetIter<'a> {
+    set: &'a SvSet,
+    group: usize,
+    mask: u64,
+}
+
+impl Iterator for SvSetIter<'_> {
+    type Item = SV;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.mask == 0 {
+            self.group += 1;
+            let group = CANONICAL_GROUPS.get(self.group)?;
+            self.mask = self.set.masks[*group];
+        }
+
+        let offset = self.mask.trailing_zeros();
+        self.mask &= self.mask - 1;
+
+        let (constellation, first_prn, _) = INDEX_GROUPS[CANONICAL_GROUPS[self.group]];
+        Some(SV::new_identified(constellation, first_prn + offset as u8))
+    }
+}
+
+impl<'a> IntoIterator for &'a SvSet {
+    type Item = SV;
+    type IntoIter = SvSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<SV> for SvSet {
+    fn from_iter<I: IntoIterator<Item = SV>>(iter: I) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl Extend<SV> for SvSet {
+    fn extend<I: IntoIterator<Item = SV>>(&mut self, iter: I) {
+        for sv in iter {
+            self.insert(sv);
+        }
+    }
+}
+
+impl core::ops::BitOr for SvSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl core::ops::BitAnd for SvSet {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl core::ops::BitXor for SvSet {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl core::ops::Sub for SvSet {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+impl core::fmt::Display for SvSet {
+    /// Formats this [SvSet] as a space separated list of satellites in CNN format
+    /// (like "G01 G05 E11"), in canonical order.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (i, sv) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:x}", sv)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SvSet;
+    use crate::prelude::{Constellation, SV};
+    use std::str::FromStr;
+
+    #[test]
+    fn insertion() {
+        let mut set = SvSet::new();
+        assert!(set.is_empty());
+
+        for index in 0..SV::INDEX_COUNT {
+            let sv = SV::from_index(index).unwrap();
+            assert!(set.insert(sv), "failed to insert {:?}", sv);
+            assert!(set.contains(&sv));
+        }
+
+        assert_eq!(set.len(), SV::INDEX_COUNT);
+
+        // invalid satellites
+        assert!(!set.insert(SV::new(Constellation::GPS, 33)));
+        assert!(!set.insert(SV::new(Constellation::Mixed, 1)));
+        assert_eq!(set.len(), SV::INDEX_COUNT);
+
+        for index in 0..SV::INDEX_COUNT {
+            let sv = SV::from_index(index).unwrap();
+            assert!(set.remove(&sv));
+            assert!(!set.remove(&sv));
+        }
+
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn ordering() {
+        let set = [
+            "S23", "I01", "J03", "C63", "C01", "E36", "R24", "G32", "G01",
+        ]
+        .iter()
+        .map(|desc| SV::from_str(desc).unwrap())
+        .collect::<SvSet>();
+
+        assert_eq!(set.to_string(), "G01 G32 R24 E36 C01 C63 J03 I01 S23");
+        assert_eq!(set.iter().count(), 9);
+    }
+
+    #[test]
+    fn algebra() {
+        let a = ["G01", "G02", "E01"]
+            .iter()
+            .map(|desc| SV::from_str(desc).unwrap())
+            .collect::<SvSet>();
+
+        let b = ["G02", "E01", "R01"]
+            .iter()
+            .map(|desc| SV::from_str(desc).unwrap())
+            .collect::<SvSet>();
+
+        assert_eq!((a | b).to_string(), "G01 G02 R01 E01");
+        assert_eq!((a & b).to_string(), "G02 E01");
+        assert_eq!((a - b).to_string(), "G01");
+        assert_eq!((a ^ b).to_string(), "G01 R01");
+        assert!((a & b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+        assert_eq!(SvSet::new().to_string(), "");
+    }
+}
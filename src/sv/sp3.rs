@@ -0,0 +1,132 @@
+//! SP3 satellite identifiers
+use crate::{
+    prelude::{Constellation, SV},
+    sv::ParsingError,
+};
+
+use alloc::{format, string::String};
+use core::str::FromStr;
+
+/// Non GNSS satellites that may be found in SP3 files:
+/// 'L' stands for Low Earth Orbiters.
+const SP3_NON_GNSS: [char; 1] = ['L'];
+
+impl SV {
+    /// Parses a [SV] from an SP3 (revision c and d) satellite identifier,
+    /// made of exactly 3 characters "CNN", where:
+    /// - C is a single letter [Constellation] identifier,
+    ///   a blank letter stands for [Constellation::GPS] (SP3-a legacy)
+    /// - NN is a two-digit PRN number, possibly blank padded.
+    ///
+    /// Non GNSS satellites (like LEO 'L') are reported
+    /// as [ParsingError::NonGnssSatellite], so they may be skipped gracefully.
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database.
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use gnss_rs::sv::ParsingError;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_sp3("G01"), Ok(sv!("G01")));
+    /// assert_eq!(SV::from_sp3("R 8"), Ok(sv!("R08")));
+    /// assert_eq!(SV::from_sp3(" 12"), Ok(sv!("G12")));
+    /// assert_eq!(SV::from_sp3("L51"), Err(ParsingError::NonGnssSatellite('L')));
+    /// assert_eq!(SV::from_sp3("G1"), Err(ParsingError::InvalidSp3Format));
+    /// ```
+    pub fn from_sp3(content: &str) -> Result<Self, ParsingError> {
+        if content.len() != 3 || !content.is_ascii() {
+            return Err(ParsingError::InvalidSp3Format);
+        }
+
+        let letter = content
+            .chars()
+            .next()
+            .ok_or(ParsingError::InvalidSp3Format)?;
+
+        if SP3_NON_GNSS.contains(&letter) {
+            return Err(ParsingError::NonGnssSatellite(letter));
+        }
+
+        let constellation = if letter == ' ' {
+            Constellation::GPS
+        } else {
+            Constellation::from_str(&content[..1])?
+        };
+
+        if constellation.is_mixed() {
+            return Err(ParsingError::InvalidSp3Format);
+        }
+
+        let prn = content[1..]
+            .trim_start()
+            .parse::<u8>()
+            .map_err(|_| ParsingError::PrnParsing)?;
+
+        Ok(Self::new_identified(constellation, prn))
+    }
+
+    /// Formats this [SV] as a strict 3 character SP3 identifier (like "G01").
+    /// Returns [None] for satellites that cannot be described in SP3
+    /// (invalid PRN number or [Constellation::Mixed]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G01").to_sp3(), Some("G01".to_string()));
+    /// assert_eq!(sv!("S23").to_sp3(), Some("S23".to_string()));
+    /// assert_eq!(sv!("C254").to_sp3(), None);
+    /// ```
+    pub fn to_sp3(&self) -> Option<String> {
+        if self.is_valid() {
+            Some(format!("{:x}", self))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+    use crate::sv::ParsingError;
+
+    #[test]
+    fn sp3_reciprocal() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                let formatted = sv.to_sp3().unwrap();
+                assert_eq!(formatted.len(), 3);
+
+                let parsed = SV::from_sp3(&formatted).unwrap();
+                assert_eq!(parsed.prn, sv.prn, "reciprocal failed for {:?}", sv);
+                assert_eq!(parsed.constellation.is_sbas(), constellation.is_sbas());
+            }
+        }
+    }
+
+    #[test]
+    fn sp3_errors() {
+        for (content, error) in [
+            ("", ParsingError::InvalidSp3Format),
+            ("G001", ParsingError::InvalidSp3Format),
+            ("M01", ParsingError::InvalidSp3Format),
+            ("L01", ParsingError::NonGnssSatellite('L')),
+            ("G0x", ParsingError::PrnParsing),
+            ("G  ", ParsingError::PrnParsing),
+        ] {
+            assert_eq!(
+                SV::from_sp3(content),
+                Err(error),
+                "failed for \"{}\"",
+                content
+            );
+        }
+
+        assert!(matches!(
+            SV::from_sp3("X01"),
+            Err(ParsingError::ConstellationParsing(_))
+        ));
+    }
+}
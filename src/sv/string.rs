@@ -0,0 +1,199 @@
+//! String [SV] serialization, for readable datasets.
+//!
+//! [SV]s are serialized as their standard "CNN" description (like "G01" or "S23"),
+//! instead of a structure. Deserialization accepts any description
+//! compatible with [SV::from_str](core::str::FromStr).
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use gnss_rs::prelude::*;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "gnss_rs::sv::string")]
+//!     sv: SV,
+//! }
+//! ```
+//!
+//! The [map] submodule serializes maps indexed by [SV], where [SV]s become string keys:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::HashMap;
+//! use gnss_rs::prelude::*;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "gnss_rs::sv::string::map")]
+//!     elevations: HashMap<SV, f64>,
+//! }
+//! ```
+use crate::prelude::SV;
+use alloc::format;
+use core::str::FromStr;
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes this [SV] as its "CNN" description.
+pub fn serialize<S: Serializer>(sv: &SV, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("{:x}", sv))
+}
+
+/// Deserializes a [SV] from its "CNN" description.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SV, D::Error> {
+    deserializer.deserialize_str(StringVisitor)
+}
+
+struct StringVisitor;
+
+impl de::Visitor<'_> for StringVisitor {
+    type Value = SV;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a satellite description, like \"G01\"")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        SV::from_str(value).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Serialization of maps indexed by [SV], using "CNN" descriptions as keys.
+/// This applies to any map type, like `HashMap<SV, T>` or `BTreeMap<SV, T>`.
+pub mod map {
+    use super::StringVisitor;
+    use crate::prelude::SV;
+
+    use alloc::format;
+    use core::marker::PhantomData;
+
+    use serde::{
+        de::{self, DeserializeSeed, MapAccess},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes this map, with [SV] keys as "CNN" descriptions.
+    pub fn serialize<'a, M, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a SV, &'a V)>,
+        V: Serialize + 'a,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        for (sv, value) in map {
+            state.serialize_entry(&format!("{:x}", sv), value)?;
+        }
+        state.end()
+    }
+
+    /// Deserializes a map, with [SV] keys as "CNN" descriptions.
+    pub fn deserialize<'de, M, V, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: FromIterator<(SV, V)>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+
+    struct KeySeed;
+
+    impl<'de> DeserializeSeed<'de> for KeySeed {
+        type Value = SV;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<SV, D::Error> {
+            deserializer.deserialize_str(StringVisitor)
+        }
+    }
+
+    struct MapVisitor<M, V>(PhantomData<(M, V)>);
+
+    impl<'de, M, V> de::Visitor<'de> for MapVisitor<M, V>
+    where
+        M: FromIterator<(SV, V)>,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "a map indexed by satellite descriptions")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut entries = alloc::vec::Vec::with_capacity(access.size_hint().unwrap_or(0));
+            while let Some(key) = access.next_key_seed(KeySeed)? {
+                entries.push((key, access.next_value()?));
+            }
+            Ok(entries.into_iter().collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::sv::string")]
+        sv: SV,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Maps {
+        #[serde(with = "crate::sv::string::map")]
+        hashed: HashMap<SV, u32>,
+
+        #[serde(with = "crate::sv::string::map")]
+        ordered: BTreeMap<SV, f64>,
+    }
+
+    #[test]
+    fn string_serdes() {
+        for (sv, expected) in [
+            (SV::new(Constellation::GPS, 1), "G01"),
+            (SV::new(Constellation::Galileo, 12), "E12"),
+            (SV::from_str("S23").unwrap(), "S23"),
+        ] {
+            let record = Record { sv };
+            let content = serde_json::to_string(&record).unwrap();
+            assert_eq!(content, format!("{{\"sv\":\"{}\"}}", expected));
+
+            let parsed: Record = serde_json::from_str(&content).unwrap();
+            assert_eq!(parsed, record);
+        }
+
+        assert!(serde_json::from_str::<Record>("{\"sv\":\"X01\"}").is_err());
+        assert!(serde_json::from_str::<Record>("{\"sv\":\"\"}").is_err());
+
+        // same interpretation as SV::from_str
+        for content in ["G01", "E 5", "R+3", "S23", "C1x", "é01", "G"] {
+            let parsed = serde_json::from_str::<Record>(&format!("{{\"sv\":\"{}\"}}", content))
+                .map(|record| record.sv)
+                .ok();
+
+            assert_eq!(parsed, SV::from_str(content).ok(), "{}", content);
+        }
+    }
+
+    #[test]
+    fn map_serdes() {
+        let g01 = SV::new(Constellation::GPS, 1);
+        let e05 = SV::new(Constellation::Galileo, 5);
+
+        let maps = Maps {
+            hashed: HashMap::from([(g01, 1), (e05, 2)]),
+            ordered: BTreeMap::from([(g01, 10.0), (e05, 20.0)]),
+        };
+
+        let content = serde_json::to_string(&maps).unwrap();
+        assert!(content.contains("\"ordered\":{\"G01\":10.0,\"E05\":20.0}"));
+
+        let parsed: Maps = serde_json::from_str(&content).unwrap();
+        assert_eq!(parsed, maps);
+    }
+}
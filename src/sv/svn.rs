@@ -0,0 +1,102 @@
+//! Space Vehicle Number
+use crate::constellation::Constellation;
+use crate::sv::ParsingError;
+
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+/// [SVN] (Space Vehicle Number) uniquely identifies a satellite (the physical spacecraft)
+/// within its [Constellation]. Unlike PRN numbers, which are reassigned when satellites
+/// are replaced, an [SVN] is never reused. Precise products (SINEX, ANTEX) are described in SVN space.
+/// Use [SV::svn_at](crate::prelude::SV::svn_at) to convert between both.
+/// ```
+/// use gnss_rs::prelude::*;
+/// use std::str::FromStr;
+///
+/// let svn = SVN::new(Constellation::GPS, 74);
+/// assert_eq!(svn.to_string(), "G074");
+/// assert_eq!(SVN::from_str("G074"), Ok(svn));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct SVN {
+    /// [Constellation] to which this satellite belongs to
+    pub constellation: Constellation,
+
+    /// Space vehicle number
+    pub number: u16,
+}
+
+impl SVN {
+    /// Builds a new [SVN].
+    pub const fn new(constellation: Constellation, number: u16) -> Self {
+        Self {
+            constellation,
+            number,
+        }
+    }
+}
+
+impl core::fmt::Display for SVN {
+    /// Formats this [SVN] in IGS format "CNNN", where:
+    /// - C is a single letter [Constellation] identifier
+    /// - NNN is a three-digit number
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:x}{:03}", self.constellation, self.number)
+    }
+}
+
+impl FromStr for SVN {
+    type Err = ParsingError;
+
+    /// Parses [SVN] from IGS "CNNN" format, like "G074".
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+
+        if string.len() < 2 || !string.is_char_boundary(1) {
+            return Err(ParsingError::PrnParsing);
+        }
+
+        let constellation = Constellation::from_str(&string[..1])?;
+
+        let number = string[1..]
+            .trim()
+            .parse::<u16>()
+            .map_err(|_| ParsingError::PrnParsing)?;
+
+        Ok(Self::new(constellation, number))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SVN;
+    use crate::prelude::Constellation;
+    use std::str::FromStr;
+
+    #[test]
+    fn svn_parsing() {
+        for (content, expected) in [
+            ("G074", SVN::new(Constellation::GPS, 74)),
+            ("G74", SVN::new(Constellation::GPS, 74)),
+            ("E210", SVN::new(Constellation::Galileo, 210)),
+            ("R801", SVN::new(Constellation::Glonass, 801)),
+        ] {
+            let svn = SVN::from_str(content).unwrap();
+            assert_eq!(svn, expected);
+
+            // reciprocal
+            assert_eq!(SVN::from_str(&svn.to_string()), Ok(svn));
+        }
+
+        for content in ["", "G", "074", "Gxx"] {
+            assert!(SVN::from_str(content).is_err());
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! u-blox (UBX protocol) satellite numbering
+use crate::prelude::{Constellation, SV};
+
+/// UBX gnssId of each [Constellation].
+/// IMES (gnssId 4) is not supported.
+const UBX_GNSS_ID: [(u8, Constellation); 7] = [
+    (0, Constellation::GPS),
+    (1, Constellation::SBAS),
+    (2, Constellation::Galileo),
+    (3, Constellation::BeiDou),
+    (5, Constellation::QZSS),
+    (6, Constellation::Glonass),
+    (7, Constellation::IRNSS),
+];
+
+impl SV {
+    /// Builds a [SV] from the (gnssId, svId) pair used by the UBX protocol
+    /// (UBX-NAV-SAT, UBX-RXM-SFRBX..):
+    /// - GPS (0): svId 1..=32
+    /// - SBAS (1): svId 120..=158
+    /// - Galileo (2): svId 1..=36
+    /// - BeiDou (3): svId 1..=63
+    /// - QZSS (5): svId 1..=10 (193..=202 is also accepted)
+    /// - Glonass (6): svId 1..=27 (slot number), 255 (unknown slot) is not supported
+    /// - NavIC (7): svId 1..=14.
+    ///
+    /// When built with std library, augmentation satellites are identified
+    /// using our builtin database (like [SV::from_sbas_absolute_prn]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(SV::from_ublox(0, 7), Some(sv!("G07")));
+    /// assert_eq!(SV::from_ublox(1, 123), Some(sv!("S23")));
+    /// assert_eq!(SV::from_ublox(5, 1), Some(sv!("J01")));
+    /// assert_eq!(SV::from_ublox(5, 193), Some(sv!("J01")));
+    /// assert_eq!(SV::from_ublox(6, 255), None);
+    /// ```
+    pub fn from_ublox(gnss_id: u8, sv_id: u8) -> Option<Self> {
+        let (_, constellation) = UBX_GNSS_ID.iter().find(|(id, _)| *id == gnss_id)?;
+
+        let sv = match constellation {
+            Constellation::SBAS => return Self::from_sbas_absolute_prn(sv_id),
+            Constellation::QZSS if sv_id > 192 => return Self::from_qzss_prn(sv_id),
+            constellation => Self::new(*constellation, sv_id),
+        };
+
+        if sv.is_valid() {
+            Some(sv)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the UBX (gnssId, svId) pair of this [SV],
+    /// which is the reciprocal of [SV::from_ublox].
+    /// Returns [None] for invalid satellites (see [SV::is_valid]).
+    /// ```
+    /// use gnss_rs::sv;
+    /// use gnss_rs::prelude::*;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(sv!("G07").to_ublox(), Some((0, 7)));
+    /// assert_eq!(sv!("S23").to_ublox(), Some((1, 123)));
+    /// assert_eq!(sv!("R08").to_ublox(), Some((6, 8)));
+    /// assert_eq!(sv!("G33").to_ublox(), None);
+    /// ```
+    pub fn to_ublox(&self) -> Option<(u8, u8)> {
+        if self.constellation.is_sbas() {
+            return Some((1, self.sbas_absolute_prn()?));
+        }
+
+        if !self.is_valid() {
+            return None;
+        }
+
+        let (gnss_id, _) = UBX_GNSS_ID
+            .iter()
+            .find(|(_, constellation)| *constellation == self.constellation)?;
+
+        Some((*gnss_id, self.prn))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::{Constellation, SV};
+
+    #[test]
+    fn ublox_reciprocal() {
+        for constellation in Constellation::VARIANTS {
+            for sv in constellation.sv_iter() {
+                let (gnss_id, sv_id) = sv.to_ublox().unwrap();
+                let parsed = SV::from_ublox(gnss_id, sv_id).unwrap();
+                assert_eq!(parsed.prn, sv.prn, "reciprocal failed for {:?}", sv);
+                if !constellation.is_sbas() {
+                    assert_eq!(parsed, sv);
+                }
+            }
+        }
+
+        for (gnss_id, sv_id) in [(0, 0), (0, 33), (1, 23), (1, 159), (4, 173), (8, 1)] {
+            assert_eq!(SV::from_ublox(gnss_id, sv_id), None);
+        }
+
+        assert_eq!(SV::new(Constellation::Mixed, 1).to_ublox(), None);
+    }
+}
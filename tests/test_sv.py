@@ -18,3 +18,24 @@ def test_sv():
     assert "{:x}".format(sat.constellation, "BDS") # drop country code
     assert sat.timescale() == TimeScale.BDT
 
+
+def test_sv_dunders():
+    import pickle
+
+    g01 = SV.from_str("G01")
+    assert g01 == SV(Constellation.GPS, 1)
+    assert g01 != SV.from_str("G02")
+    assert g01 < SV.from_str("R01") # canonical order
+    assert repr(g01) == "<SV G01>"
+
+    # usable as dict keys
+    elevations = {g01: 45.0}
+    assert elevations[SV(Constellation.GPS, 1)] == 45.0
+
+    assert pickle.loads(pickle.dumps(g01)) == g01
+
+    gal = Constellation.from_str("Galileo")
+    assert gal == Constellation.Galileo
+    assert repr(gal) == "<Constellation GAL>"
+    assert {gal: 1}[Constellation.Galileo] == 1
+    assert pickle.loads(pickle.dumps(gal)) == gal